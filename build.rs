@@ -0,0 +1,20 @@
+//! Writes `index.node.d.ts` into `OUT_DIR`, the declarations of the functions the addon exports,
+//! with `Case`, `Pattern`, and `Boundary` generated from the enums of `convert_case`.
+
+use std::{env, fs, path::Path};
+
+use convert_case_core::dts;
+
+fn main()
+{
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Each profile and feature set has its own OUT_DIR, so builds with other features don't
+    // overwrite these declarations; `npm run build` copies them next to the addon
+    let dts = dts::module(dts::TYPES, &dts::functions());
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("index.node.d.ts");
+    if fs::read_to_string(&path).ok().as_deref() != Some(dts.as_str())
+    {
+        fs::write(&path, dts).unwrap();
+    }
+}
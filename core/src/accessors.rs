@@ -0,0 +1,146 @@
+/// A language whose accessor naming convention the functions below follow.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lang
+{
+    /// JavaBeans: `getFoo`, `isFoo`, and `setFoo`, decapitalized the way `Introspector` does.
+    Java,
+    /// .NET properties: `Foo`, with the field camelized the way JSON serializers do.
+    CSharp,
+    /// Closure-style accessor methods: `getFoo`, `isFoo`, and `setFoo`.
+    JavaScript,
+}
+
+impl Lang
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "java" => Some(Lang::Java),
+            "csharp" => Some(Lang::CSharp),
+            "javascript" => Some(Lang::JavaScript),
+            _ => None,
+        }
+    }
+}
+
+/// Drops the marks of a private member: a leading `_`, `m_`, or JS `#`.
+fn strip_member_prefix(field: &str) -> &str
+{
+    let field = field.strip_prefix("m_").or_else(|| field.strip_prefix('#')).unwrap_or(field);
+    field.trim_start_matches('_')
+}
+
+fn capitalize(name: &str) -> String
+{
+    let mut chars = name.chars();
+    match chars.next()
+    {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Whether `name` is `prefix` followed by an uppercase letter, as with `isActive`.
+fn has_word_prefix(name: &str, prefix: &str) -> bool
+{
+    name.strip_prefix(prefix).is_some_and(|rest| rest.starts_with(char::is_uppercase))
+}
+
+/// The property name an accessor's name is built from.
+fn property(field: &str, lang: Lang) -> String
+{
+    match lang
+    {
+        // `Introspector` leaves names starting with two capitals as they are, so `xCoordinate` has
+        // to keep its lowercase `x` (`getxCoordinate`) to come back as `xCoordinate`
+        Lang::Java if field.chars().nth(1).is_some_and(char::is_uppercase) => field.to_string(),
+        _ => capitalize(field),
+    }
+}
+
+/// Derives the name of the getter of `field`.  Boolean getters of Java and JS start with `is`, and
+/// a boolean field that already does, such as `isActive`, is its own getter name.
+pub fn getter_name(field: &str, lang: Lang, boolean: bool) -> String
+{
+    let field = strip_member_prefix(field);
+    match lang
+    {
+        Lang::CSharp => capitalize(field),
+        _ if boolean && has_word_prefix(field, "is") => field.to_string(),
+        _ if boolean => format!("is{}", property(field, lang)),
+        _ => format!("get{}", property(field, lang)),
+    }
+}
+
+/// Derives the name of the setter of `field`.  A setter of a boolean `isActive` field is
+/// `setActive`, matching the getter `isActive`.
+pub fn setter_name(field: &str, lang: Lang, boolean: bool) -> String
+{
+    let field = strip_member_prefix(field);
+    match lang
+    {
+        Lang::CSharp => capitalize(field),
+        _ if boolean && has_word_prefix(field, "is") => format!("set{}", &field[2..]),
+        _ => format!("set{}", property(field, lang)),
+    }
+}
+
+/// Lowercases a leading acronym as well as the first letter, the way Json.NET and `System.Text.Json`
+/// camelize names, so `URLValue` becomes `urlValue` and `IO` becomes `io`.
+fn camelize(name: &str) -> String
+{
+    let chars: Vec<char> = name.chars().collect();
+    let mut camel = String::with_capacity(name.len());
+    for (i, c) in chars.iter().enumerate()
+    {
+        let next_is_lower = chars.get(i + 1).is_some_and(|next| !next.is_uppercase());
+        if (i == 1 && !c.is_uppercase()) || (i > 0 && next_is_lower)
+        {
+            camel.extend(&chars[i..]);
+            return camel;
+        }
+        camel.extend(c.to_lowercase());
+    }
+    camel
+}
+
+/// `Introspector.decapitalize`: lowercases the first letter unless the first two are both
+/// uppercase, so `URLValue` stays `URLValue`.
+fn decapitalize(name: &str) -> String
+{
+    let mut chars = name.chars();
+    match (chars.next(), chars.next())
+    {
+        (Some(first), Some(second)) if first.is_uppercase() && second.is_uppercase() => name.to_string(),
+        (Some(first), _) => first.to_lowercase().chain(name[first.len_utf8()..].chars()).collect(),
+        (None, _) => String::new(),
+    }
+}
+
+/// Recovers the field an accessor belongs to, or returns `None` if `name` isn't an accessor name.
+/// C# property names, optionally with the `get_` or `set_` of their accessor methods, always are.
+pub fn field_from_accessor(name: &str, lang: Lang) -> Option<String>
+{
+    if lang == Lang::CSharp
+    {
+        let property = name.strip_prefix("get_").or_else(|| name.strip_prefix("set_")).unwrap_or(name);
+        return if property.is_empty() { None } else { Some(camelize(property)) };
+    }
+
+    let property = ["get", "set", "is"]
+        .iter()
+        .filter_map(|prefix| name.strip_prefix(prefix))
+        .find(|rest| match lang
+        {
+            // A lowercase letter followed by a capital is a property too, as in `getxCoordinate`
+            Lang::Java => rest.starts_with(char::is_uppercase) || rest.chars().nth(1).is_some_and(char::is_uppercase),
+            _ => rest.starts_with(char::is_uppercase),
+        })?;
+
+    match lang
+    {
+        Lang::Java => Some(decapitalize(property)),
+        _ => Some(camelize(property)),
+    }
+}
@@ -0,0 +1,69 @@
+//! Finds the acronyms a set of identifiers uses, by how their words are capitalized.
+
+use std::collections::HashMap;
+
+use convert_case::Converter;
+
+/// An acronym found by [`discover_acronyms`].
+pub struct Acronym
+{
+    pub acronym: String,
+    /// How many times the acronym is written in uppercase.
+    pub uses: usize,
+    /// The fraction of the times its word is written in a way that shows its casing that it is
+    /// written in uppercase.
+    pub consistency: f64,
+}
+
+/// Finds words that `identifiers` consistently write in uppercase, as `HTTP` is in `HTTPServer`
+/// and `getHTTPResponse`.
+///
+/// Only words whose casing is a choice count: identifiers without both lowercase and uppercase
+/// letters are skipped, as is a lowercase first word, which camel case lowercases anyway.  A word
+/// of two or more letters is suggested if it is written in uppercase at least `min_uses` times
+/// and in at least `min_consistency` of the places its casing shows, so that `Id` in most of the
+/// identifiers outweighs an occasional `ID`.  Acronyms are returned most used first, and in
+/// alphabetical order when tied.
+pub fn discover_acronyms(identifiers: &[String], min_uses: usize, min_consistency: f64) -> Vec<Acronym>
+{
+    let conv = Converter::new().set_delim("\0");
+
+    // For every word in lowercase, how many times it is written in uppercase and how many times
+    // it is written otherwise
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    for identifier in identifiers
+    {
+        if !identifier.chars().any(char::is_lowercase) || !identifier.chars().any(char::is_uppercase)
+        {
+            continue;
+        }
+
+        let split = conv.convert(identifier);
+        for (i, word) in split.split('\0').filter(|word| !word.is_empty()).enumerate()
+        {
+            let starts_upper = word.chars().next().is_some_and(char::is_uppercase);
+            if (i == 0 && !starts_upper) || word.chars().filter(|c| c.is_alphabetic()).count() < 2
+            {
+                continue;
+            }
+
+            let count = counts.entry(word.to_lowercase()).or_default();
+            match word.chars().any(char::is_lowercase)
+            {
+                true => count.1 += 1,
+                false => count.0 += 1,
+            }
+        }
+    }
+
+    let mut acronyms: Vec<Acronym> = counts.into_iter()
+        .map(|(word, (upper, other))| Acronym {
+            acronym: word.to_uppercase(),
+            uses: upper,
+            consistency: upper as f64 / (upper + other) as f64,
+        })
+        .filter(|found| found.uses > 0 && found.uses >= min_uses && found.consistency >= min_consistency)
+        .collect();
+    acronyms.sort_by(|a, b| b.uses.cmp(&a.uses).then_with(|| a.acronym.cmp(&b.acronym)));
+    acronyms
+}
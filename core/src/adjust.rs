@@ -0,0 +1,30 @@
+//! Changes the casing of one word of a string and leaves the rest of it as it is, for adjustments
+//! like camel to pascal case that converting the whole string again could disturb.
+
+use convert_case::Pattern;
+
+use crate::find;
+
+/// Cases the word at `index` of `input` with `pattern`, counting from the end if `index` is
+/// negative, so -1 is the last word.  Everything else, delimiters included, is left as it is, and
+/// `input` is returned unchanged if it has no word at `index`.
+///
+/// The pattern is applied to the word on its own, so patterns that treat the first word
+/// differently, as [`Pattern::Camel`] lowercases it, always do.
+pub fn apply_to_word(input: &str, index: isize, pattern: Pattern) -> String
+{
+    let spans = find::words(input);
+    let position = match index < 0
+    {
+        true => spans.len().checked_sub(index.unsigned_abs()),
+        false => Some(index as usize),
+    };
+    let (start, end) = match position.and_then(|position| spans.get(position))
+    {
+        Some(span) => *span,
+        None => return input.to_string(),
+    };
+
+    let word = pattern.mutate(&[&input[start..end]]).concat();
+    format!("{}{}{}", &input[..start], word, &input[end..])
+}
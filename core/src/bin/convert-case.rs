@@ -0,0 +1,234 @@
+//! `convert-case`, the conversion functions of the addon as a command-line filter.
+//!
+//! Reads stdin and writes stdout, converting either every line as an identifier or, with
+//! `--keys`, the keys of a JSON, NDJSON, YAML, TOML, or CSV document exactly as the matching Node
+//! function would.  Lines and NDJSON records are converted and written as they arrive.
+
+use std::io::{self, BufRead, Read, Write};
+use std::process;
+
+use convert_case::{Case, Casing, Converter};
+use convert_case_core::collisions::CollisionPolicy;
+use convert_case_core::keys::KeyOptions;
+use convert_case_core::mapping::KeyReport;
+use convert_case_core::paths::PathFilter;
+use convert_case_core::{delimited, json, toml, yaml};
+
+const USAGE: &str = "\
+Usage: convert-case --to <case> [--from <case>] [--keys <format> [<key options>]]
+
+Converts every line of stdin to <case>, or with --keys, the keys of the document on stdin.
+
+Cases are named like Case in the Node API, in any case: snake, upper-snake, camel, pascal,
+kebab, cobol, train, flat, upper-flat, lower, upper, title, toggle, alternating, ...
+
+Formats:
+    --json          a JSON document (keys of nested objects are converted too)
+    --ndjson        newline-delimited JSON, converted record by record
+    --yaml          a YAML document
+    --toml          a TOML document
+    --csv           CSV, of which only the header row is converted
+
+Key options:
+    --shallow               only convert top-level keys of JSON and NDJSON
+    --max-depth <n>         convert at most <n> levels of JSON and NDJSON keys
+    --collisions <policy>   lastWins, firstWins, suffix, or error
+    --strict                fail instead of guessing, as the strict option does
+    --pretty                pretty-print converted JSON
+    --delimiter <char>      the CSV field delimiter, ',' by default
+";
+
+enum Format
+{
+    Json,
+    Ndjson,
+    Yaml,
+    Toml,
+    Csv,
+}
+
+struct Args
+{
+    conv: Converter,
+    format: Option<Format>,
+    options: KeyOptions,
+    pretty: bool,
+    delimiter: u8,
+}
+
+/// Prints `message` and exits, with status 2 for usage errors and 1 for conversion errors.
+fn fail(message: &str, status: i32) -> !
+{
+    eprintln!("convert-case: {}", message);
+    process::exit(status)
+}
+
+/// Finds the case named `name`, comparing names in kebab case so that `upper_snake`,
+/// `UpperSnake`, and `upper-snake` all work.
+fn parse_case(name: &str) -> Case
+{
+    let kebab = name.to_case(Case::Kebab);
+    match Case::all_cases().into_iter().find(|case| format!("{:?}", case).to_case(Case::Kebab) == kebab)
+    {
+        Some(case) => case,
+        None => fail(&format!("unknown case \"{}\"", name), 2),
+    }
+}
+
+fn parse_args() -> Args
+{
+    let mut args = std::env::args().skip(1);
+    let mut to_case = None;
+    let mut from_case = None;
+    let mut keys = false;
+    let mut format = None;
+    let mut options = KeyOptions {
+        deep: true,
+        max_depth: None,
+        filter: PathFilter::default(),
+        collisions: CollisionPolicy::default(),
+        strict: false,
+        rename_all: None,
+    };
+    let mut collisions = None;
+    let mut pretty = false;
+    let mut delimiter = b',';
+
+    while let Some(arg) = args.next()
+    {
+        let mut value = |flag: &str| args.next().unwrap_or_else(|| fail(&format!("{} needs a value", flag), 2));
+
+        match arg.as_str()
+        {
+            "-h" | "--help" =>
+            {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "--to" => to_case = Some(parse_case(&value("--to"))),
+            "--from" => from_case = Some(parse_case(&value("--from"))),
+            "--keys" => keys = true,
+            "--json" => format = Some(Format::Json),
+            "--ndjson" => format = Some(Format::Ndjson),
+            "--yaml" => format = Some(Format::Yaml),
+            "--toml" => format = Some(Format::Toml),
+            "--csv" => format = Some(Format::Csv),
+            "--shallow" => options.deep = false,
+            "--max-depth" => match value("--max-depth").parse()
+            {
+                Ok(max_depth) => options.max_depth = Some(max_depth),
+                Err(_) => fail("--max-depth must be a number", 2),
+            },
+            "--collisions" =>
+            {
+                let policy = value("--collisions");
+                match CollisionPolicy::from_name(&policy)
+                {
+                    Some(policy) => collisions = Some(policy),
+                    None => fail(&format!("unknown collision policy \"{}\"", policy), 2),
+                }
+            }
+            "--strict" => options.strict = true,
+            "--pretty" => pretty = true,
+            "--delimiter" => match value("--delimiter").as_bytes()
+            {
+                [byte] => delimiter = *byte,
+                _ => fail("--delimiter must be a single ASCII character", 2),
+            },
+            _ => fail(&format!("unknown argument \"{}\"\n\n{}", arg, USAGE), 2),
+        }
+    }
+
+    let to_case = to_case.unwrap_or_else(|| fail(&format!("--to is required\n\n{}", USAGE), 2));
+    if keys != format.is_some()
+    {
+        fail("--keys needs exactly one of --json, --ndjson, --yaml, --toml, or --csv", 2);
+    }
+
+    // As with the Node functions, strict mode refuses collisions unless a policy is given
+    options.collisions = collisions.unwrap_or(if options.strict { CollisionPolicy::Error } else { CollisionPolicy::LastWins });
+
+    let mut conv = Converter::new();
+    if let Some(from_case) = from_case
+    {
+        conv = conv.from_case(from_case);
+    }
+
+    Args { conv: conv.to_case(to_case), format, options, pretty, delimiter }
+}
+
+fn read_stdin() -> String
+{
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input)
+    {
+        fail(&format!("cannot read stdin: {}", e), 1);
+    }
+    input
+}
+
+/// Writes `output`, exiting quietly if stdout was closed, as when piping into `head`.
+fn write(out: &mut impl Write, output: &str)
+{
+    if out.write_all(output.as_bytes()).and_then(|_| out.flush()).is_err()
+    {
+        process::exit(0);
+    }
+}
+
+fn main()
+{
+    let args = parse_args();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    let converted = match args.format
+    {
+        None =>
+        {
+            // Every line is an identifier, converted without its line ending
+            for line in io::stdin().lock().lines()
+            {
+                match line
+                {
+                    Ok(line) => write(&mut out, &format!("{}\n", args.conv.convert(line.trim_end_matches('\r')))),
+                    Err(e) => fail(&format!("cannot read stdin: {}", e), 1),
+                }
+            }
+            return;
+        }
+        Some(Format::Ndjson) =>
+        {
+            let mut stream = json::NdjsonStream::new(args.conv, args.options);
+            let stdin = io::stdin();
+            let mut stdin = stdin.lock();
+            let mut line = String::new();
+            loop
+            {
+                line.clear();
+                match stdin.read_line(&mut line)
+                {
+                    Ok(0) => break,
+                    Ok(_) => match stream.write(&line)
+                    {
+                        Ok(converted) => write(&mut out, &converted),
+                        Err(e) => fail(&e, 1),
+                    },
+                    Err(e) => fail(&format!("cannot read stdin: {}", e), 1),
+                }
+            }
+            stream.end()
+        }
+        Some(Format::Json) => json::convert_json_keys(&read_stdin(), &args.conv, &args.options, args.pretty, &mut KeyReport::default())
+            .map(|converted| converted + "\n"),
+        Some(Format::Yaml) => yaml::convert_yaml_keys(&read_stdin(), &args.conv).map_err(|e| format!("Invalid YAML: {}", e)),
+        Some(Format::Toml) => toml::convert_toml_keys(&read_stdin(), &args.conv),
+        Some(Format::Csv) => delimited::convert_csv_header(&read_stdin(), &args.conv, args.delimiter, false).map_err(|e| format!("Invalid CSV: {}", e)),
+    };
+
+    match converted
+    {
+        Ok(converted) => write(&mut out, &converted),
+        Err(e) => fail(&e, 1),
+    }
+}
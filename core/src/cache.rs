@@ -0,0 +1,134 @@
+//! Memoizes conversions of strings to a case, for servers that convert the same keys over and
+//! over.
+//!
+//! The bindings keep one [`Cache`] for each instance of the module, so every worker thread and
+//! every Electron context has its own, and it is off until it is given a size with
+//! [`Cache::set_size`].
+
+use std::collections::{HashMap, VecDeque};
+use std::mem::size_of;
+
+use convert_case::Case;
+
+use crate::trace;
+
+/// The string, the case it was converted to, and the case it was converted from, if one was
+/// given.
+type Key = (String, Case, Option<Case>);
+
+#[derive(Default)]
+pub struct Cache
+{
+    size: usize,
+    entries: HashMap<Key, String>,
+    /// The keys of `entries`, oldest first.
+    order: VecDeque<Key>,
+    hits: u64,
+    misses: u64,
+    memory: usize,
+}
+
+/// What [`Cache::stats`] reports about a cache.
+pub struct CacheStats
+{
+    pub entries: usize,
+    /// The most entries the cache keeps, which is 0 if it is off.
+    pub size: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// A rough estimate of the bytes the entries take, counting their strings and the space each
+    /// takes in the cache's collections.
+    pub memory: usize,
+}
+
+/// A rough estimate of the bytes an entry for `input` converting to `output` takes.
+fn entry_memory(input: &str, output: &str) -> usize
+{
+    // Each key is stored in both the map and the queue
+    2 * (size_of::<Key>() + input.len()) + size_of::<String>() + output.len()
+}
+
+impl Cache
+{
+    /// Whether conversions to `case` can be cached at all, which those to [`Case::Random`] and
+    /// [`Case::PseudoRandom`] can't, since they are meant to come out differently every time.
+    pub fn caches(case: Case) -> bool
+    {
+        !matches!(case, Case::Random | Case::PseudoRandom)
+    }
+
+    /// Whether the cache is on.
+    pub fn enabled(&self) -> bool
+    {
+        self.size > 0
+    }
+
+    /// Returns what `input` converted from `from_case` to `case` was cached as, if it was,
+    /// counting a hit or a miss and emitting a hit as a [`trace`] event.
+    pub fn get(&mut self, input: &str, case: Case, from_case: Option<Case>) -> Option<String>
+    {
+        let found = self.entries.get(&(input.to_string(), case, from_case)).cloned();
+        match &found
+        {
+            Some(found) =>
+            {
+                self.hits += 1;
+                trace::cache_hit(input, found);
+            }
+            None => self.misses += 1,
+        }
+        found
+    }
+
+    /// Caches `output` as what `input` converted from `from_case` to `case` gives, dropping the
+    /// oldest entry if the cache is full.  Does nothing if the cache is off or `case` isn't one
+    /// it [`caches`](Cache::caches).
+    pub fn insert(&mut self, input: &str, case: Case, from_case: Option<Case>, output: &str)
+    {
+        let key = (input.to_string(), case, from_case);
+        if self.size == 0 || !Cache::caches(case) || self.entries.contains_key(&key)
+        {
+            return;
+        }
+        while self.entries.len() >= self.size
+        {
+            self.evict_oldest();
+        }
+        self.memory += entry_memory(input, output);
+        self.order.push_back(key.clone());
+        self.entries.insert(key, output.to_string());
+    }
+
+    fn evict_oldest(&mut self)
+    {
+        if let Some(key) = self.order.pop_front()
+        {
+            if let Some(output) = self.entries.remove(&key)
+            {
+                self.memory -= entry_memory(&key.0, &output);
+            }
+        }
+    }
+
+    /// Sets the most entries the cache keeps, dropping the oldest ones if it has more, or turns
+    /// the cache off and empties it with 0.
+    pub fn set_size(&mut self, size: usize)
+    {
+        self.size = size;
+        while self.entries.len() > size
+        {
+            self.evict_oldest();
+        }
+    }
+
+    /// Empties the cache and resets its counts of hits and misses, leaving its size as it is.
+    pub fn clear(&mut self)
+    {
+        *self = Cache { size: self.size, ..Cache::default() };
+    }
+
+    pub fn stats(&self) -> CacheStats
+    {
+        CacheStats { entries: self.entries.len(), size: self.size, hits: self.hits, misses: self.misses, memory: self.memory }
+    }
+}
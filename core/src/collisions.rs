@@ -0,0 +1,137 @@
+use std::collections::{HashMap, HashSet};
+
+/// What to do when several keys of one object end up with the same name.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum CollisionPolicy
+{
+    /// Keep the last of the colliding keys, which is what assigning them in order would do.
+    #[default]
+    LastWins,
+
+    /// Keep the first of the colliding keys and drop the rest.
+    FirstWins,
+
+    /// Keep the first key and append `<delim>2`, `<delim>3`, ... to the others.
+    Suffix,
+
+    /// Refuse to convert the object at all.
+    Error,
+}
+
+impl CollisionPolicy
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "lastWins" => Some(CollisionPolicy::LastWins),
+            "firstWins" => Some(CollisionPolicy::FirstWins),
+            "suffix" => Some(CollisionPolicy::Suffix),
+            "error" => Some(CollisionPolicy::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Several keys of the object at `path` that were converted to the same `name`.
+pub struct Collision
+{
+    pub path: Vec<String>,
+    pub keys: Vec<String>,
+    pub name: String,
+}
+
+impl Collision
+{
+    /// The message thrown for this collision under [`CollisionPolicy::Error`].
+    pub fn message(&self) -> String
+    {
+        let keys = self.keys.iter().map(|key| format!("\"{}\"", key)).collect::<Vec<_>>().join(", ");
+        let mut message = format!("Keys {} all convert to \"{}\"", keys, self.name);
+        if !self.path.is_empty()
+        {
+            message.push_str(&format!(" at \"{}\"", self.path.join(".")));
+        }
+        message
+    }
+}
+
+/// Settles the final name of every key of one object.
+///
+/// `keys` are the original keys and `names` what each of them was converted to, in the same order.
+/// Returns the name each key should be stored under, or `None` if the policy drops it, along with
+/// every collision that was found.  Under [`CollisionPolicy::Error`] the first collision is
+/// returned as the error instead.
+pub fn resolve(keys: &[String], names: Vec<String>, policy: CollisionPolicy, delim: &str, path: &[String]) -> Result<(Vec<Option<String>>, Vec<Collision>), Collision>
+{
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of: HashMap<&str, usize> = HashMap::new();
+    for (i, name) in names.iter().enumerate()
+    {
+        match group_of.get(name.as_str())
+        {
+            Some(&group) => groups[group].push(i),
+            None =>
+            {
+                group_of.insert(name, groups.len());
+                groups.push(vec![i]);
+            }
+        }
+    }
+
+    let collisions: Vec<Collision> = groups.iter()
+        .filter(|group| group.len() > 1)
+        .map(|group| Collision {
+            path: path.to_vec(),
+            keys: group.iter().map(|&i| keys[i].clone()).collect(),
+            name: names[group[0]].clone(),
+        })
+        .collect();
+
+    if policy == CollisionPolicy::Error
+    {
+        if let Some(collision) = collisions.into_iter().next()
+        {
+            return Err(collision);
+        }
+        return Ok((names.into_iter().map(Some).collect(), Vec::new()));
+    }
+
+    let mut taken: HashSet<String> = names.iter().cloned().collect();
+    let mut resolved: Vec<Option<String>> = names.iter().cloned().map(Some).collect();
+
+    for group in groups.iter().filter(|group| group.len() > 1)
+    {
+        let (kept, others) = match policy
+        {
+            CollisionPolicy::LastWins => (group[group.len() - 1], &group[..group.len() - 1]),
+            _ => (group[0], &group[1..]),
+        };
+        let name = names[kept].clone();
+
+        let mut n = 2;
+        for &i in others
+        {
+            resolved[i] = if policy == CollisionPolicy::Suffix
+            {
+                let suffixed = loop
+                {
+                    let candidate = format!("{}{}{}", name, delim, n);
+                    n += 1;
+                    if !taken.contains(&candidate)
+                    {
+                        break candidate;
+                    }
+                };
+                taken.insert(suffixed.clone());
+                Some(suffixed)
+            }
+            else
+            {
+                None
+            };
+        }
+    }
+
+    Ok((resolved, collisions))
+}
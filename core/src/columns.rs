@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use convert_case::Converter;
+
+/// Where [`ColumnRenamer`] gets the new name of a column from.
+pub enum Columns
+{
+    /// Convert every column name to a case.
+    Case(Converter),
+
+    /// Look the name up, keeping columns that aren't listed.
+    Mapping(HashMap<String, String>),
+}
+
+/// Renames the columns of many rows, working out the new name of each distinct column only once.
+pub struct ColumnRenamer
+{
+    columns: Columns,
+    names: HashMap<String, String>,
+}
+
+impl ColumnRenamer
+{
+    pub fn new(columns: Columns) -> Self
+    {
+        ColumnRenamer { columns, names: HashMap::new() }
+    }
+
+    pub fn name(&mut self, column: &str) -> &str
+    {
+        if !self.names.contains_key(column)
+        {
+            let name = match &self.columns
+            {
+                Columns::Case(conv) => conv.convert(column),
+                Columns::Mapping(mapping) => mapping.get(column).cloned().unwrap_or_else(|| column.to_string()),
+            };
+            self.names.insert(column.to_string(), name);
+        }
+
+        &self.names[column]
+    }
+}
@@ -0,0 +1,285 @@
+//! Reports on how consistently a set of identifiers follows one naming convention.
+
+use std::cmp::Reverse;
+
+use convert_case::{Boundary, Case, Casing, Converter};
+
+use crate::conversion::{self, CaseOptions};
+
+/// The cases identifiers are written in, without the aliases `UpperCamel`, `ScreamingSnake`, and
+/// `UpperKebab`, or the space-delimited cases only single words can be in.  Ties between
+/// conventions that the identifiers follow equally well go to the one listed first.
+pub const CONVENTIONS: [Case; 9] = [
+    Case::Camel,
+    Case::Pascal,
+    Case::Snake,
+    Case::UpperSnake,
+    Case::Kebab,
+    Case::Cobol,
+    Case::Train,
+    Case::Flat,
+    Case::UpperFlat,
+];
+
+/// An identifier that doesn't follow the inferred convention.
+pub struct Outlier
+{
+    pub identifier: String,
+    /// The conventions the identifier does follow, which is none if it mixes several.
+    pub cases: Vec<Case>,
+    /// How the identifier mixes conventions within itself, if it does, as [`mixed_conventions`]
+    /// describes it.  Outliers that don't are consistently in a different case.
+    pub mixed: Option<String>,
+    /// The identifier converted to the inferred convention.
+    pub suggestion: String,
+}
+
+pub struct ConsistencyReport
+{
+    /// How many identifiers follow each of [`CONVENTIONS`], most followed first.
+    pub counts: Vec<(Case, usize)>,
+    /// The convention most identifiers follow, or `None` if none of them follow any.
+    pub convention: Option<Case>,
+    pub outliers: Vec<Outlier>,
+}
+
+/// The conventions `identifier` follows, in the order of [`CONVENTIONS`].  Single words follow
+/// several at once; `"name"` is in camel, snake, kebab, and flat case.
+pub fn conventions_of(identifier: &str) -> Vec<Case>
+{
+    conventions_with(identifier, &CaseOptions::default())
+}
+
+/// The conventions `identifier` follows, checking each with [`conversion::is_case`] and `options`.
+pub fn conventions_with(identifier: &str, options: &CaseOptions) -> Vec<Case>
+{
+    CONVENTIONS.iter().copied().filter(|case| conversion::is_case(identifier, *case, options)).collect()
+}
+
+const DELIMITERS: [char; 3] = ['_', '-', ' '];
+
+/// How the letters of one word of a delimited identifier are cased.
+#[derive(Clone, Copy, PartialEq)]
+enum WordCase
+{
+    Lower,
+    Upper,
+    Capital,
+    /// A single uppercase letter, which could be an uppercase or a capitalized word.
+    Letter,
+}
+
+impl WordCase
+{
+    fn of(word: &str) -> Option<WordCase>
+    {
+        let letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+        if letters.is_empty()
+        {
+            None
+        }
+        else if letters.iter().all(|c| c.is_lowercase())
+        {
+            Some(WordCase::Lower)
+        }
+        else if letters.len() == 1
+        {
+            Some(WordCase::Letter)
+        }
+        else if letters.iter().all(|c| c.is_uppercase())
+        {
+            Some(WordCase::Upper)
+        }
+        else
+        {
+            Some(WordCase::Capital)
+        }
+    }
+
+    fn name(self) -> &'static str
+    {
+        match self
+        {
+            WordCase::Lower => "lowercase",
+            WordCase::Upper => "uppercase",
+            WordCase::Capital | WordCase::Letter => "capitalized",
+        }
+    }
+}
+
+/// Describes how `identifier` mixes conventions within itself, as `user_Id` and
+/// `HTTP_server_Count` do, or returns `None` if it consistently follows one, even one that isn't
+/// expected.
+///
+/// An identifier mixes conventions if it separates words with more than one of `_`, `-`, and
+/// space, if it separates some words with a delimiter and others with capital letters, or if its
+/// delimited words aren't all cased the same way.  Identifiers without delimiters only separate
+/// words by their casing, so they can't mix conventions, and acronyms in them, as in
+/// `HTTPServer`, are taken to be deliberate.
+pub fn mixed_conventions(identifier: &str) -> Option<String>
+{
+    let trimmed = identifier.trim_matches(&DELIMITERS[..]);
+
+    let mut delimiters: Vec<char> = Vec::new();
+    for c in trimmed.chars().filter(|c| DELIMITERS.contains(c))
+    {
+        if !delimiters.contains(&c)
+        {
+            delimiters.push(c);
+        }
+    }
+    let delimiter = match delimiters[..]
+    {
+        [] => return None,
+        [delimiter] => delimiter,
+        _ =>
+        {
+            let names: Vec<String> = delimiters.iter().map(|delimiter| format!("`{}`", delimiter)).collect();
+            return Some(format!("separates words with both {}", names.join(" and ")));
+        }
+    };
+
+    let words: Vec<&str> = trimmed.split(delimiter).filter(|word| !word.is_empty()).collect();
+    let capitals = [Boundary::LowerUpper, Boundary::Acronym];
+    if words.iter().any(|word| Boundary::list_from(word).iter().any(|boundary| capitals.contains(boundary)))
+    {
+        return Some(format!("separates words with both `{}` and capital letters", delimiter));
+    }
+
+    let mut cases: Vec<WordCase> = Vec::new();
+    for case in words.iter().filter_map(|word| WordCase::of(word))
+    {
+        if !cases.contains(&case)
+        {
+            cases.push(case);
+        }
+    }
+    // A single uppercase letter fits in with uppercase words as well as capitalized ones
+    if cases.contains(&WordCase::Upper) || cases.contains(&WordCase::Capital)
+    {
+        cases.retain(|case| *case != WordCase::Letter);
+    }
+    if cases.len() < 2
+    {
+        return None;
+    }
+
+    let names: Vec<&str> = cases.iter().map(|case| case.name()).collect();
+    let names = match names.split_last()
+    {
+        Some((last, [first])) => format!("{} and {}", first, last),
+        Some((last, rest)) => format!("{}, and {}", rest.join(", "), last),
+        None => unreachable!(),
+    };
+    Some(format!("mixes {} words", names))
+}
+
+/// Counts the conventions `identifiers` follow, infers the one they were meant to follow, and
+/// lists the identifiers that don't follow it, with what they would be if they did.
+///
+/// Single words count towards every convention they are in, so the convention is the one the
+/// most identifiers follow, and between conventions that are tied, the one the most identifiers
+/// of several words follow.  Remaining ties go to the convention listed first in
+/// [`CONVENTIONS`].  Which conventions an identifier follows is checked with `options`, which
+/// also decide what happens to delimiters at its edges in its suggestion.
+pub fn case_consistency_report(identifiers: &[String], options: &CaseOptions) -> ConsistencyReport
+{
+    let matches: Vec<Vec<Case>> = identifiers.iter().map(|identifier| conventions_with(identifier, options)).collect();
+
+    // (case, identifiers following it, identifiers of several words following it)
+    let mut tallies: Vec<(Case, usize, usize)> = CONVENTIONS.iter().map(|case| (*case, 0, 0)).collect();
+    for (identifier, cases) in identifiers.iter().zip(&matches)
+    {
+        let several_words = identifier.to_case(Case::Flat) != identifier.to_case(Case::Snake);
+        for tally in tallies.iter_mut().filter(|tally| cases.contains(&tally.0))
+        {
+            tally.1 += 1;
+            tally.2 += several_words as usize;
+        }
+    }
+
+    // Sorting is stable, so tallies that are tied stay in the order of `CONVENTIONS`
+    tallies.sort_by_key(|tally| Reverse((tally.1, tally.2)));
+    let convention = tallies.first().filter(|tally| tally.1 > 0).map(|tally| tally.0);
+
+    let outliers = match convention
+    {
+        Some(convention) => identifiers.iter().zip(matches)
+            .filter(|(_, cases)| !cases.contains(&convention))
+            .map(|(identifier, cases)| Outlier {
+                identifier: identifier.clone(),
+                cases,
+                mixed: mixed_conventions(identifier),
+                // Identifiers whose delimiters are rejected are suggested without them
+                suggestion: conversion::convert(identifier, &Converter::new().to_case(convention), options)
+                    .unwrap_or_else(|_| identifier.to_case(convention)),
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    ConsistencyReport {
+        counts: tallies.into_iter().map(|(case, count, _)| (case, count)).collect(),
+        convention,
+        outliers,
+    }
+}
+
+/// Picks which of the conventions `identifier` follows it is meant to be in, by which of them
+/// `context` follows most, as [`case_consistency_report`] ranks them.  A single word like `name`
+/// follows several, so the context decides between them; an identifier that follows only one
+/// gets that one whatever the context, and one that follows a convention the context doesn't
+/// gets the first of its own, in the order of [`CONVENTIONS`].  Returns `None` if `identifier`
+/// follows none.
+pub fn suggest_case(identifier: &str, context: &[String]) -> Option<Case>
+{
+    let cases = conventions_of(identifier);
+    let report = case_consistency_report(context, &CaseOptions::default());
+    report.counts.iter()
+        .find(|(case, count)| *count > 0 && cases.contains(case))
+        .map(|(case, _)| *case)
+        .or_else(|| cases.first().copied())
+}
+
+/// Detects the case to split `identifier` as: the first of [`CONVENTIONS`] it follows with
+/// `options`, or, if it follows none, snake, kebab, or lower case for whichever of `_`, `-`,
+/// and spaces it has most of, or camel case if it has none of them.  Splitting on one case's
+/// boundaries keeps words like the `v2` of `MIXED_Case_v2` whole where the default boundaries
+/// would split them further.
+pub fn detect_case(identifier: &str, options: &CaseOptions) -> Case
+{
+    if let Some(case) = CONVENTIONS.iter().copied().find(|case| conversion::is_case(identifier, *case, options))
+    {
+        return case;
+    }
+    [(Case::Snake, '_'), (Case::Kebab, '-'), (Case::Lower, ' ')].iter()
+        .map(|(case, delimiter)| (*case, identifier.matches(*delimiter).count()))
+        .filter(|(_, count)| *count > 0)
+        .fold(None, |best: Option<(Case, usize)>, (case, count)| match best
+        {
+            Some((_, best_count)) if best_count >= count => best,
+            _ => Some((case, count)),
+        })
+        .map_or(Case::Camel, |(case, _)| case)
+}
+
+/// An identifier found by [`find_mixed_conventions`].
+pub struct MixedIdentifier
+{
+    /// The position of the identifier in the list that was searched.
+    pub index: usize,
+    pub identifier: String,
+    pub reason: String,
+}
+
+/// Finds the identifiers that mix conventions within themselves, leaving out those that are only
+/// in an unexpected case.
+pub fn find_mixed_conventions(identifiers: &[String]) -> Vec<MixedIdentifier>
+{
+    identifiers.iter()
+        .enumerate()
+        .filter_map(|(index, identifier)| {
+            mixed_conventions(identifier).map(|reason| MixedIdentifier { index, identifier: identifier.clone(), reason })
+        })
+        .collect()
+}
@@ -0,0 +1,738 @@
+//! Options for converting a single string and checking its case, shared by `toCase`, `isCase`,
+//! `mutate`, and the functions that detect which cases identifiers are in.
+
+use convert_case::{Boundary, Case, Converter};
+
+use crate::consistency;
+use crate::random;
+use crate::reserved::{self, Avoidance};
+use crate::trace;
+
+/// What to do with delimiters at the start or end of a string, as in `_private` or `value_`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EdgeDelimiters
+{
+    /// Drop them, so `_foo_` converts to `foo` and is in snake case.
+    Strip,
+
+    /// Keep them as they are around the converted string, so `_foo_` converts to `_foo_` in
+    /// snake case.  The string is in a case if the rest of it is.
+    Preserve,
+
+    /// Refuse to convert the string, and don't take it to be in any case.
+    Reject,
+}
+
+impl EdgeDelimiters
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "strip" => Some(EdgeDelimiters::Strip),
+            "preserve" => Some(EdgeDelimiters::Preserve),
+            "reject" => Some(EdgeDelimiters::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// What to do with a string without any words: one that is empty or only has whitespace, `_`,
+/// and `-` in it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EmptyInput
+{
+    /// Leave it as it is.
+    Unchanged,
+
+    /// Convert it to the empty string.
+    Empty,
+
+    /// Refuse to convert it, and don't take it to be in any case.
+    Throw,
+}
+
+impl EmptyInput
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "unchanged" => Some(EmptyInput::Unchanged),
+            "empty" => Some(EmptyInput::Empty),
+            "throw" => Some(EmptyInput::Throw),
+            _ => None,
+        }
+    }
+}
+
+/// What to do with apostrophes in words, as in `don't` and `users'`.  Both `'` and `’` are
+/// apostrophes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Apostrophes
+{
+    /// Keep them in the word, so `don't stop` converts to `don't_stop` in snake case.
+    Keep,
+
+    /// Drop them, joining the word around them, so `don't stop` converts to `dont_stop`.
+    Strip,
+
+    /// Split the word on them, so `don't stop` converts to `don_t_stop`.
+    Split,
+}
+
+impl Apostrophes
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "keep" => Some(Apostrophes::Keep),
+            "strip" => Some(Apostrophes::Strip),
+            "split" => Some(Apostrophes::Split),
+            _ => None,
+        }
+    }
+}
+
+/// What to do with a string whose first word starts with a digit, as `3dModel` does, which isn't
+/// a valid identifier in most languages.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LeadingDigit
+{
+    /// Put an `_` before it, so `3d model` converts to `_3_d_model` in snake case.
+    PrefixUnderscore,
+
+    /// Spell out each leading digit as a word, so `3d model` converts to `three_d_model`.
+    SpellOut,
+
+    /// Leave it as it is.
+    Keep,
+
+    /// Refuse to convert the string.
+    Error,
+}
+
+impl LeadingDigit
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "prefixUnderscore" => Some(LeadingDigit::PrefixUnderscore),
+            "spellOut" => Some(LeadingDigit::SpellOut),
+            "keep" => Some(LeadingDigit::Keep),
+            "error" => Some(LeadingDigit::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Characters whose case [`CaseOptions::keep_case`] keeps as it is while the rest of a string is
+/// cased.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CharacterClass
+{
+    /// Letters of the Greek alphabet, as in the `Δ` of `ΔTime`.
+    Greek,
+
+    /// Letters of the Cyrillic alphabet.
+    Cyrillic,
+
+    /// Letters of any alphabet but the Latin one.
+    NonLatin,
+
+    /// Every character inside `()`, `[]`, or `{}`.  Each outermost bracketed span is also kept
+    /// whole, brackets and delimiters included, instead of being split into words.
+    Bracketed,
+}
+
+impl CharacterClass
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "greek" => Some(CharacterClass::Greek),
+            "cyrillic" => Some(CharacterClass::Cyrillic),
+            "nonLatin" => Some(CharacterClass::NonLatin),
+            "bracketed" => Some(CharacterClass::Bracketed),
+            _ => None,
+        }
+    }
+
+    /// Whether `c`, `depth` brackets deep, is in this class.
+    fn contains(self, c: char, depth: usize) -> bool
+    {
+        match self
+        {
+            CharacterClass::Greek => matches!(c, '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}'),
+            CharacterClass::Cyrillic => matches!(c, '\u{0400}'..='\u{052F}' | '\u{1C80}'..='\u{1C8F}' | '\u{2DE0}'..='\u{2DFF}' | '\u{A640}'..='\u{A69F}'),
+            CharacterClass::NonLatin => c.is_alphabetic()
+                && !matches!(c, 'A'..='Z' | 'a'..='z' | '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' | '\u{2C60}'..='\u{2C7F}' | '\u{A720}'..='\u{A7FF}'),
+            CharacterClass::Bracketed => depth > 0,
+        }
+    }
+}
+
+/// Puts back the characters of `original` in `classes` into `cased`, the words of `original` as
+/// a pattern cased them.
+///
+/// Casing can change how many characters a letter is, as `ß` uppercases to `SS`, so each letter
+/// is matched to as many characters of its cased word as its upper or lowercase form has.
+fn keep_case(original: &[String], cased: &mut [String], classes: &[CharacterClass])
+{
+    let mut depth: usize = 0;
+    for (original, cased) in original.iter().zip(cased.iter_mut())
+    {
+        let cased_chars: Vec<char> = cased.chars().collect();
+        let mut kept = String::with_capacity(cased.len());
+        let mut i = 0;
+        for c in original.chars()
+        {
+            if c == ')' || c == ']' || c == '}'
+            {
+                depth = depth.saturating_sub(1);
+            }
+            let width = [c.to_uppercase().collect::<Vec<_>>(), c.to_lowercase().collect()]
+                .iter()
+                .find(|form| cased_chars[i.min(cased_chars.len())..].starts_with(form))
+                .map_or(1, |form| form.len());
+            let end = (i + width).min(cased_chars.len());
+            match classes.iter().any(|class| class.contains(c, depth))
+            {
+                true => kept.push(c),
+                false => kept.extend(&cased_chars[i.min(end)..end]),
+            }
+            i = end;
+            if c == '(' || c == '[' || c == '{'
+            {
+                depth += 1;
+            }
+        }
+        kept.extend(&cased_chars[i.min(cased_chars.len())..]);
+        *cased = kept;
+    }
+}
+
+const DIGIT_NAMES: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Options for [`convert`] and [`is_case`].
+#[derive(Clone)]
+pub struct CaseOptions
+{
+    /// What to do with delimiters at the start or end of the string.  Conversions strip them and
+    /// case checks reject them unless this says otherwise.
+    pub edge_delimiters: Option<EdgeDelimiters>,
+
+    /// Whether several delimiters in a row separate two words, as they do by default, or also
+    /// an empty word between each pair of them, so that `foo__bar` stays `foo__bar` in snake case.
+    pub collapse_delims: bool,
+
+    /// What to do with a string without any words.  Unless this says otherwise, such a string
+    /// converts like any other, which usually gives the empty string but keeps whitespace other
+    /// than spaces and, with [`EdgeDelimiters::Preserve`], the delimiters.
+    pub empty_input: Option<EmptyInput>,
+
+    /// The most UTF-16 code units, as JS counts the length of a string, a string may have, so
+    /// that a pathologically large one is refused before any work is done on it.
+    pub max_input_length: Option<usize>,
+
+    /// Whether an `_` between digits that starts a group of three, as in `1_000_000`, is kept
+    /// inside the number instead of splitting it into words.
+    pub keep_numeric_separators: bool,
+
+    /// The words, from the first index up to the second, that are converted, counting from the
+    /// end for negative indices.  The rest of the string is kept as it is.
+    pub word_range: Option<(isize, isize)>,
+
+    /// What to do with apostrophes in words, which are kept unless this says otherwise.
+    pub apostrophes: Option<Apostrophes>,
+
+    /// Whether a word that follows one ending in a digit starts with an uppercase letter, as in
+    /// `utf8String`, when the pattern capitalizes it, or a lowercase one, as in `utf8string`.
+    /// This only matters when words are joined without a delimiter, as in camel and pascal case.
+    pub capitalize_after_digit: bool,
+
+    /// What to do with a string whose first word starts with a digit, which is kept unless this
+    /// says otherwise.
+    pub leading_digit: Option<LeadingDigit>,
+
+    /// How to rename a converted string that is a reserved word, if it should be.
+    pub reserved_words: Option<Avoidance>,
+
+    /// The seed the `Random` and `PseudoRandom` patterns draw from, so that they case the same
+    /// words the same way every time.  Without one, they use the thread's random generator.
+    pub seed: Option<u64>,
+
+    /// The characters whose case is kept as it is while patterns case the rest of the string.
+    pub keep_case: Vec<CharacterClass>,
+}
+
+impl Default for CaseOptions
+{
+    fn default() -> Self
+    {
+        CaseOptions {
+            edge_delimiters: None,
+            collapse_delims: true,
+            empty_input: None,
+            max_input_length: None,
+            keep_numeric_separators: false,
+            word_range: None,
+            apostrophes: None,
+            capitalize_after_digit: true,
+            leading_digit: None,
+            reserved_words: None,
+            seed: None,
+            keep_case: Vec::new(),
+        }
+    }
+}
+
+/// A string split into words, with the delimiters at its edges that are kept.
+pub struct Split<'a>
+{
+    pub leading: &'a str,
+    pub words: Vec<String>,
+    pub trailing: &'a str,
+}
+
+impl Split<'_>
+{
+    /// Joins `words`, which stand for the words of the split, with `delim`, between the kept
+    /// delimiters.
+    pub fn join(&self, words: &[String], delim: &str) -> String
+    {
+        format!("{}{}{}", self.leading, words.join(delim), self.trailing)
+    }
+}
+
+/// The delimiters that `boundaries` split on.
+fn delimiters(boundaries: &[Boundary]) -> Vec<char>
+{
+    [(Boundary::Underscore, '_'), (Boundary::Hyphen, '-'), (Boundary::Space, ' ')].iter()
+        .filter(|(boundary, _)| boundaries.contains(boundary))
+        .map(|(_, delimiter)| *delimiter)
+        .collect()
+}
+
+/// Splits `input` into its leading delimiters, the rest, and its trailing delimiters, counting
+/// only the delimiters `boundaries` split on.
+pub fn split_edges<'a>(input: &'a str, boundaries: &[Boundary]) -> (&'a str, &'a str, &'a str)
+{
+    let delimiters = delimiters(boundaries);
+    let rest = input.trim_start_matches(&delimiters[..]);
+    let leading = &input[..input.len() - rest.len()];
+    let middle = rest.trim_end_matches(&delimiters[..]);
+    (leading, middle, &rest[middle.len()..])
+}
+
+/// Stands in for the numeric separators [`protect_numeric_separators`] keeps while the rest of a
+/// string is split, as a character no boundary splits on.
+const NUMERIC_SEPARATOR: char = '\u{E000}';
+
+/// Replaces every `_` of `input` that separates digit groups, one after a digit and before exactly
+/// three digits, with [`NUMERIC_SEPARATOR`].
+fn protect_numeric_separators(input: &str) -> String
+{
+    let chars: Vec<char> = input.chars().collect();
+    let is_digit = |i: usize| chars.get(i).is_some_and(|c| c.is_ascii_digit());
+    (0..chars.len())
+        .map(|i| {
+            let separates = chars[i] == '_' && i > 0 && is_digit(i - 1)
+                && (1..=3).all(|offset| is_digit(i + offset)) && !is_digit(i + 4);
+            if separates { NUMERIC_SEPARATOR } else { chars[i] }
+        })
+        .collect()
+}
+
+/// Stands in for the bracketed spans [`protect_brackets`] keeps whole while the rest of a string
+/// is split, as a character no boundary splits on.
+const BRACKETED: char = '\u{E001}';
+
+/// Replaces every outermost span of `input` inside `()`, `[]`, or `{}`, brackets included, with
+/// [`BRACKETED`], returning the spans in order.  A bracket that is never closed is left as it is.
+fn protect_brackets(input: &str) -> (String, Vec<String>)
+{
+    let mut protected = String::with_capacity(input.len());
+    let mut spans = Vec::new();
+    let mut rest = input;
+    while let Some(open) = rest.find(['(', '[', '{'])
+    {
+        let mut depth: usize = 0;
+        let close = rest[open..].char_indices().find_map(|(i, c)| {
+            match c
+            {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            }
+            (depth == 0).then_some(open + i + c.len_utf8())
+        });
+        let close = match close
+        {
+            Some(close) => close,
+            None => break,
+        };
+        protected.push_str(&rest[..open]);
+        protected.push(BRACKETED);
+        spans.push(rest[open..close].to_string());
+        rest = &rest[close..];
+    }
+    protected.push_str(rest);
+    (protected, spans)
+}
+
+/// Puts the spans [`protect_brackets`] took out of a string back into its words, in order.
+fn restore_brackets(words: Vec<String>, spans: Vec<String>) -> Vec<String>
+{
+    let mut spans = spans.into_iter();
+    words.into_iter()
+        .map(|word| match word.contains(BRACKETED)
+        {
+            true => word.chars()
+                .map(|c| if c == BRACKETED { spans.next().unwrap_or_default() } else { c.to_string() })
+                .collect(),
+            false => word,
+        })
+        .collect()
+}
+
+/// Describes how far `input` is over `options.max_input_length`, or returns `None` if it isn't.
+pub fn input_too_long(input: &str, options: &CaseOptions) -> Option<String>
+{
+    let max = options.max_input_length?;
+
+    // A string never has more UTF-16 code units than UTF-8 bytes, so most don't need counting
+    if input.len() <= max
+    {
+        return None;
+    }
+    let length = input.encode_utf16().count();
+    (length > max).then(|| format!("The input is {} characters long, over the limit of {}", length, max))
+}
+
+/// Reads a `maxInputLength` from a JS number, which has to be an integer of at least 0, or
+/// `Infinity` for no limit, so that a `NaN` or a negative number isn't read as a limit of 0.
+pub fn max_input_length(limit: f64) -> Result<usize, String>
+{
+    match limit >= 0.0 && limit == limit.floor()
+    {
+        true => Ok(limit as usize),
+        false => Err(format!("The input length limit must be an integer of at least 0, not {}", limit)),
+    }
+}
+
+/// The words `conv` splits `input` into when it converts it on its own, without any options.
+pub fn words(conv: &Converter, input: &str) -> Vec<String>
+{
+    let words = Converter::new().set_boundaries(&conv.boundaries).set_delim("\0").convert(input);
+    if words.is_empty() { Vec::new() } else { words.split('\0').map(String::from).collect() }
+}
+
+/// Splits `input` into words on the boundaries of `conv` as `options` say to.
+pub fn split<'a>(input: &'a str, conv: &Converter, options: &CaseOptions) -> Result<Split<'a>, String>
+{
+    if let Some(empty_input) = options.empty_input
+    {
+        if input.chars().all(|c| c.is_whitespace() || c == '_' || c == '-')
+        {
+            return match empty_input
+            {
+                EmptyInput::Unchanged => Ok(Split { leading: input, words: Vec::new(), trailing: "" }),
+                EmptyInput::Empty => Ok(Split { leading: "", words: Vec::new(), trailing: "" }),
+                EmptyInput::Throw => Err(format!("\"{}\" has no words", input)),
+            };
+        }
+    }
+
+    let (leading, middle, trailing) = split_edges(input, &conv.boundaries);
+    let (leading, trailing) = match options.edge_delimiters.unwrap_or(EdgeDelimiters::Strip)
+    {
+        EdgeDelimiters::Strip => ("", ""),
+        EdgeDelimiters::Preserve => (leading, trailing),
+        EdgeDelimiters::Reject if !leading.is_empty() || !trailing.is_empty() =>
+        {
+            return Err(format!("\"{}\" starts or ends with a delimiter", input));
+        }
+        EdgeDelimiters::Reject => ("", ""),
+    };
+
+    // The separator stand-in can't be told from the same character in the input, so numbers in
+    // strings that already have one are split as usual
+    let protected;
+    let middle = match options.keep_numeric_separators && !middle.contains(NUMERIC_SEPARATOR)
+    {
+        true =>
+        {
+            protected = protect_numeric_separators(middle);
+            protected.as_str()
+        }
+        false => middle,
+    };
+
+    // Likewise for bracketed spans kept whole
+    let bracketed;
+    let mut spans = Vec::new();
+    let middle = match options.keep_case.contains(&CharacterClass::Bracketed) && !middle.contains(BRACKETED)
+    {
+        true =>
+        {
+            (bracketed, spans) = protect_brackets(middle);
+            bracketed.as_str()
+        }
+        false => middle,
+    };
+
+    let splitter = Converter::new().set_boundaries(&conv.boundaries).set_delim("\0");
+    let split_words = |piece: &str| -> Vec<String> {
+        splitter.convert(piece)
+            .split('\0')
+            .filter(|word| !word.is_empty())
+            .map(|word| word.replace(NUMERIC_SEPARATOR, "_"))
+            .collect()
+    };
+
+    // Without collapsing, every delimiter separates two words, so the pieces between them are
+    // split on the other boundaries on their own and the empty ones kept
+    let words = match options.collapse_delims
+    {
+        true => split_words(middle),
+        false if middle.is_empty() => Vec::new(),
+        false => middle.split(&delimiters(&conv.boundaries)[..])
+            .flat_map(|piece| if piece.is_empty() { vec![String::new()] } else { split_words(piece) })
+            .collect(),
+    };
+
+    let is_apostrophe = |c: char| c == '\'' || c == '\u{2019}';
+    let words = match options.apostrophes.unwrap_or(Apostrophes::Keep)
+    {
+        Apostrophes::Keep => words,
+        Apostrophes::Strip => words.into_iter()
+            .filter(|word| word.is_empty() || !word.chars().all(is_apostrophe))
+            .map(|word| word.replace(is_apostrophe, ""))
+            .collect(),
+        Apostrophes::Split => words.into_iter()
+            .flat_map(|word| match word.is_empty()
+            {
+                true => vec![word],
+                false => word.split(is_apostrophe).filter(|piece| !piece.is_empty()).map(String::from).collect(),
+            })
+            .collect(),
+    };
+
+    let mut words = restore_brackets(words, spans);
+    let leading_digits = words.first().map_or(0, |word| word.chars().take_while(char::is_ascii_digit).count());
+    if leading_digits > 0
+    {
+        match options.leading_digit.unwrap_or(LeadingDigit::Keep)
+        {
+            LeadingDigit::PrefixUnderscore => words[0].insert(0, '_'),
+            LeadingDigit::SpellOut =>
+            {
+                let first = words.remove(0);
+                let rest = &first[leading_digits..];
+                let spelled = first[..leading_digits].chars()
+                    .map(|digit| DIGIT_NAMES[digit as usize - '0' as usize].to_string())
+                    .chain((!rest.is_empty()).then(|| rest.to_string()));
+                words.splice(0..0, spelled);
+            }
+            LeadingDigit::Keep => (),
+            LeadingDigit::Error => return Err(format!("\"{}\" starts with a digit", input)),
+        }
+    }
+
+    Ok(Split { leading, words, trailing })
+}
+
+/// Resolves `index` into `0..=len`, counting from the end if it is negative.
+fn resolve_index(index: isize, len: usize) -> usize
+{
+    match index < 0
+    {
+        true => len.saturating_sub(index.unsigned_abs()),
+        false => (index as usize).min(len),
+    }
+}
+
+/// Joins the words of `split`, the split of `input`, as `conv` writes them, or only those in
+/// `options.word_range` if it is given, and renames the result if it is a reserved word
+/// `options` says to avoid.
+fn join(input: &str, split: &Split, conv: &Converter, options: &CaseOptions) -> String
+{
+    let joined = join_words(input, split, conv, options);
+    match &options.reserved_words
+    {
+        Some(avoidance) => reserved::avoid_reserved(joined, avoidance),
+        None => joined,
+    }
+}
+
+fn join_words(input: &str, split: &Split, conv: &Converter, options: &CaseOptions) -> String
+{
+    let split_words: Vec<&str> = split.words.iter().map(String::as_str).collect();
+    let mut words = match (conv.pattern, options.seed)
+    {
+        (Some(pattern), Some(seed)) => random::mutate(pattern, &split_words, seed).unwrap_or_else(|| pattern.mutate(&split_words)),
+        (Some(pattern), None) => pattern.mutate(&split_words),
+        (None, _) => split.words.clone(),
+    };
+    if !options.capitalize_after_digit && conv.delim.is_empty()
+    {
+        for i in 1..words.len()
+        {
+            if words[i - 1].ends_with(|c: char| c.is_ascii_digit())
+            {
+                let mut chars = words[i].chars();
+                words[i] = match chars.next()
+                {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                };
+            }
+        }
+    }
+    if !options.keep_case.is_empty()
+    {
+        keep_case(&split.words, &mut words, &options.keep_case);
+    }
+    let (start, end) = match options.word_range
+    {
+        Some((start, end)) => (resolve_index(start, words.len()), resolve_index(end, words.len())),
+        None => return split.join(&words, &conv.delim),
+    };
+
+    // Splitting only removes delimiters, so every word is found in order after the last one,
+    // unless apostrophes were stripped from it, which leaves it where the last one ended
+    let (_, middle, _) = split_edges(input, &conv.boundaries);
+    let mut cursor = 0;
+    let mut spans = Vec::new();
+    for word in &split.words
+    {
+        match middle[cursor..].find(word.as_str())
+        {
+            Some(offset) =>
+            {
+                spans.push((cursor + offset, cursor + offset + word.len()));
+                cursor += offset + word.len();
+            }
+            None => spans.push((cursor, cursor)),
+        }
+    }
+    if start >= end
+    {
+        return format!("{}{}{}", split.leading, middle, split.trailing);
+    }
+
+    // The delimiters on either side of the range are between a converted word and another word,
+    // so they are written as the converted words are
+    let prefix = match start
+    {
+        0 => String::new(),
+        _ => format!("{}{}", &middle[..spans[start - 1].1], conv.delim),
+    };
+    let suffix = match end == words.len()
+    {
+        true => String::new(),
+        false => format!("{}{}", conv.delim, &middle[spans[end].0..]),
+    };
+    format!("{}{}{}{}{}", split.leading, prefix, words[start..end].join(&conv.delim), suffix, split.trailing)
+}
+
+/// Converts `input` with `conv` as `options` say to, emitting the words it was split into as a
+/// [`trace`] event.
+pub fn convert(input: &str, conv: &Converter, options: &CaseOptions) -> Result<String, String>
+{
+    let split = split(input, conv, options)?;
+    trace::split(input, &split.words);
+    Ok(join(input, &split, conv, options))
+}
+
+/// What [`convert_detailed`] found converting a string.
+pub struct Detailed
+{
+    pub result: String,
+    /// The words of the string as it writes them.
+    pub words: Vec<String>,
+    /// The boundaries of `conv` found in the string, in the order of [`Boundary::all`].
+    pub boundaries: Vec<Boundary>,
+    /// The first of [`consistency::CONVENTIONS`] the string is in before converting, if any.
+    pub from_case: Option<Case>,
+}
+
+/// Converts `input` with `conv` as `options` say to, also returning the words it was split into,
+/// the boundaries that split it, and the case it was in, for callers that need all of them.
+pub fn convert_detailed(input: &str, conv: &Converter, options: &CaseOptions) -> Result<Detailed, String>
+{
+    let split = split(input, conv, options)?;
+    trace::split(input, &split.words);
+    let result = join(input, &split, conv, options);
+    let mut boundaries = Boundary::list_from(input);
+    boundaries.retain(|boundary| conv.boundaries.contains(boundary));
+    let from_case = consistency::CONVENTIONS.iter().copied().find(|case| is_case(input, *case, options));
+    Ok(Detailed { result, words: split.words, boundaries, from_case })
+}
+
+/// Checks whether `input` is in `case` as `options` say to, which is whether converting it to
+/// `case` leaves it as it is.
+pub fn is_case(input: &str, case: Case, options: &CaseOptions) -> bool
+{
+    let conv = Converter::new().to_case(case);
+    let edges = match options.edge_delimiters.unwrap_or(EdgeDelimiters::Reject)
+    {
+        EdgeDelimiters::Strip | EdgeDelimiters::Preserve => EdgeDelimiters::Preserve,
+        EdgeDelimiters::Reject => EdgeDelimiters::Reject,
+    };
+    let options = CaseOptions { edge_delimiters: Some(edges), ..options.clone() };
+
+    // Only a check, so it doesn't emit the split through `convert`
+    split(input, &conv, &options).is_ok_and(|split| join(input, &split, &conv, &options) == input)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn words(input: &str, options: &CaseOptions) -> Vec<String>
+    {
+        split(input, &Converter::new(), options).unwrap().words
+    }
+
+    #[test]
+    fn bracketed_spans_are_single_words()
+    {
+        let options = CaseOptions { keep_case: vec![CharacterClass::Bracketed], ..CaseOptions::default() };
+        assert_eq!(words("get-(HTTP Status)-code", &options), ["get", "(HTTP Status)", "code"]);
+        assert_eq!(words("get_value(fooBar(x))_[unclosed", &options), ["get", "value(fooBar(x))", "[unclosed"]);
+    }
+
+    #[test]
+    fn numeric_separators_are_kept_between_digits()
+    {
+        let options = CaseOptions { keep_numeric_separators: true, ..CaseOptions::default() };
+        assert_eq!(words("LIMIT_1_000_000", &options), ["LIMIT", "1_000_000"]);
+        assert_eq!(words("v_1_a", &options), ["v", "1", "a"]);
+    }
+
+    #[test]
+    fn input_length_counts_utf16_code_units()
+    {
+        let options = CaseOptions { max_input_length: Some(2), ..CaseOptions::default() };
+        assert!(input_too_long("ab", &options).is_none());
+        assert!(input_too_long("é", &options).is_none());
+        assert!(input_too_long("😀a", &options).is_some());
+    }
+
+    #[test]
+    fn input_length_limits_are_whole_numbers()
+    {
+        assert_eq!(max_input_length(10.0), Ok(10));
+        assert_eq!(max_input_length(f64::INFINITY), Ok(usize::MAX));
+        for limit in [-1.0, 1.5, f64::NAN, f64::NEG_INFINITY]
+        {
+            assert!(max_input_length(limit).is_err());
+        }
+    }
+}
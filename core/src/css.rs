@@ -0,0 +1,52 @@
+use convert_case::{Boundary, Case, Converter};
+
+/// Converts a CSS property name to the camelCase name used by `CSSStyleDeclaration`.
+///
+/// Vendor prefixes become a capitalized first word (`-webkit-transition` becomes
+/// `WebkitTransition`), except for `-ms-`, which stays lowercase (`msTransform`).  Custom
+/// properties (`--main-color`) are returned unchanged.
+pub fn css_property_to_camel(name: &str) -> String
+{
+    if name.starts_with("--")
+    {
+        return name.to_string();
+    }
+
+    let camel = Converter::new().set_boundaries(&[Boundary::Hyphen, Boundary::LowerUpper]);
+    match name.strip_prefix('-')
+    {
+        Some(rest) if rest.starts_with("ms-") => camel.to_case(Case::Camel).convert(rest),
+        Some(rest) => camel.to_case(Case::Pascal).convert(rest),
+        None => camel.to_case(Case::Camel).convert(name),
+    }
+}
+
+/// Converts a camelCase `CSSStyleDeclaration` property name back to its CSS spelling, the inverse
+/// of [`css_property_to_camel`].
+///
+/// A leading capital (`WebkitTransition`) or a leading `ms` word (`msTransform`) marks a vendor
+/// prefix and gets a leading hyphen.  Names that already start with a hyphen are returned
+/// unchanged.
+pub fn camel_to_css_property(name: &str) -> String
+{
+    if name.starts_with('-')
+    {
+        return name.to_string();
+    }
+
+    let kebab = Converter::new()
+        .set_boundaries(&[Boundary::LowerUpper, Boundary::Acronym])
+        .to_case(Case::Kebab)
+        .convert(name);
+
+    let prefixed = name.starts_with(|c: char| c.is_ascii_uppercase())
+        || (name.starts_with("ms") && name[2..].starts_with(|c: char| c.is_ascii_uppercase()));
+    if prefixed
+    {
+        format!("-{}", kebab)
+    }
+    else
+    {
+        kebab
+    }
+}
@@ -0,0 +1,41 @@
+use convert_case::Converter;
+
+/// Converts the field names in the first record of `text`.
+///
+/// The header is parsed and rewritten with proper quoting, keeping its original line terminator;
+/// everything after it is copied byte for byte.  With `header_only`, only the rewritten header
+/// row is returned, without a terminator.
+pub fn convert_csv_header(text: &str, conv: &Converter, delimiter: u8, header_only: bool) -> csv::Result<String>
+{
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let mut header = csv::StringRecord::new();
+    if !reader.read_record(&mut header)?
+    {
+        return Ok(text.to_string());
+    }
+    let header_end = reader.position().byte() as usize;
+
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    writer.write_record(header.iter().map(|field| conv.convert(field)))?;
+    let written = writer.into_inner().map_err(|e| e.into_error())?;
+
+    let mut converted = String::from_utf8(written).expect("CSV writer produced invalid UTF-8");
+    converted.pop();
+
+    if !header_only
+    {
+        let raw_header = &text[..header_end];
+        let terminator_len = raw_header.len() - raw_header.trim_end_matches(['\r', '\n']).len();
+        converted.push_str(&text[header_end - terminator_len..]);
+    }
+
+    Ok(converted)
+}
@@ -0,0 +1,281 @@
+//! Finds the characters a conversion removed or changed other than by casing them, for callers
+//! that have to account for every lossy change to a name.
+
+use std::ops::Range;
+
+/// How a run of characters changed between the strings [`dropped_characters`] compares.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChangeKind
+{
+    Removed,
+    Replaced,
+    Added,
+}
+
+impl ChangeKind
+{
+    pub fn name(self) -> &'static str
+    {
+        match self
+        {
+            ChangeKind::Removed => "removed",
+            ChangeKind::Replaced => "replaced",
+            ChangeKind::Added => "added",
+        }
+    }
+}
+
+/// A run of characters found by [`dropped_characters`].
+pub struct CharacterChange
+{
+    pub kind: ChangeKind,
+    /// Where the run starts in the original string, in UTF-16 code units, as JS strings are
+    /// indexed.  Added characters are at the index of the character after them.
+    pub index: usize,
+    /// The characters of the original string, empty if they were added.
+    pub original: String,
+    /// What the characters became, empty if they were removed.
+    pub replacement: String,
+}
+
+fn is_delimiter(c: char, delim: &str) -> bool
+{
+    c == '_' || c == '-' || c == ' ' || delim.contains(c)
+}
+
+/// Whether `a` of the original string and `b` of the converted one are the same character but
+/// for its case, or a delimiter replaced by another.
+fn same(a: char, b: char, delim: &str) -> bool
+{
+    a == b || a.to_lowercase().eq(b.to_lowercase()) || (is_delimiter(a, delim) && is_delimiter(b, delim))
+}
+
+/// Aligns two strings on the longest sequence of characters they share with Myers' diff, which
+/// splits them at the middle of their shortest edit and aligns each half in turn, so that it only
+/// needs space proportional to their lengths.
+struct Alignment<'a>
+{
+    a: &'a [char],
+    b: &'a [char],
+    delim: &'a str,
+    /// The furthest position in `a` reached on each diagonal, forward and backward.
+    forward: Vec<isize>,
+    backward: Vec<isize>,
+    /// The positions in `a` and `b` of every character they share, in order.
+    matches: Vec<(usize, usize)>,
+}
+
+impl<'a> Alignment<'a>
+{
+    fn same(&self, i: usize, j: usize) -> bool
+    {
+        same(self.a[i], self.b[j], self.delim)
+    }
+
+    fn align(&mut self, mut a: Range<usize>, mut b: Range<usize>)
+    {
+        while !a.is_empty() && !b.is_empty() && self.same(a.start, b.start)
+        {
+            self.matches.push((a.start, b.start));
+            a.start += 1;
+            b.start += 1;
+        }
+        let mut suffix = 0;
+        while a.len() > suffix && b.len() > suffix && self.same(a.end - suffix - 1, b.end - suffix - 1)
+        {
+            suffix += 1;
+        }
+        a.end -= suffix;
+        b.end -= suffix;
+
+        // What is left starts and ends with a difference, so if neither side is empty it takes at
+        // least two edits, and each half of it at least one
+        if !a.is_empty() && !b.is_empty()
+        {
+            let (x, y) = self.middle(a.clone(), b.clone());
+            self.align(a.start..x, b.start..y);
+            self.align(x..a.end, y..b.end);
+        }
+        self.matches.extend((0..suffix).map(|k| (a.end + k, b.end + k)));
+    }
+
+    /// Finds a point on a shortest edit of `a` into `b` with as many edits before it as after,
+    /// by following edits from both ends at once until they meet.
+    fn middle(&mut self, a: Range<usize>, b: Range<usize>) -> (usize, usize)
+    {
+        let (n, m) = (a.len() as isize, b.len() as isize);
+        let delta = n - m;
+        let odd = delta % 2 != 0;
+        let max = (n + m + 1) / 2;
+        let offset = max + 1;
+        let diagonal = |k: isize| (k + offset) as usize;
+        self.forward.clear();
+        self.forward.resize(2 * offset as usize + 1, 0);
+        self.backward.clear();
+        self.backward.resize(2 * offset as usize + 1, 0);
+
+        for d in 0..=max
+        {
+            for k in (-d..=d).step_by(2)
+            {
+                let mut x = match k == -d || (k != d && self.forward[diagonal(k - 1)] < self.forward[diagonal(k + 1)])
+                {
+                    true => self.forward[diagonal(k + 1)],
+                    false => self.forward[diagonal(k - 1)] + 1,
+                };
+                let mut y = x - k;
+                while x < n && y < m && self.same(a.start + x as usize, b.start + y as usize)
+                {
+                    x += 1;
+                    y += 1;
+                }
+                self.forward[diagonal(k)] = x;
+                if odd && (k - delta).abs() < d && x + self.backward[diagonal(delta - k)] >= n
+                {
+                    return (a.start + x as usize, b.start + y as usize);
+                }
+            }
+
+            for k in (-d..=d).step_by(2)
+            {
+                let mut x = match k == -d || (k != d && self.backward[diagonal(k - 1)] < self.backward[diagonal(k + 1)])
+                {
+                    true => self.backward[diagonal(k + 1)],
+                    false => self.backward[diagonal(k - 1)] + 1,
+                };
+                let mut y = x - k;
+                while x < n && y < m && self.same(a.end - x as usize - 1, b.end - y as usize - 1)
+                {
+                    x += 1;
+                    y += 1;
+                }
+                self.backward[diagonal(k)] = x;
+                if !odd && (k - delta).abs() <= d && x + self.forward[diagonal(delta - k)] >= n
+                {
+                    return (a.end - x as usize, b.end - y as usize);
+                }
+            }
+        }
+        unreachable!("the edits from both ends meet within half of the longest edit")
+    }
+}
+
+/// Compares `before` with `after`, what converting it with a delimiter of `delim` gave, and
+/// returns each run of characters that was removed, replaced, or added, in order.  Characters
+/// that only changed case, and delimiters replaced by `delim` or another delimiter, aren't
+/// reported.
+///
+/// The strings are aligned on the longest sequence of characters they share, which takes time
+/// proportional to their lengths times the number of characters that differ, and space
+/// proportional to their lengths.
+pub fn dropped_characters(before: &str, after: &str, delim: &str) -> Vec<CharacterChange>
+{
+    let (a, b): (Vec<char>, Vec<char>) = (before.chars().collect(), after.chars().collect());
+    let mut alignment = Alignment { a: &a, b: &b, delim, forward: Vec::new(), backward: Vec::new(), matches: Vec::new() };
+    alignment.align(0..a.len(), 0..b.len());
+
+    let mut changes = Vec::new();
+    let (mut i, mut j, mut index) = (0, 0, 0);
+    let end = (a.len(), b.len());
+    for (next_i, next_j) in alignment.matches.into_iter().chain(Some(end))
+    {
+        let kind = match (i == next_i, j == next_j)
+        {
+            (true, true) => None,
+            (false, true) => Some(ChangeKind::Removed),
+            (false, false) => Some(ChangeKind::Replaced),
+            (true, false) => Some(ChangeKind::Added),
+        };
+        if let Some(kind) = kind
+        {
+            let original = a[i..next_i].iter().collect();
+            let replacement = b[j..next_j].iter().collect();
+            changes.push(CharacterChange { kind, index, original, replacement });
+        }
+
+        index += a[i..next_i].iter().map(|c| c.len_utf16()).sum::<usize>();
+        if (next_i, next_j) != end
+        {
+            index += a[next_i].len_utf16();
+        }
+        (i, j) = (next_i + 1, next_j + 1);
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn changes(before: &str, after: &str) -> Vec<(ChangeKind, usize, String, String)>
+    {
+        dropped_characters(before, after, "_")
+            .into_iter()
+            .map(|change| (change.kind, change.index, change.original, change.replacement))
+            .collect()
+    }
+
+    fn change(kind: ChangeKind, index: usize, original: &str, replacement: &str) -> (ChangeKind, usize, String, String)
+    {
+        (kind, index, original.to_string(), replacement.to_string())
+    }
+
+    #[test]
+    fn casing_and_delimiters_are_not_changes()
+    {
+        assert!(changes("user-Id", "USER_ID").is_empty());
+        assert!(changes("", "").is_empty());
+    }
+
+    #[test]
+    fn runs_are_reported_in_order()
+    {
+        assert_eq!(changes("_Don't stop", "dont_stop"), [change(ChangeKind::Removed, 0, "_", ""), change(ChangeKind::Removed, 4, "'", "")]);
+        assert_eq!(changes("3d", "three_d"), [change(ChangeKind::Replaced, 0, "3", "three_")]);
+        assert_eq!(changes("ab", "a_b"), [change(ChangeKind::Added, 1, "", "_")]);
+        assert_eq!(changes("abc", ""), [change(ChangeKind::Removed, 0, "abc", "")]);
+    }
+
+    #[test]
+    fn indexes_count_utf16_code_units()
+    {
+        assert_eq!(changes("😀a!", "😀a"), [change(ChangeKind::Removed, 3, "!", "")]);
+    }
+
+    /// The length of the longest sequence `a` and `b` share, by the table Myers' diff avoids.
+    fn common_length(a: &[char], b: &[char]) -> usize
+    {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len()
+        {
+            for j in 0..b.len()
+            {
+                table[i + 1][j + 1] = match same(a[i], b[j], "_")
+                {
+                    true => table[i][j] + 1,
+                    false => table[i][j + 1].max(table[i + 1][j]),
+                };
+            }
+        }
+        table[a.len()][b.len()]
+    }
+
+    #[test]
+    fn alignments_are_as_long_as_the_longest_common_sequence()
+    {
+        let words = ["", "a", "ab", "ba", "a_b", "xyz", "aXbY", "b-a", "abcabba", "cbabac", "zz_zz"];
+        for before in words
+        {
+            for after in words
+            {
+                let (a, b): (Vec<char>, Vec<char>) = (before.chars().collect(), after.chars().collect());
+                let mut alignment = Alignment { a: &a, b: &b, delim: "_", forward: Vec::new(), backward: Vec::new(), matches: Vec::new() };
+                alignment.align(0..a.len(), 0..b.len());
+                assert_eq!(alignment.matches.len(), common_length(&a, &b), "{:?} and {:?}", before, after);
+                assert!(alignment.matches.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! TypeScript declarations generated from the Rust definitions, so that the ids JS passes across
+//! the boundary can't drift from the enums they are read as.
+//!
+//! The addon's build script writes these to `index.node.d.ts` in its `OUT_DIR`, and
+//! `npm run build` copies that next to the addon itself.  The WebAssembly build's build script
+//! writes the same declarations for `wasm-bindgen` to add to the ones it generates.
+
+use std::fmt::Write;
+
+use convert_case::{Boundary, Case};
+
+use crate::ids;
+
+/// Renders `Case`, `Pattern`, and `Boundary` as `export const enum`s, with every member set to
+/// the id of the Rust variant of the same name.
+pub fn enums() -> String
+{
+    let mut cases = Case::all_cases();
+    cases.sort_by_key(|case| *case as u8);
+    let cases = cases.into_iter().map(|case| (format!("{:?}", case), case as u8));
+
+    let mut patterns = ids::patterns();
+    patterns.sort_by_key(|pattern| *pattern as u8);
+    let patterns = patterns.into_iter().map(|pattern| (format!("{:?}", pattern), pattern as u8));
+
+    let mut boundaries = Boundary::all();
+    boundaries.sort_by_key(|boundary| *boundary as u8);
+    let boundaries = boundaries.into_iter().map(|boundary| (format!("{:?}", boundary), boundary as u8));
+
+    [render_enum("Case", cases), render_enum("Pattern", patterns), render_enum("Boundary", boundaries)].join("\n")
+}
+
+/// The types the signatures of [`functions`] share.
+pub const TYPES: &str = r#"
+export type CollisionPolicy = "lastWins" | "firstWins" | "suffix" | "error";
+export type KeyCollision = {path: string, keys: string[], name: string};
+export type KeyOptions = {
+    deep?: boolean,
+    maxDepth?: number,
+    include?: string[],
+    exclude?: string[],
+    values?: string[],
+    collisions?: CollisionPolicy,
+    onCollision?: (collision: KeyCollision) => void,
+    strict?: boolean,
+    renameAllCompat?: boolean,
+    mappings?: boolean,
+};
+export type KeyMappings<T> = {result: T, forwardMap: Map<string, string>, reverseMap: Map<string, string>};
+export type NdjsonHandle = {readonly __brand: "NdjsonHandle"};
+export type AccessorLang = "java" | "csharp" | "javascript";
+export type TraceEvent =
+    | {kind: "split", input: string, words: string[]}
+    | {kind: "cacheHit", input: string, result: string}
+    | {kind: "fallback", function: string, input: string, reason: string};
+export type IdRange = {min: number, max: number};
+export type EdgeDelimiters = "strip" | "preserve" | "reject";
+export type EmptyInput = "unchanged" | "empty" | "throw";
+export type Apostrophes = "keep" | "strip" | "split";
+export type LeadingDigit = "prefixUnderscore" | "spellOut" | "keep" | "error";
+export type KeepCase = "greek" | "cyrillic" | "nonLatin" | "bracketed";
+export type ReservedLanguage = "javascript" | "rust" | "python" | "sql";
+export type ReservedWords = {language: ReservedLanguage, prefix?: string, suffix?: string, replacements?: Record<string, string>};
+export type CaseOptions = {edgeDelimiters?: EdgeDelimiters, collapseDelims?: boolean, emptyInput?: EmptyInput, maxInputLength?: number, keepNumericSeparators?: boolean, wordRange?: [number, number], apostrophes?: Apostrophes, capitalizeAfterDigit?: boolean, leadingDigit?: LeadingDigit, reservedWords?: ReservedWords, seed?: number | bigint, keepCase?: KeepCase[]};
+"#;
+
+/// A function to declare, with its signature written as the parameter list and return type, as
+/// in `"(input: string): string"`.
+pub struct Function
+{
+    pub name: &'static str,
+    pub signature: &'static str,
+    /// The `cfg` the function is exported under, if it isn't always exported, as in
+    /// `cfg(feature = "yaml")`.
+    pub cfg: Option<&'static str>,
+}
+
+/// Renders a whole declaration file: a header, the enums, `types`, and then an
+/// `export function` for each of `functions`.
+///
+/// Functions behind a `cfg` are declared too, noting the `cfg`, so that the file is the same
+/// whichever features the addon was built with.
+pub fn module(types: &str, functions: &[Function]) -> String
+{
+    let mut dts = String::from("// Generated by a build script from the Rust definitions.  Do not edit.\n\n");
+    dts.push_str(&enums());
+    dts.push('\n');
+    dts.push_str(types.trim());
+    dts.push_str("\n\n");
+    for function in functions
+    {
+        if let Some(cfg) = function.cfg
+        {
+            writeln!(dts, "/** Only exported by builds with `{}`. */", cfg).unwrap();
+        }
+        writeln!(dts, "export function {}{};", function.name, function.signature).unwrap();
+    }
+    dts
+}
+
+/// Declares a [`Function`] for each entry of [`for_each_export!`](crate::for_each_export).
+macro_rules! declare
+{
+    ($($(#[$attr:meta])* $name:literal => $function:ident $signature:literal;)*) =>
+    {
+        vec![$(
+            Function {
+                name: $name,
+                signature: $signature,
+                cfg: None$(.or(Some(stringify!($attr))))*,
+            },
+        )*]
+    };
+}
+
+/// Every function the bindings export, whichever features this crate was built with.
+pub fn functions() -> Vec<Function>
+{
+    crate::for_each_export!(declare)
+}
+
+fn render_enum(name: &str, members: impl Iterator<Item = (String, u8)>) -> String
+{
+    let mut rendered = format!("export const enum {}\n{{\n", name);
+    for (member, id) in members
+    {
+        writeln!(rendered, "    {} = {},", member, id).unwrap();
+    }
+    rendered.push_str("}\n");
+    rendered
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn declares_each_function_once()
+    {
+        let mut names: Vec<&str> = functions().iter().map(|function| function.name).collect();
+        let declared = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), declared);
+    }
+}
@@ -0,0 +1,1965 @@
+//! The functions the bindings export, written once over [`Js`] for the Node addon, the napi-rs
+//! build, and the WebAssembly build.
+//!
+//! Each function takes the arguments JS passed, reads them and their options, calls into the
+//! other modules, and builds its result, so that the bindings only convert between their own
+//! handles and JS values.  [`for_each_export!`](crate::for_each_export) lists them with the names
+//! they are exported under.  The NDJSON handles are the one thing each binding makes and reads
+//! itself, so [`ndjson_new`], [`ndjson_write`], and [`ndjson_end`] work on the stream a handle
+//! keeps rather than on the handle.
+
+#[cfg(feature = "json")]
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use convert_case::{Boundary, Case, Converter};
+
+use crate::js::{Js, JsResult, State, Thrown, Type};
+use crate::key_walker::KeyWalker;
+use crate::keys::KeyOptions;
+use crate::{
+    accessors, acronyms, adjust, cache, collisions, columns, consistency, conversion, css, dropped,
+    find, graphql, headers, html, ids, info, jsx, k8s, lines, lint, macros, mapping, matching,
+    packages, paths, presets, proto, query, rename_all, rename_map, renames, reserved, resource,
+    reversibility, segmentation, sql, trace, truncation,
+};
+#[cfg(feature = "csv")]
+use crate::delimited;
+#[cfg(feature = "json")]
+use crate::{json, openapi};
+#[cfg(feature = "toml")]
+use crate::toml;
+#[cfg(feature = "yaml")]
+use crate::yaml;
+
+/// Passes every function the bindings export to the macro `$callback`, as entries of the form
+/// `"name" => function "(signature)";`: the name JS calls it by, the function of
+/// [`exports`](crate::exports) behind it, and the TypeScript signature it is declared with.
+///
+/// Entries only exported by builds with a feature carry its `cfg`, which is checked in the crate
+/// `$callback` expands in, so each binding exports exactly the functions its features allow and
+/// each declaration file declares all of them.
+#[macro_export]
+macro_rules! for_each_export
+{
+    ($callback:ident) =>
+    {
+        $callback! {
+            "toCase" => to_case "(input: string, caseType: Case, fromCase?: Case | \"auto\", options?: CaseOptions & {pattern?: Pattern}): string";
+            "isCase" => is_case "(input: string, caseType: Case, options?: CaseOptions): boolean";
+            "mutate" => mutate "(input: string, options: CaseOptions & {pattern?: Pattern | ((word: string, index: number) => string), boundaries?: Boundary[], defaultBoundaries?: boolean, delim?: string}): string";
+            "listFrom" => list_from "(input: string, candidates?: Boundary[]): Boundary[]";
+            "convertLineKeys" => convert_line_keys "(text: string, caseType: Case, options: {separator?: string}): string";
+            "convertHtmlAttributes" => convert_html_attributes "(html: string, caseType: Case, options: {excludeData?: boolean, excludeAria?: boolean}): string";
+            "convertKeys" => convert_keys "(value: unknown, caseType: Case, options: KeyOptions): any";
+            #[cfg(feature = "json")]
+            "convertJsonKeys" => convert_json_keys "(json: string, caseType: Case, options: KeyOptions & {pretty?: boolean}): string | KeyMappings<string>";
+            #[cfg(feature = "json")]
+            "ndjsonNew" => ndjson_new "(caseType: Case, options: KeyOptions): NdjsonHandle";
+            #[cfg(feature = "json")]
+            "ndjsonWrite" => ndjson_write "(handle: NdjsonHandle, chunk: string): string";
+            #[cfg(feature = "json")]
+            "ndjsonEnd" => ndjson_end "(handle: NdjsonHandle): string";
+            #[cfg(feature = "yaml")]
+            "convertYamlKeys" => convert_yaml_keys "(yaml: string, caseType: Case): string";
+            #[cfg(feature = "toml")]
+            "convertTomlKeys" => convert_toml_keys "(toml: string, caseType: Case): string";
+            #[cfg(feature = "csv")]
+            "convertCsvHeader" => convert_csv_header "(csv: string, caseType: Case, options: {delimiter?: string, headerOnly?: boolean}): string";
+            "convertQueryKeys" => convert_query_keys "(query: string, caseType: Case): string";
+            "renameColumns" => rename_columns "(rows: object[], columns: Case | [string, string][]): any[]";
+            "canonicalizeHeader" => canonicalize_header "(name: string): string";
+            "cssPropertyToCamel" => css_property_to_camel "(name: string): string";
+            "camelToCssProperty" => camel_to_css_property "(name: string): string";
+            "htmlToJsxProp" => html_to_jsx_prop "(name: string): string";
+            "jsxToHtmlAttribute" => jsx_to_html_attribute "(name: string): string";
+            "htmlAttributesToJsx" => html_attributes_to_jsx "(html: string): string";
+            "convertGraphqlFields" => convert_graphql_fields "(sdl: string, caseType: Case, options: {fieldMap?: boolean}): string | {sdl: string, fieldMap: Record<string, Record<string, string>>}";
+            "convertDdlIdentifiers" => convert_ddl_identifiers "(sql: string, caseType: Case): string";
+            "protoJsonName" => proto_json_name "(field: string): string";
+            "protoFieldName" => proto_field_name "(jsonName: string): string";
+            "toK8sName" => to_k8s_name "(input: string): {name: string, truncated: boolean}";
+            "toResourceName" => to_resource_name "(input: string, options: {case?: Case, maxLength?: number, charset?: string, hashSuffixOnTruncate?: boolean}): string";
+            #[cfg(feature = "json")]
+            "convertOpenApiProperties" => convert_openapi_properties "(json: string, caseType: Case, options: {pretty?: boolean}): string";
+            "planRenames" => plan_renames "(paths: string[], caseType: Case): {steps: {from: string, to: string}[], collisions: {target: string, sources: string[]}[]}";
+            "toPackageName" => to_package_name "(input: string, options: {registry?: \"npm\" | \"crates\" | \"pypi\"}): string";
+            "toMacroName" => to_macro_name "(input: string): string";
+            "toIncludeGuard" => to_include_guard "(path: string): string";
+            "preset" => preset "(name: string): {boundaries: Boundary[], pattern: Pattern, delim: string}";
+            "toGetterName" => to_getter_name "(field: string, options: {lang?: AccessorLang, boolean?: boolean}): string";
+            "toSetterName" => to_setter_name "(field: string, options: {lang?: AccessorLang, boolean?: boolean}): string";
+            "fieldFromAccessor" => field_from_accessor "(name: string, options: {lang?: AccessorLang}): string | null";
+            "setTraceCallback" => set_trace_callback "(callback?: (event: TraceEvent) => void): void";
+            "setMaxInputLength" => set_max_input_length "(maxInputLength?: number): void";
+            "nativeInfo" => native_info "(): {version: string, convertCaseVersion: string, features: string[], unicodeVersion: string, caseIds: IdRange, patternIds: IdRange, boundaryIds: IdRange}";
+            "caseConsistencyReport" => case_consistency_report "(identifiers: string[], options?: CaseOptions): {counts: {case: Case, count: number}[], convention: Case | null, outliers: {identifier: string, cases: Case[], mixed: string | null, suggestion: string}[]}";
+            "findMixedConventions" => find_mixed_conventions "(identifiers: string[]): {index: number, identifier: string, reason: string}[]";
+            "lintIdentifiers" => lint_identifiers "(identifiers: string[], caseType: Case, options: {allow?: string[], allowLeadingUnderscore?: boolean}): {index: number, identifier: string, severity: \"error\" | \"warning\", problems: {boundary: Boundary | null, message: string}[], fix: string}[]";
+            "buildRenameMap" => build_rename_map "(identifiers: string[], caseType: Case): [string, string][]";
+            "findIdentifier" => find_identifier "(text: string, name: string): {start: number, end: number, text: string, cases: Case[]}[]";
+            "matchIdentifiers" => match_identifiers "(query: string, candidates: string[], options: {limit?: number, minScore?: number}): {candidate: string, index: number, score: number}[]";
+            "segmentationStats" => segmentation_stats "(inputs: string[], options: {boundaries?: Boundary[]}): {input: string, wordCount: number, words: string[], characterClasses: string[], boundaries: {boundary: Boundary, count: number}[]}[]";
+            "isReversible" => is_reversible "(input: string, fromCase: Case, toCase: Case): {reversible: boolean, forward: string, back: string, losses: {kind: string, message: string}[]}";
+            "groupBySignature" => group_by_signature "(identifiers: string[]): {words: string[], identifiers: string[]}[]";
+            "discoverAcronyms" => discover_acronyms "(identifiers: string[], options: {minUses?: number, minConsistency?: number}): {acronym: string, uses: number, consistency: number}[]";
+            "diffIdentifiers" => diff_identifiers "(a: string, b: string): {change: string, from: string | null, to: string | null}[]";
+            "suggestCase" => suggest_case "(identifier: string, context: string[]): Case | null";
+            "toCaseDetailed" => to_case_detailed "(input: string, caseType: Case, fromCase?: Case | \"auto\", options?: CaseOptions & {pattern?: Pattern}): {result: string, words: string[], boundaries: Boundary[], fromCaseDetected: Case | null}";
+            "applyToWord" => apply_to_word "(input: string, index: number, pattern: Pattern): string";
+            "reservedWords" => reserved_words "(language: ReservedLanguage): string[]";
+            "isReservedWord" => is_reserved_word "(word: string, language: ReservedLanguage): boolean";
+            "truncateWords" => truncate_words "(input: string, maxLength: number, options: {case?: Case, ellipsis?: string}): string";
+            "droppedCharacters" => dropped_characters "(before: string, after: string, delim?: string): {kind: string, index: number, original: string, replacement: string}[]";
+            "clearCache" => clear_cache "(): void";
+            "setCacheSize" => set_cache_size "(size: number): void";
+            "cacheStats" => cache_stats "(): {entries: number, size: number, hits: number, misses: number, hitRate: number, memoryEstimate: number}";
+        }
+    };
+}
+
+/// Builds a plain object out of `key: value` pairs, whose values are built in order.
+macro_rules! object
+{
+    ($js:expr, { $($key:literal: $value:expr),* $(,)? }) =>
+    {{
+        let object = $js.new_object(false)?;
+        $(
+            let value = $value;
+            $js.define(&object, $key, value)?;
+        )*
+        object
+    }};
+}
+
+fn error<J: Js, T>(js: &mut J, message: impl Into<String>) -> JsResult<J, T>
+{
+    Err(js.throw(Thrown::Error(message.into())))
+}
+
+fn range_error<J: Js, T>(js: &mut J, message: impl Into<String>) -> JsResult<J, T>
+{
+    Err(js.throw(Thrown::RangeError(message.into())))
+}
+
+fn type_error<J: Js, T>(js: &mut J, message: impl Into<String>) -> JsResult<J, T>
+{
+    Err(js.throw(Thrown::TypeError(message.into())))
+}
+
+/// Throws the `TypeError` for a value that isn't a `name`, with the message neon throws for one.
+fn downcast_error<J: Js, T>(js: &mut J, name: &str) -> JsResult<J, T>
+{
+    type_error(js, format!("failed to downcast any to {}", name))
+}
+
+/// The value at `index` of the arguments, or of the elements of an array, which is `undefined`
+/// if there isn't one.
+fn argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J>
+{
+    match args.get(index)
+    {
+        Some(arg) => Ok(arg.clone()),
+        None => js.undefined(),
+    }
+}
+
+/// The argument at `index`, throwing if fewer arguments were passed, as neon's `argument` does.
+/// Bindings that always pass as many arguments as a function declares read one left out as
+/// `undefined` instead.
+fn required_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J>
+{
+    match args.get(index)
+    {
+        Some(arg) => Ok(arg.clone()),
+        None => type_error(js, "not enough arguments"),
+    }
+}
+
+fn string_value<J: Js>(js: &mut J, value: &J::Value) -> JsResult<J, String>
+{
+    match js.string(value)?
+    {
+        Some(string) => Ok(string),
+        None => downcast_error(js, "string"),
+    }
+}
+
+fn number_value<J: Js>(js: &mut J, value: &J::Value) -> JsResult<J, f64>
+{
+    match js.number(value)?
+    {
+        Some(number) => Ok(number),
+        None => downcast_error(js, "number"),
+    }
+}
+
+/// Checks that `value` is an object, as arrays and functions are.
+fn object_value<J: Js>(js: &mut J, value: J::Value) -> JsResult<J>
+{
+    match js.type_of(&value)?.is_object()
+    {
+        true => Ok(value),
+        false => downcast_error(js, "object"),
+    }
+}
+
+/// The elements of `value`, which has to be an array.
+fn array_value<J: Js>(js: &mut J, value: &J::Value) -> JsResult<J, Vec<J::Value>>
+{
+    match js.type_of(value)?
+    {
+        Type::Array => js.elements(value),
+        _ => downcast_error(js, "Array"),
+    }
+}
+
+fn string_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, String>
+{
+    let arg = required_argument(js, args, index)?;
+    string_value(js, &arg)
+}
+
+fn number_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, f64>
+{
+    let arg = required_argument(js, args, index)?;
+    number_value(js, &arg)
+}
+
+fn object_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J>
+{
+    let arg = required_argument(js, args, index)?;
+    object_value(js, arg)
+}
+
+fn array_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, Vec<J::Value>>
+{
+    let arg = required_argument(js, args, index)?;
+    array_value(js, &arg)
+}
+
+/// The options argument at `index`, if it is an object, which callers written before a function
+/// had any options leave out.
+fn options_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, Option<J::Value>>
+{
+    let arg = argument(js, args, index)?;
+    Ok(match js.type_of(&arg)?.is_object()
+    {
+        true => Some(arg),
+        false => None,
+    })
+}
+
+/// Reads the `Case`, `Pattern`, or `Boundary` whose id is `id`, throwing a `RangeError` if it
+/// isn't the id of one.
+fn id<J: Js, T>(js: &mut J, id: f64) -> JsResult<J, T>
+where
+    ids::Id<T>: TryFrom<f64, Error = String>,
+{
+    match ids::Id::<T>::try_from(id)
+    {
+        Ok(ids::Id(value)) => Ok(value),
+        Err(message) => range_error(js, message),
+    }
+}
+
+/// Reads an id like [`id`], throwing a `TypeError` if it isn't a number.
+fn id_value<J: Js, T>(js: &mut J, value: &J::Value) -> JsResult<J, T>
+where
+    ids::Id<T>: TryFrom<f64, Error = String>,
+{
+    let value = number_value(js, value)?;
+    id(js, value)
+}
+
+fn id_argument<J: Js, T>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, T>
+where
+    ids::Id<T>: TryFrom<f64, Error = String>,
+{
+    let arg = required_argument(js, args, index)?;
+    id_value(js, &arg)
+}
+
+/// Reads an array of ids, such as the boundaries from `Boundary` in `main.ts`.
+fn id_values<J: Js, T>(js: &mut J, values: &[J::Value]) -> JsResult<J, Vec<T>>
+where
+    ids::Id<T>: TryFrom<f64, Error = String>,
+{
+    values.iter().map(|value| id_value(js, value)).collect()
+}
+
+/// Reads `options[key]`, which is `undefined` if it isn't present.
+fn option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J>
+{
+    js.get(options, key)
+}
+
+fn bool_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Option<bool>>
+{
+    let value = option(js, options, key)?;
+    js.boolean(&value)
+}
+
+fn number_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Option<f64>>
+{
+    let value = option(js, options, key)?;
+    js.number(&value)
+}
+
+fn string_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Option<String>>
+{
+    let value = option(js, options, key)?;
+    js.string(&value)
+}
+
+/// Reads `options[key]` if it is an object, as arrays and functions are.
+fn object_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Option<J::Value>>
+{
+    let value = option(js, options, key)?;
+    Ok(match js.type_of(&value)?.is_object()
+    {
+        true => Some(value),
+        false => None,
+    })
+}
+
+/// Reads the elements of `options[key]` if it is an array.
+fn array_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Option<Vec<J::Value>>>
+{
+    let value = option(js, options, key)?;
+    match js.type_of(&value)?
+    {
+        Type::Array => Ok(Some(js.elements(&value)?)),
+        _ => Ok(None),
+    }
+}
+
+fn function_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Option<J::Value>>
+{
+    let value = option(js, options, key)?;
+    match js.type_of(&value)?
+    {
+        Type::Function => Ok(Some(value)),
+        _ => Ok(None),
+    }
+}
+
+/// Reads `options[key]` as an array of strings if it is present.
+fn string_array_option<J: Js>(js: &mut J, options: &J::Value, key: &str) -> JsResult<J, Vec<String>>
+{
+    match array_option(js, options, key)?
+    {
+        Some(array) => array.iter().map(|element| string_value(js, element)).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Runs `f` on the state of the instance the function was called through, throwing if it wasn't
+/// called through one.
+fn exports_state<J: Js, R>(js: &mut J, f: impl FnOnce(&State) -> R) -> JsResult<J, R>
+{
+    match js.with_state(f)?
+    {
+        Some(result) => Ok(result),
+        None => type_error(js, "The functions that set up the addon must be called on its exports"),
+    }
+}
+
+/// Returns the limit `setMaxInputLength` set on the instance the function was called through, if
+/// there is one.
+fn max_input_length<J: Js>(js: &mut J) -> JsResult<J, Option<usize>>
+{
+    Ok(js.with_state(|state| state.max_input_length.get())?.flatten())
+}
+
+/// The options of a function without [`case_options`], which only limit the length of its
+/// inputs to the one `setMaxInputLength` set.
+fn length_options<J: Js>(js: &mut J) -> JsResult<J, conversion::CaseOptions>
+{
+    Ok(conversion::CaseOptions { max_input_length: max_input_length(js)?, ..Default::default() })
+}
+
+/// Reads a `maxInputLength`, throwing a `RangeError` if it isn't a limit.
+fn checked_max_input_length<J: Js>(js: &mut J, max_input_length: f64) -> JsResult<J, usize>
+{
+    match conversion::max_input_length(max_input_length)
+    {
+        Ok(max_input_length) => Ok(max_input_length),
+        Err(message) => range_error(js, message),
+    }
+}
+
+/// Throws a `RangeError` with the code `ERR_INPUT_TOO_LONG` if `input` is longer than `options`
+/// allow.
+fn check_input_length<J: Js>(js: &mut J, input: &str, options: &conversion::CaseOptions) -> JsResult<J, ()>
+{
+    match conversion::input_too_long(input, options)
+    {
+        Some(message) => Err(js.throw(Thrown::InputTooLong(message))),
+        None => Ok(()),
+    }
+}
+
+/// Reads the string argument at `index` of a function without [`case_options`], throwing like
+/// [`check_input_length`] if it is longer than `setMaxInputLength` allows.
+fn input_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, String>
+{
+    let input = string_argument(js, args, index)?;
+    let options = length_options(js)?;
+    check_input_length(js, &input, &options)?;
+    Ok(input)
+}
+
+/// Reads the argument at `index` as an array of strings.
+fn string_array_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, Vec<String>>
+{
+    let array = array_argument(js, args, index)?;
+    array.iter().map(|string| string_value(js, string)).collect()
+}
+
+/// Reads the array of strings at `index` like [`string_array_argument`], throwing like
+/// [`input_argument`] if any of them is too long.
+fn input_array_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, Vec<String>>
+{
+    let inputs = string_array_argument(js, args, index)?;
+    let options = length_options(js)?;
+    for input in &inputs
+    {
+        check_input_length(js, input, &options)?;
+    }
+    Ok(inputs)
+}
+
+fn strings<J: Js, S: AsRef<str>>(js: &mut J, strings: &[S]) -> JsResult<J>
+{
+    let strings = strings.iter()
+        .map(|string| js.new_string(string.as_ref()))
+        .collect::<JsResult<J, _>>()?;
+    js.new_array(strings)
+}
+
+/// An array of the ids of `Case`s or `Boundary`s.
+fn id_array<J: Js>(js: &mut J, ids: impl IntoIterator<Item = u8>) -> JsResult<J>
+{
+    let ids = ids.into_iter()
+        .map(|id| js.new_number(id as f64))
+        .collect::<JsResult<J, _>>()?;
+    js.new_array(ids)
+}
+
+fn string_or_null<J: Js>(js: &mut J, string: Option<&str>) -> JsResult<J>
+{
+    match string
+    {
+        Some(string) => js.new_string(string),
+        None => js.null(),
+    }
+}
+
+fn id_or_null<J: Js>(js: &mut J, id: Option<u8>) -> JsResult<J>
+{
+    match id
+    {
+        Some(id) => js.new_number(id as f64),
+        None => js.null(),
+    }
+}
+
+/// Runs the conversion `f`, reporting what it traced if a trace callback is set.
+fn traced<J: Js, R>(js: &mut J, f: impl FnOnce() -> R) -> JsResult<J, R>
+{
+    traced_js(js, |_| Ok(f()))
+}
+
+/// Runs `f` like [`traced`], for a conversion that works on JS values and can throw, in which case
+/// nothing is reported.
+fn traced_js<J: Js, R>(js: &mut J, f: impl FnOnce(&mut J) -> JsResult<J, R>) -> JsResult<J, R>
+{
+    let callback = js.trace_callback()?;
+    let (result, events) = match callback.is_some()
+    {
+        true => trace::capture(|| f(js)),
+        false => (f(js), Vec::new()),
+    };
+    let result = result?;
+    if let Some(callback) = callback
+    {
+        for event in events
+        {
+            let event = trace_event(js, event)?;
+            js.call(&callback, &[event])?;
+        }
+    }
+
+    Ok(result)
+}
+
+fn trace_event<J: Js>(js: &mut J, event: trace::TraceEvent) -> JsResult<J>
+{
+    Ok(match event
+    {
+        trace::TraceEvent::Split { input, words } => object!(js, {
+            "kind": js.new_string("split")?,
+            "input": js.new_string(&input)?,
+            "words": strings(js, &words)?,
+        }),
+        trace::TraceEvent::CacheHit { input, result } => object!(js, {
+            "kind": js.new_string("cacheHit")?,
+            "input": js.new_string(&input)?,
+            "result": js.new_string(&result)?,
+        }),
+        trace::TraceEvent::Fallback { function, input, reason } => object!(js, {
+            "kind": js.new_string("fallback")?,
+            "function": js.new_string(&function)?,
+            "input": js.new_string(&input)?,
+            "reason": js.new_string(&reason)?,
+        }),
+    })
+}
+
+/// A function that converts its input string argument into another string with `f`.
+fn string_function<J: Js>(js: &mut J, args: &[J::Value], f: impl FnOnce(&str) -> String) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    js.new_string(&f(&input))
+}
+
+pub fn to_case<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = string_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = case_options(js, args, 3)?;
+    check_input_length(js, &input, &options)?;
+
+    let cached_from_case = cached_from_case(js, args, case)?;
+    if let Some(from_case) = cached_from_case
+    {
+        let cached = traced_js(js, |js| js.with_state(|state| state.cache.borrow_mut().get(&input, case, from_case)))?;
+        if let Some(cached) = cached.flatten()
+        {
+            return js.new_string(&cached);
+        }
+    }
+
+    let conv = from_case_converter(js, args, 2, &input, &options)?;
+    let conv = pattern_override(js, args, 3, conv.to_case(case))?;
+    match traced(js, || conversion::convert(&input, &conv, &options))?
+    {
+        Ok(converted) =>
+        {
+            if let Some(from_case) = cached_from_case
+            {
+                js.with_state(|state| state.cache.borrow_mut().insert(&input, case, from_case, &converted))?;
+            }
+            js.new_string(&converted)
+        }
+        Err(message) => range_error(js, message),
+    }
+}
+
+/// Returns the case `toCase` was asked to convert from, if the call to `case` can use the cache:
+/// the cache is on, the case isn't random, no options but the defaults are given, and the case to
+/// convert from isn't to be detected.
+fn cached_from_case<J: Js>(js: &mut J, args: &[J::Value], case: Case) -> JsResult<J, Option<Option<Case>>>
+{
+    let enabled = js.with_state(|state| state.cache.borrow().enabled())?.unwrap_or(false);
+    if !enabled || !cache::Cache::caches(case)
+    {
+        return Ok(None);
+    }
+    if let Some(options) = options_argument(js, args, 3)?
+    {
+        if !js.keys(&options)?.is_empty()
+        {
+            return Ok(None);
+        }
+    }
+
+    let from_case = argument(js, args, 2)?;
+    match js.type_of(&from_case)?
+    {
+        Type::Undefined => Ok(Some(None)),
+        // An id that isn't one is thrown by `from_case_converter`
+        Type::Number => match ids::Id::<Case>::try_from(number_value(js, &from_case)?)
+        {
+            Ok(ids::Id(from_case)) => Ok(Some(Some(from_case))),
+            Err(_) => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Starts a converter from the case the argument at `index` gives: a `Case`, `"auto"` to detect
+/// it from `input`, or nothing to split on the default boundaries.
+fn from_case_converter<J: Js>(js: &mut J, args: &[J::Value], index: usize, input: &str, options: &conversion::CaseOptions) -> JsResult<J, Converter>
+{
+    let from_case = argument(js, args, index)?;
+    match js.type_of(&from_case)?
+    {
+        Type::Number =>
+        {
+            let from_case: Case = id_value(js, &from_case)?;
+            Ok(Converter::new().from_case(from_case))
+        }
+        Type::String => match string_value(js, &from_case)?
+        {
+            from_case if from_case == "auto" => Ok(Converter::new().from_case(consistency::detect_case(input, options))),
+            from_case => range_error(js, format!("Unknown from case \"{}\"", from_case)),
+        },
+        _ => Ok(Converter::new()),
+    }
+}
+
+/// Sets the pattern of `conv` to the `pattern` of the options at `index`, if they give one, so
+/// that a case's delimiter and boundaries can be used with another pattern.
+fn pattern_override<J: Js>(js: &mut J, args: &[J::Value], index: usize, conv: Converter) -> JsResult<J, Converter>
+{
+    let options = match options_argument(js, args, index)?
+    {
+        Some(options) => options,
+        None => return Ok(conv),
+    };
+    match number_option(js, &options, "pattern")?
+    {
+        Some(pattern) => Ok(conv.set_pattern(id(js, pattern)?)),
+        None => Ok(conv),
+    }
+}
+
+pub fn is_case<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = string_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = case_options(js, args, 2)?;
+    check_input_length(js, &input, &options)?;
+
+    js.new_boolean(conversion::is_case(&input, case, &options))
+}
+
+/// Reads the options `toCase` and `isCase` share from the argument at `index`, which callers
+/// written before there were any options leave out.  A `maxInputLength` that isn't given is the
+/// one `setMaxInputLength` set on the instance the function was called through, if any.
+fn case_options<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, conversion::CaseOptions>
+{
+    let mut case_options = length_options(js)?;
+
+    let options = match options_argument(js, args, index)?
+    {
+        Some(options) => options,
+        None => return Ok(case_options),
+    };
+    if let Some(max_input_length) = number_option(js, &options, "maxInputLength")?
+    {
+        case_options.max_input_length = Some(checked_max_input_length(js, max_input_length)?);
+    }
+    if let Some(edges) = string_option(js, &options, "edgeDelimiters")?
+    {
+        case_options.edge_delimiters = match conversion::EdgeDelimiters::from_name(&edges)
+        {
+            Some(edges) => Some(edges),
+            None => return range_error(js, format!("Unknown edge delimiter policy \"{}\"", edges)),
+        };
+    }
+    if let Some(collapse_delims) = bool_option(js, &options, "collapseDelims")?
+    {
+        case_options.collapse_delims = collapse_delims;
+    }
+    if let Some(empty_input) = string_option(js, &options, "emptyInput")?
+    {
+        case_options.empty_input = match conversion::EmptyInput::from_name(&empty_input)
+        {
+            Some(empty_input) => Some(empty_input),
+            None => return range_error(js, format!("Unknown empty input policy \"{}\"", empty_input)),
+        };
+    }
+    if let Some(keep_numeric_separators) = bool_option(js, &options, "keepNumericSeparators")?
+    {
+        case_options.keep_numeric_separators = keep_numeric_separators;
+    }
+    if let Some(word_range) = array_option(js, &options, "wordRange")?
+    {
+        let start = argument(js, &word_range, 0)?;
+        let end = argument(js, &word_range, 1)?;
+        case_options.word_range = Some((number_value(js, &start)? as isize, number_value(js, &end)? as isize));
+    }
+    if let Some(apostrophes) = string_option(js, &options, "apostrophes")?
+    {
+        case_options.apostrophes = match conversion::Apostrophes::from_name(&apostrophes)
+        {
+            Some(apostrophes) => Some(apostrophes),
+            None => return range_error(js, format!("Unknown apostrophe policy \"{}\"", apostrophes)),
+        };
+    }
+    if let Some(capitalize_after_digit) = bool_option(js, &options, "capitalizeAfterDigit")?
+    {
+        case_options.capitalize_after_digit = capitalize_after_digit;
+    }
+    if let Some(leading_digit) = string_option(js, &options, "leadingDigit")?
+    {
+        case_options.leading_digit = match conversion::LeadingDigit::from_name(&leading_digit)
+        {
+            Some(leading_digit) => Some(leading_digit),
+            None => return range_error(js, format!("Unknown leading digit policy \"{}\"", leading_digit)),
+        };
+    }
+    if let Some(reserved_words) = object_option(js, &options, "reservedWords")?
+    {
+        case_options.reserved_words = Some(avoidance(js, &reserved_words)?);
+    }
+    if let Some(keep_case) = array_option(js, &options, "keepCase")?
+    {
+        for class in keep_case
+        {
+            let class = string_value(js, &class)?;
+            match conversion::CharacterClass::from_name(&class)
+            {
+                Some(class) => case_options.keep_case.push(class),
+                None => return range_error(js, format!("Unknown character class \"{}\"", class)),
+            }
+        }
+    }
+    let seed = option(js, &options, "seed")?;
+    if js.type_of(&seed)? != Type::Undefined
+    {
+        case_options.seed = Some(seed_value(js, &seed)?);
+    }
+
+    Ok(case_options)
+}
+
+/// Reads the `reservedWords` option of [`case_options`]: a language, and a `prefix`, `suffix`,
+/// and `replacements` for its reserved words, with a suffix of `_` if neither of the first two is
+/// given.
+fn avoidance<J: Js>(js: &mut J, options: &J::Value) -> JsResult<J, reserved::Avoidance>
+{
+    let language = option(js, options, "language")?;
+    let language = string_value(js, &language)?;
+    let language = match reserved::Language::from_name(&language)
+    {
+        Some(language) => language,
+        None => return range_error(js, format!("Unknown language \"{}\"", language)),
+    };
+    let prefix = string_option(js, options, "prefix")?;
+    let suffix = string_option(js, options, "suffix")?;
+    let (prefix, suffix) = match (prefix, suffix)
+    {
+        (None, None) => (String::new(), "_".to_string()),
+        (prefix, suffix) => (prefix.unwrap_or_default(), suffix.unwrap_or_default()),
+    };
+
+    let mut replacements = Vec::new();
+    if let Some(js_replacements) = object_option(js, options, "replacements")?
+    {
+        for word in js.keys(&js_replacements)?
+        {
+            let replacement = option(js, &js_replacements, &word)?;
+            replacements.push((word, string_value(js, &replacement)?));
+        }
+    }
+
+    Ok(reserved::Avoidance { language, prefix, suffix, replacements })
+}
+
+/// Reads `seed`, a number or a `bigint` from 0 to 2^64 - 1, or the decimal string of one.
+fn seed_value<J: Js>(js: &mut J, seed: &J::Value) -> JsResult<J, u64>
+{
+    // Anything but a number is read through its decimal string, which is all a `bigint` needs
+    match js.number(seed)?
+    {
+        Some(seed) =>
+        {
+            if seed >= 0.0 && seed.fract() == 0.0 && seed <= u64::MAX as f64
+            {
+                return Ok(seed as u64);
+            }
+        }
+        None =>
+        {
+            if let Ok(seed) = js.to_string(seed)?.parse::<u64>()
+            {
+                return Ok(seed);
+            }
+        }
+    }
+    range_error(js, "The seed must be an integer from 0 to 2^64 - 1")
+}
+
+pub fn set_max_input_length<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let max_input_length = argument(js, args, 0)?;
+    let max_input_length = match js.type_of(&max_input_length)?
+    {
+        Type::Undefined => None,
+        _ =>
+        {
+            let max_input_length = number_value(js, &max_input_length)?;
+            Some(checked_max_input_length(js, max_input_length)?)
+        }
+    };
+    exports_state(js, |state| state.max_input_length.set(max_input_length))?;
+
+    js.undefined()
+}
+
+pub fn mutate<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = string_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let mut conv = Converter::new();
+
+    if let Some(delim) = string_option(js, &options, "delim")?
+    {
+        conv = conv.set_delim(delim);
+    }
+
+    let pattern = option(js, &options, "pattern")?;
+    let transform = match js.type_of(&pattern)?
+    {
+        Type::Number =>
+        {
+            conv = conv.set_pattern(id_value(js, &pattern)?);
+            None
+        }
+        Type::Function => Some(pattern),
+        _ => None,
+    };
+
+    // Giving boundaries replaces the defaults unless they are asked for too
+    let boundaries = array_option(js, &options, "boundaries")?;
+    let default_boundaries = bool_option(js, &options, "defaultBoundaries")?;
+    if !default_boundaries.unwrap_or(boundaries.is_none())
+    {
+        conv = conv.remove_boundaries(&Boundary::all());
+    }
+    if let Some(boundaries) = boundaries
+    {
+        for boundary in id_values(js, &boundaries)?
+        {
+            conv = conv.add_boundary(boundary);
+        }
+    }
+
+    let options = case_options(js, args, 1)?;
+    check_input_length(js, &input, &options)?;
+
+    let converted = traced_js(js, |js| match &transform
+    {
+        Some(transform) => transform_words(js, &conv, &input, &options, transform),
+        None => match conversion::convert(&input, &conv, &options)
+        {
+            Ok(converted) => Ok(converted),
+            Err(message) => range_error(js, message),
+        },
+    })?;
+    js.new_string(&converted)
+}
+
+/// Splits `input` into words as `conv` would, and joins what the JS `transform` makes of each
+/// word and its index with the delimiter of `conv`.
+fn transform_words<J: Js>(js: &mut J, conv: &Converter, input: &str, options: &conversion::CaseOptions, transform: &J::Value) -> JsResult<J, String>
+{
+    let split = match conversion::split(input, conv, options)
+    {
+        Ok(split) => split,
+        Err(message) => return range_error(js, message),
+    };
+    trace::split(input, &split.words);
+
+    let mut transformed = Vec::with_capacity(split.words.len());
+    for (i, word) in split.words.iter().enumerate()
+    {
+        let args = [js.new_string(word)?, js.new_number(i as f64)?];
+        let result = js.call(transform, &args)?;
+        match js.string(&result)?
+        {
+            Some(word) => transformed.push(word),
+            None => return type_error(js, format!("The pattern function must return a string, but didn't for \"{}\"", word)),
+        }
+    }
+
+    Ok(split.join(&transformed, &conv.delim))
+}
+
+pub fn list_from<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let mut boundaries = Boundary::list_from(input.as_str());
+
+    // Each boundary is detected on its own, so restricting them is only a matter of filtering
+    let candidates = argument(js, args, 1)?;
+    if js.type_of(&candidates)? == Type::Array
+    {
+        let candidates = js.elements(&candidates)?;
+        let candidates: Vec<Boundary> = id_values(js, &candidates)?;
+        boundaries.retain(|boundary| candidates.contains(boundary));
+    }
+
+    id_array(js, boundaries.into_iter().map(|boundary| boundary as u8))
+}
+
+pub fn convert_line_keys<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let text = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+    let separator = string_option(js, &options, "separator")?.unwrap_or_else(|| String::from("="));
+
+    let conv = Converter::new().to_case(case);
+    js.new_string(&lines::convert_line_keys(&text, &conv, &separator))
+}
+
+pub fn convert_html_attributes<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let html = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+
+    let mut filter = html::AttributeFilter::default();
+    if let Some(exclude_data) = bool_option(js, &options, "excludeData")?
+    {
+        filter.exclude_data = exclude_data;
+    }
+    if let Some(exclude_aria) = bool_option(js, &options, "excludeAria")?
+    {
+        filter.exclude_aria = exclude_aria;
+    }
+
+    let conv = Converter::new().to_case(case);
+    let rename = |name: &str| if filter.skips(name) { name.to_string() } else { conv.convert(name) };
+    js.new_string(&html::rename_attributes(&html, &rename))
+}
+
+/// Reads the `deep`, `maxDepth`, `include`, `exclude`, `values`, `collisions`, `strict`, and
+/// `renameAllCompat` options shared by the key conversion functions.
+fn key_options<J: Js>(js: &mut J, options: &J::Value, case: Case, deep_by_default: bool) -> JsResult<J, KeyOptions>
+{
+    let mut key_options = KeyOptions {
+        deep: deep_by_default,
+        max_depth: None,
+        filter: paths::PathFilter::default(),
+        collisions: collisions::CollisionPolicy::default(),
+        strict: false,
+        rename_all: None,
+    };
+
+    if let Some(deep) = bool_option(js, options, "deep")?
+    {
+        key_options.deep = deep;
+    }
+    if let Some(max_depth) = number_option(js, options, "maxDepth")?
+    {
+        key_options.max_depth = Some(max_depth as usize);
+    }
+
+    key_options.filter.include = string_array_option(js, options, "include")?.iter()
+        .map(|pattern| paths::PathGlob::new(pattern))
+        .collect();
+    key_options.filter.exclude = string_array_option(js, options, "exclude")?.iter()
+        .map(|pattern| paths::PathGlob::new(pattern))
+        .collect();
+    key_options.filter.values = string_array_option(js, options, "values")?.iter()
+        .map(|pattern| paths::PathGlob::new(pattern))
+        .collect();
+
+    if let Some(strict) = bool_option(js, options, "strict")?
+    {
+        key_options.strict = strict;
+    }
+    if key_options.strict
+    {
+        key_options.collisions = collisions::CollisionPolicy::Error;
+    }
+    if let Some(policy) = string_option(js, options, "collisions")?
+    {
+        key_options.collisions = match collisions::CollisionPolicy::from_name(&policy)
+        {
+            Some(policy) => policy,
+            None => return range_error(js, format!("Unknown collision policy \"{}\"", policy)),
+        };
+    }
+    if bool_option(js, options, "renameAllCompat")?.unwrap_or(false)
+    {
+        key_options.rename_all = match rename_all::RenameRule::from_case(case)
+        {
+            Some(rule) => Some(rule),
+            None => return range_error(js, format!("serde's rename_all has no equivalent of {:?} case", case)),
+        };
+    }
+
+    Ok(key_options)
+}
+
+/// Passes a report of each collision to the `onCollision` callback, if one was given.
+fn report_collisions<J: Js>(js: &mut J, callback: Option<&J::Value>, collisions: &[collisions::Collision]) -> JsResult<J, ()>
+{
+    let callback = match callback
+    {
+        Some(callback) => callback,
+        None => return Ok(()),
+    };
+
+    for collision in collisions
+    {
+        let report = object!(js, {
+            "path": js.new_string(&collision.path.join("."))?,
+            "keys": strings(js, &collision.keys)?,
+            "name": js.new_string(&collision.name)?,
+        });
+        js.call(callback, &[report])?;
+    }
+
+    Ok(())
+}
+
+/// Returns `{result, forwardMap, reverseMap}`, with the renames in `mapping` as `Map`s.
+fn mapped_result<J: Js>(js: &mut J, result: J::Value, mapping: &mapping::KeyMapping) -> JsResult<J>
+{
+    let object = object!(js, { "result": result });
+    for (key, pairs) in [("forwardMap", mapping.forward()), ("reverseMap", mapping.reverse())]
+    {
+        let mut entries = Vec::with_capacity(pairs.len());
+        for (from, to) in pairs
+        {
+            entries.push((js.new_string(from)?, js.new_string(to)?));
+        }
+        let map = js.new_map(entries)?;
+        js.define(&object, key, map)?;
+    }
+
+    Ok(object)
+}
+
+pub fn convert_keys<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let value = required_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+    let key_options = key_options(js, &options, case, false)?;
+    let on_collision = function_option(js, &options, "onCollision")?;
+    let mappings = bool_option(js, &options, "mappings")?.unwrap_or(false);
+
+    let mut walker = KeyWalker::new(Converter::new().to_case(case), key_options)
+        .with_max_input_length(max_input_length(js)?);
+    if mappings
+    {
+        walker = walker.with_mapping();
+    }
+    let converted = traced_js(js, |js| walker.convert(js, value))?;
+    report_collisions(js, on_collision.as_ref(), &walker.report().collisions)?;
+
+    match &walker.report().mapping
+    {
+        Some(mapping) => mapped_result(js, converted, mapping),
+        None => Ok(converted),
+    }
+}
+
+#[cfg(feature = "json")]
+pub fn convert_json_keys<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let text = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+    let key_options = key_options(js, &options, case, true)?;
+    let on_collision = function_option(js, &options, "onCollision")?;
+    let pretty = bool_option(js, &options, "pretty")?.unwrap_or(false);
+
+    let mut report = mapping::KeyReport::default();
+    if bool_option(js, &options, "mappings")?.unwrap_or(false)
+    {
+        report.mapping = Some(mapping::KeyMapping::default());
+    }
+
+    let conv = Converter::new().to_case(case);
+    let converted = match traced(js, || json::convert_json_keys(&text, &conv, &key_options, pretty, &mut report))?
+    {
+        Ok(converted) => js.new_string(&converted)?,
+        Err(e) => return error(js, e),
+    };
+    report_collisions(js, on_collision.as_ref(), &report.collisions)?;
+
+    match &report.mapping
+    {
+        Some(mapping) => mapped_result(js, converted, mapping),
+        None => Ok(converted),
+    }
+}
+
+/// Reads the arguments of `ndjsonNew(caseType, options)` into the stream its handle keeps and the
+/// `onCollision` callback it reports that stream's collisions to, if one was given.
+#[cfg(feature = "json")]
+pub fn ndjson_new<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J, (json::NdjsonStream, Option<J::Value>)>
+{
+    let case: Case = id_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let key_options = key_options(js, &options, case, true)?;
+    let on_collision = function_option(js, &options, "onCollision")?;
+
+    Ok((json::NdjsonStream::new(Converter::new().to_case(case), key_options), on_collision))
+}
+
+/// `ndjsonWrite(handle, chunk)`, on the stream and the callback the handle keeps.
+#[cfg(feature = "json")]
+pub fn ndjson_write<J: Js>(js: &mut J, stream: &RefCell<json::NdjsonStream>, on_collision: Option<J::Value>, args: &[J::Value]) -> JsResult<J>
+{
+    let chunk = input_argument(js, args, 1)?;
+
+    let converted = traced(js, || stream.borrow_mut().write(&chunk))?;
+    ndjson_finish(js, stream, on_collision, converted)
+}
+
+/// `ndjsonEnd(handle)`, on the stream and the callback the handle keeps.
+#[cfg(feature = "json")]
+pub fn ndjson_end<J: Js>(js: &mut J, stream: &RefCell<json::NdjsonStream>, on_collision: Option<J::Value>) -> JsResult<J>
+{
+    let converted = traced(js, || stream.borrow_mut().end())?;
+    ndjson_finish(js, stream, on_collision, converted)
+}
+
+/// Reports the collisions found by the last write and turns its result into a JS string.
+#[cfg(feature = "json")]
+fn ndjson_finish<J: Js>(js: &mut J, stream: &RefCell<json::NdjsonStream>, on_collision: Option<J::Value>, converted: Result<String, String>) -> JsResult<J>
+{
+    let collisions = stream.borrow_mut().take_collisions();
+    report_collisions(js, on_collision.as_ref(), &collisions)?;
+
+    match converted
+    {
+        Ok(converted) => js.new_string(&converted),
+        Err(e) => error(js, e),
+    }
+}
+
+#[cfg(feature = "yaml")]
+pub fn convert_yaml_keys<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let text = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+
+    let conv = Converter::new().to_case(case);
+    match yaml::convert_yaml_keys(&text, &conv)
+    {
+        Ok(converted) => js.new_string(&converted),
+        Err(e) => error(js, format!("Invalid YAML: {}", e)),
+    }
+}
+
+#[cfg(feature = "toml")]
+pub fn convert_toml_keys<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let text = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+
+    let conv = Converter::new().to_case(case);
+    match toml::convert_toml_keys(&text, &conv)
+    {
+        Ok(converted) => js.new_string(&converted),
+        Err(e) => error(js, e),
+    }
+}
+
+#[cfg(feature = "csv")]
+pub fn convert_csv_header<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let text = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+
+    let mut delimiter = b',';
+    if let Some(js_delimiter) = string_option(js, &options, "delimiter")?
+    {
+        match js_delimiter.as_bytes()
+        {
+            [byte] => delimiter = *byte,
+            _ => return range_error(js, "delimiter must be a single ASCII character"),
+        }
+    }
+    let header_only = bool_option(js, &options, "headerOnly")?.unwrap_or(false);
+
+    let conv = Converter::new().to_case(case);
+    match delimited::convert_csv_header(&text, &conv, delimiter, header_only)
+    {
+        Ok(converted) => js.new_string(&converted),
+        Err(e) => error(js, format!("Invalid CSV: {}", e)),
+    }
+}
+
+pub fn convert_query_keys<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let query = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+
+    let conv = Converter::new().to_case(case);
+    js.new_string(&query::convert_query_keys(&query, &conv))
+}
+
+pub fn rename_columns<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let rows = array_argument(js, args, 0)?;
+    let columns = required_argument(js, args, 1)?;
+
+    // Either a case id or an array of [from, to] pairs
+    let columns = match js.type_of(&columns)?
+    {
+        Type::Number =>
+        {
+            let case: Case = id_value(js, &columns)?;
+            columns::Columns::Case(Converter::new().to_case(case))
+        }
+        _ =>
+        {
+            let mut mapping = HashMap::new();
+            for pair in array_value(js, &columns)?
+            {
+                let pair = array_value(js, &pair)?;
+                let (from, to) = (argument(js, &pair, 0)?, argument(js, &pair, 1)?);
+                mapping.insert(string_value(js, &from)?, string_value(js, &to)?);
+            }
+            columns::Columns::Mapping(mapping)
+        }
+    };
+    let mut renamer = columns::ColumnRenamer::new(columns);
+    let options = length_options(js)?;
+
+    let mut renamed = Vec::with_capacity(rows.len());
+    for row in rows
+    {
+        // Only objects that aren't arrays are records
+        if !matches!(js.type_of(&row)?, Type::Object | Type::Function)
+        {
+            renamed.push(row);
+            continue;
+        }
+
+        let renamed_row = js.new_object(false)?;
+        for key in js.keys(&row)?
+        {
+            check_input_length(js, &key, &options)?;
+            let value = js.get(&row, &key)?;
+            js.define(&renamed_row, renamer.name(&key), value)?;
+        }
+        renamed.push(renamed_row);
+    }
+
+    js.new_array(renamed)
+}
+
+pub fn canonicalize_header<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, headers::canonicalize_header)
+}
+
+pub fn css_property_to_camel<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, css::css_property_to_camel)
+}
+
+pub fn camel_to_css_property<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, css::camel_to_css_property)
+}
+
+pub fn html_to_jsx_prop<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, jsx::html_to_jsx_prop)
+}
+
+pub fn jsx_to_html_attribute<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, jsx::jsx_to_html_attribute)
+}
+
+pub fn html_attributes_to_jsx<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, |html| html::rename_attributes(html, &jsx::html_to_jsx_prop))
+}
+
+pub fn convert_graphql_fields<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let sdl = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+
+    let conv = Converter::new().to_case(case);
+    let (converted, renames) = match graphql::convert_graphql_fields(&sdl, &conv)
+    {
+        Ok(converted) => converted,
+        Err(e) => return error(js, format!("Invalid GraphQL SDL: {}", e)),
+    };
+    let converted = js.new_string(&converted)?;
+    if !bool_option(js, &options, "fieldMap")?.unwrap_or(false)
+    {
+        return Ok(converted);
+    }
+
+    // {Type: {oldField: newField}}, the shape resolver maps are written in
+    let types = js.new_object(false)?;
+    let mut type_fields: Vec<(String, J::Value)> = Vec::new();
+    for rename in renames
+    {
+        let fields = match type_fields.iter().find(|(type_name, _)| *type_name == rename.type_name)
+        {
+            Some((_, fields)) => fields.clone(),
+            None =>
+            {
+                let fields = js.new_object(false)?;
+                js.define(&types, &rename.type_name, fields.clone())?;
+                type_fields.push((rename.type_name.clone(), fields.clone()));
+                fields
+            }
+        };
+        let name = js.new_string(&rename.name)?;
+        js.define(&fields, &rename.field, name)?;
+    }
+
+    Ok(object!(js, {
+        "sdl": converted,
+        "fieldMap": types,
+    }))
+}
+
+pub fn convert_ddl_identifiers<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let sql = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+
+    let conv = Converter::new().to_case(case);
+    match sql::convert_ddl_identifiers(&sql, &conv)
+    {
+        Ok(converted) => js.new_string(&converted),
+        Err(e) => error(js, format!("Invalid SQL: {}", e)),
+    }
+}
+
+pub fn proto_json_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    string_function(js, args, proto::proto_json_name)
+}
+
+pub fn proto_field_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let json_name = input_argument(js, args, 0)?;
+    match proto::proto_field_name(&json_name)
+    {
+        Some(field) => js.new_string(&field),
+        None => error(js, format!("Invalid protobuf JSON name \"{}\": JSON names never contain underscores", json_name)),
+    }
+}
+
+pub fn to_k8s_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let k8s_name = match traced(js, || k8s::to_k8s_name(&input))?
+    {
+        Some(k8s_name) => k8s_name,
+        None => return error(js, format!("Cannot derive a Kubernetes name from \"{}\": it has no ASCII letters or digits", input)),
+    };
+
+    Ok(object!(js, {
+        "name": js.new_string(&k8s_name.name)?,
+        "truncated": js.new_boolean(k8s_name.truncated)?,
+    }))
+}
+
+pub fn to_resource_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+
+    let case = match number_option(js, &options, "case")?
+    {
+        Some(case) => id(js, case)?,
+        None => Case::Kebab,
+    };
+    let max_length = number_option(js, &options, "maxLength")?.map(|max_length| max_length as usize);
+    let charset = match string_option(js, &options, "charset")?
+    {
+        Some(charset) => match resource::Charset::parse(&charset)
+        {
+            Ok(charset) => Some(charset),
+            Err(e) => return range_error(js, e),
+        },
+        None => None,
+    };
+    let hash_suffix = bool_option(js, &options, "hashSuffixOnTruncate")?.unwrap_or(false);
+
+    let namer = match resource::ResourceNamer::new(case, max_length, charset, hash_suffix)
+    {
+        Ok(namer) => namer,
+        Err(e) => return range_error(js, e),
+    };
+    match traced(js, || namer.name(&input))?
+    {
+        Some(name) => js.new_string(&name),
+        None => error(js, format!("Cannot derive a resource name from \"{}\": none of its characters are allowed", input)),
+    }
+}
+
+#[cfg(feature = "json")]
+pub fn convert_openapi_properties<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let json = input_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+    let pretty = bool_option(js, &options, "pretty")?.unwrap_or(false);
+
+    let conv = Converter::new().to_case(case);
+    match openapi::convert_openapi(&json, &conv, pretty)
+    {
+        Ok(converted) => js.new_string(&converted),
+        Err(e) => error(js, e),
+    }
+}
+
+pub fn plan_renames<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let paths = input_array_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+
+    let plan = renames::plan_renames(&paths, &Converter::new().to_case(case));
+
+    let mut steps = Vec::with_capacity(plan.steps.len());
+    for step in &plan.steps
+    {
+        steps.push(object!(js, {
+            "from": js.new_string(&step.from)?,
+            "to": js.new_string(&step.to)?,
+        }));
+    }
+    let mut collisions = Vec::with_capacity(plan.collisions.len());
+    for collision in &plan.collisions
+    {
+        collisions.push(object!(js, {
+            "target": js.new_string(&collision.target)?,
+            "sources": strings(js, &collision.sources)?,
+        }));
+    }
+
+    Ok(object!(js, {
+        "steps": js.new_array(steps)?,
+        "collisions": js.new_array(collisions)?,
+    }))
+}
+
+pub fn to_package_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+
+    let registry = match string_option(js, &options, "registry")?
+    {
+        Some(registry) => match packages::Registry::from_name(&registry)
+        {
+            Some(registry) => registry,
+            None => return range_error(js, format!("Unknown package registry \"{}\"", registry)),
+        },
+        None => packages::Registry::Npm,
+    };
+
+    match packages::to_package_name(&input, registry)
+    {
+        Ok(name) => js.new_string(&name),
+        Err(e) => error(js, format!("Cannot derive a package name from \"{}\": {}", input, e)),
+    }
+}
+
+pub fn to_macro_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    match traced(js, || macros::to_macro_name(&input))?
+    {
+        Some(name) => js.new_string(&name),
+        None => error(js, format!("Cannot derive a macro name from \"{}\": it has no ASCII letters or digits", input)),
+    }
+}
+
+pub fn to_include_guard<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let path = input_argument(js, args, 0)?;
+    match traced(js, || macros::to_include_guard(&path))?
+    {
+        Some(guard) => js.new_string(&guard),
+        None => error(js, format!("Cannot derive an include guard from \"{}\": it has no ASCII letters or digits", path)),
+    }
+}
+
+pub fn preset<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let name = string_argument(js, args, 0)?;
+    let preset = match presets::preset(&name)
+    {
+        Some(preset) => preset,
+        None => return range_error(js, format!("Unknown preset \"{}\"", name)),
+    };
+
+    Ok(object!(js, {
+        "pattern": js.new_number(preset.pattern as u8 as f64)?,
+        "delim": js.new_string(preset.delim)?,
+        "boundaries": id_array(js, preset.boundaries.iter().map(|boundary| *boundary as u8))?,
+    }))
+}
+
+/// Reads the `lang` option of the accessor functions, which defaults to Java.
+fn accessor_lang<J: Js>(js: &mut J, options: &J::Value) -> JsResult<J, accessors::Lang>
+{
+    let lang = match string_option(js, options, "lang")?
+    {
+        Some(lang) => lang,
+        None => return Ok(accessors::Lang::Java),
+    };
+
+    match accessors::Lang::from_name(&lang)
+    {
+        Some(lang) => Ok(lang),
+        None => range_error(js, format!("Unknown accessor language \"{}\"", lang)),
+    }
+}
+
+pub fn to_getter_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let field = input_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let lang = accessor_lang(js, &options)?;
+    let boolean = bool_option(js, &options, "boolean")?.unwrap_or(false);
+
+    js.new_string(&accessors::getter_name(&field, lang, boolean))
+}
+
+pub fn to_setter_name<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let field = input_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let lang = accessor_lang(js, &options)?;
+    let boolean = bool_option(js, &options, "boolean")?.unwrap_or(false);
+
+    js.new_string(&accessors::setter_name(&field, lang, boolean))
+}
+
+pub fn field_from_accessor<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let name = input_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let lang = accessor_lang(js, &options)?;
+
+    string_or_null(js, accessors::field_from_accessor(&name, lang).as_deref())
+}
+
+pub fn set_trace_callback<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let callback = argument(js, args, 0)?;
+    let callback = match js.type_of(&callback)?
+    {
+        Type::Function => Some(callback),
+        _ => None,
+    };
+    exports_state(js, |_| ())?;
+    js.set_trace_callback(callback)?;
+
+    js.undefined()
+}
+
+/// Returns `{min, max}` for a range of ids.
+fn id_range<J: Js>(js: &mut J, ids: std::ops::RangeInclusive<u8>) -> JsResult<J>
+{
+    Ok(object!(js, {
+        "min": js.new_number(*ids.start() as f64)?,
+        "max": js.new_number(*ids.end() as f64)?,
+    }))
+}
+
+pub fn native_info<J: Js>(js: &mut J, _args: &[J::Value]) -> JsResult<J>
+{
+    Ok(object!(js, {
+        "version": js.new_string(J::VERSION)?,
+        "convertCaseVersion": js.new_string(info::CONVERT_CASE_VERSION)?,
+        "features": strings(js, &info::features())?,
+        "unicodeVersion": js.new_string(&info::unicode_version())?,
+        "caseIds": id_range(js, info::case_ids())?,
+        "patternIds": id_range(js, info::pattern_ids())?,
+        "boundaryIds": id_range(js, info::boundary_ids())?,
+    }))
+}
+
+pub fn case_consistency_report<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifiers = string_array_argument(js, args, 0)?;
+    let options = case_options(js, args, 1)?;
+    for identifier in &identifiers
+    {
+        check_input_length(js, identifier, &options)?;
+    }
+    let report = consistency::case_consistency_report(&identifiers, &options);
+
+    let mut counts = Vec::with_capacity(report.counts.len());
+    for (case, count) in &report.counts
+    {
+        counts.push(object!(js, {
+            "case": js.new_number(*case as u8 as f64)?,
+            "count": js.new_number(*count as f64)?,
+        }));
+    }
+    let mut outliers = Vec::with_capacity(report.outliers.len());
+    for outlier in &report.outliers
+    {
+        outliers.push(object!(js, {
+            "identifier": js.new_string(&outlier.identifier)?,
+            "cases": id_array(js, outlier.cases.iter().map(|case| *case as u8))?,
+            "mixed": string_or_null(js, outlier.mixed.as_deref())?,
+            "suggestion": js.new_string(&outlier.suggestion)?,
+        }));
+    }
+
+    Ok(object!(js, {
+        "counts": js.new_array(counts)?,
+        "convention": id_or_null(js, report.convention.map(|convention| convention as u8))?,
+        "outliers": js.new_array(outliers)?,
+    }))
+}
+
+pub fn find_mixed_conventions<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifiers = input_array_argument(js, args, 0)?;
+
+    let mut mixed = Vec::new();
+    for found in consistency::find_mixed_conventions(&identifiers)
+    {
+        mixed.push(object!(js, {
+            "index": js.new_number(found.index as f64)?,
+            "identifier": js.new_string(&found.identifier)?,
+            "reason": js.new_string(&found.reason)?,
+        }));
+    }
+    js.new_array(mixed)
+}
+
+pub fn lint_identifiers<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifiers = input_array_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+
+    let lint_options = lint::LintOptions {
+        allow: string_array_option(js, &options, "allow")?,
+        allow_leading_underscore: bool_option(js, &options, "allowLeadingUnderscore")?.unwrap_or(false),
+    };
+
+    let mut violations = Vec::new();
+    for violation in lint::lint_identifiers(&identifiers, case, &lint_options)
+    {
+        let mut problems = Vec::with_capacity(violation.problems.len());
+        for problem in &violation.problems
+        {
+            problems.push(object!(js, {
+                "boundary": id_or_null(js, problem.boundary.map(|boundary| boundary as u8))?,
+                "message": js.new_string(&problem.message)?,
+            }));
+        }
+        violations.push(object!(js, {
+            "index": js.new_number(violation.index as f64)?,
+            "identifier": js.new_string(&violation.identifier)?,
+            "severity": js.new_string(violation.severity.name())?,
+            "problems": js.new_array(problems)?,
+            "fix": js.new_string(&violation.fix)?,
+        }));
+    }
+    js.new_array(violations)
+}
+
+pub fn build_rename_map<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifiers = input_array_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+
+    let mut pairs = Vec::new();
+    for (from, to) in rename_map::build_rename_map(&identifiers, &Converter::new().to_case(case))
+    {
+        let pair = [from, to];
+        pairs.push(strings(js, &pair)?);
+    }
+    js.new_array(pairs)
+}
+
+pub fn find_identifier<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let text = input_argument(js, args, 0)?;
+    let name = input_argument(js, args, 1)?;
+
+    // Occurrences come in order, so their UTF-16 indices can be counted from the previous one
+    let (mut byte, mut index) = (0, 0);
+    let mut utf16_index = |offset: usize| {
+        index += find::utf16_index(&text[byte..], offset - byte);
+        byte = offset;
+        index
+    };
+
+    let mut occurrences = Vec::new();
+    for occurrence in find::find_identifier(&text, &name)
+    {
+        occurrences.push(object!(js, {
+            "start": js.new_number(utf16_index(occurrence.start) as f64)?,
+            "end": js.new_number(utf16_index(occurrence.end) as f64)?,
+            "text": js.new_string(&text[occurrence.start..occurrence.end])?,
+            "cases": id_array(js, occurrence.cases.iter().map(|case| *case as u8))?,
+        }));
+    }
+    js.new_array(occurrences)
+}
+
+pub fn match_identifiers<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let query = input_argument(js, args, 0)?;
+    let candidates = input_array_argument(js, args, 1)?;
+    let options = object_argument(js, args, 2)?;
+    let limit = number_option(js, &options, "limit")?.map(|limit| limit as usize);
+    let min_score = number_option(js, &options, "minScore")?.unwrap_or(0.0);
+
+    let mut matches = Vec::new();
+    for found in matching::match_identifiers(&query, &candidates, limit, min_score)
+    {
+        matches.push(object!(js, {
+            "candidate": js.new_string(&candidates[found.index])?,
+            "index": js.new_number(found.index as f64)?,
+            "score": js.new_number(found.score)?,
+        }));
+    }
+    js.new_array(matches)
+}
+
+pub fn segmentation_stats<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let inputs = input_array_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let boundaries = match array_option(js, &options, "boundaries")?
+    {
+        Some(boundaries) => id_values(js, &boundaries)?,
+        None => Boundary::defaults(),
+    };
+
+    let mut stats = Vec::with_capacity(inputs.len());
+    for input in &inputs
+    {
+        let input_stats = segmentation::segment_stats(input, &boundaries);
+        let mut fired = Vec::with_capacity(input_stats.boundaries.len());
+        for (boundary, count) in &input_stats.boundaries
+        {
+            fired.push(object!(js, {
+                "boundary": js.new_number(*boundary as u8 as f64)?,
+                "count": js.new_number(*count as f64)?,
+            }));
+        }
+        stats.push(object!(js, {
+            "input": js.new_string(input)?,
+            "wordCount": js.new_number(input_stats.words.len() as f64)?,
+            "words": strings(js, &input_stats.words)?,
+            "characterClasses": strings(js, &input_stats.character_classes)?,
+            "boundaries": js.new_array(fired)?,
+        }));
+    }
+    js.new_array(stats)
+}
+
+pub fn is_reversible<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let from_case = id_argument(js, args, 1)?;
+    let to_case = id_argument(js, args, 2)?;
+    let result = reversibility::check_reversible(&input, from_case, to_case);
+
+    let mut losses = Vec::with_capacity(result.losses.len());
+    for loss in &result.losses
+    {
+        losses.push(object!(js, {
+            "kind": js.new_string(loss.kind)?,
+            "message": js.new_string(&loss.message)?,
+        }));
+    }
+
+    Ok(object!(js, {
+        "reversible": js.new_boolean(result.reversible)?,
+        "forward": js.new_string(&result.forward)?,
+        "back": js.new_string(&result.back)?,
+        "losses": js.new_array(losses)?,
+    }))
+}
+
+pub fn group_by_signature<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifiers = input_array_argument(js, args, 0)?;
+
+    let mut groups = Vec::new();
+    for group in matching::group_by_signature(&identifiers)
+    {
+        groups.push(object!(js, {
+            "words": strings(js, &group.words)?,
+            "identifiers": strings(js, &group.identifiers)?,
+        }));
+    }
+    js.new_array(groups)
+}
+
+pub fn discover_acronyms<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifiers = input_array_argument(js, args, 0)?;
+    let options = object_argument(js, args, 1)?;
+    let min_uses = number_option(js, &options, "minUses")?.map_or(2, |min_uses| min_uses as usize);
+    let min_consistency = number_option(js, &options, "minConsistency")?.unwrap_or(0.75);
+
+    let mut acronyms = Vec::new();
+    for found in acronyms::discover_acronyms(&identifiers, min_uses, min_consistency)
+    {
+        acronyms.push(object!(js, {
+            "acronym": js.new_string(&found.acronym)?,
+            "uses": js.new_number(found.uses as f64)?,
+            "consistency": js.new_number(found.consistency)?,
+        }));
+    }
+    js.new_array(acronyms)
+}
+
+pub fn diff_identifiers<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let a = input_argument(js, args, 0)?;
+    let b = input_argument(js, args, 1)?;
+
+    let mut diff = Vec::new();
+    for step in matching::diff_identifiers(&a, &b)
+    {
+        diff.push(object!(js, {
+            "change": js.new_string(step.change.name())?,
+            "from": string_or_null(js, step.from.as_deref())?,
+            "to": string_or_null(js, step.to.as_deref())?,
+        }));
+    }
+    js.new_array(diff)
+}
+
+pub fn suggest_case<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let identifier = input_argument(js, args, 0)?;
+    let context = input_array_argument(js, args, 1)?;
+    let suggestion = consistency::suggest_case(&identifier, &context);
+
+    id_or_null(js, suggestion.map(|case| case as u8))
+}
+
+pub fn to_case_detailed<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = string_argument(js, args, 0)?;
+    let case: Case = id_argument(js, args, 1)?;
+    let options = case_options(js, args, 3)?;
+    check_input_length(js, &input, &options)?;
+
+    let conv = from_case_converter(js, args, 2, &input, &options)?;
+    let conv = pattern_override(js, args, 3, conv.to_case(case))?;
+    let detailed = match traced(js, || conversion::convert_detailed(&input, &conv, &options))?
+    {
+        Ok(detailed) => detailed,
+        Err(message) => return range_error(js, message),
+    };
+
+    Ok(object!(js, {
+        "result": js.new_string(&detailed.result)?,
+        "words": strings(js, &detailed.words)?,
+        "boundaries": id_array(js, detailed.boundaries.iter().map(|boundary| *boundary as u8))?,
+        "fromCaseDetected": id_or_null(js, detailed.from_case.map(|from_case| from_case as u8))?,
+    }))
+}
+
+pub fn apply_to_word<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let index = number_argument(js, args, 1)? as isize;
+    let pattern = id_argument(js, args, 2)?;
+
+    js.new_string(&adjust::apply_to_word(&input, index, pattern))
+}
+
+/// Reads the language named by the argument at `index`.
+fn language_argument<J: Js>(js: &mut J, args: &[J::Value], index: usize) -> JsResult<J, reserved::Language>
+{
+    let language = string_argument(js, args, index)?;
+    match reserved::Language::from_name(&language)
+    {
+        Some(language) => Ok(language),
+        None => range_error(js, format!("Unknown language \"{}\"", language)),
+    }
+}
+
+pub fn reserved_words<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let language = language_argument(js, args, 0)?;
+    strings(js, reserved::reserved_words(language))
+}
+
+pub fn is_reserved_word<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let word = input_argument(js, args, 0)?;
+    let language = language_argument(js, args, 1)?;
+
+    js.new_boolean(reserved::is_reserved(&word, language))
+}
+
+pub fn truncate_words<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let input = input_argument(js, args, 0)?;
+    let max_length = number_argument(js, args, 1)? as usize;
+    let options = object_argument(js, args, 2)?;
+    let case: Option<Case> = match number_option(js, &options, "case")?
+    {
+        Some(case) => Some(id(js, case)?),
+        None => None,
+    };
+    let ellipsis = string_option(js, &options, "ellipsis")?.unwrap_or_default();
+
+    js.new_string(&truncation::truncate_words(&input, max_length, case, &ellipsis))
+}
+
+pub fn dropped_characters<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let before = input_argument(js, args, 0)?;
+    let after = input_argument(js, args, 1)?;
+    let delim = argument(js, args, 2)?;
+    let delim = js.string(&delim)?.unwrap_or_default();
+
+    let mut changes = Vec::new();
+    for change in dropped::dropped_characters(&before, &after, &delim)
+    {
+        changes.push(object!(js, {
+            "kind": js.new_string(change.kind.name())?,
+            "index": js.new_number(change.index as f64)?,
+            "original": js.new_string(&change.original)?,
+            "replacement": js.new_string(&change.replacement)?,
+        }));
+    }
+    js.new_array(changes)
+}
+
+pub fn clear_cache<J: Js>(js: &mut J, _args: &[J::Value]) -> JsResult<J>
+{
+    exports_state(js, |state| state.cache.borrow_mut().clear())?;
+    js.undefined()
+}
+
+pub fn set_cache_size<J: Js>(js: &mut J, args: &[J::Value]) -> JsResult<J>
+{
+    let size = number_argument(js, args, 0)? as usize;
+    exports_state(js, |state| state.cache.borrow_mut().set_size(size))?;
+    js.undefined()
+}
+
+pub fn cache_stats<J: Js>(js: &mut J, _args: &[J::Value]) -> JsResult<J>
+{
+    let stats = exports_state(js, |state| state.cache.borrow().stats())?;
+    let lookups = stats.hits + stats.misses;
+    let hit_rate = if lookups == 0 { 0.0 } else { stats.hits as f64 / lookups as f64 };
+
+    Ok(object!(js, {
+        "entries": js.new_number(stats.entries as f64)?,
+        "size": js.new_number(stats.size as f64)?,
+        "hits": js.new_number(stats.hits as f64)?,
+        "misses": js.new_number(stats.misses as f64)?,
+        "hitRate": js.new_number(hit_rate)?,
+        "memoryEstimate": js.new_number(stats.memory as f64)?,
+    }))
+}
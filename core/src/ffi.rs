@@ -0,0 +1,103 @@
+//! A C ABI over the case conversion functions, for runtimes that load shared libraries directly,
+//! such as Deno's `Deno.dlopen` and Bun's `bun:ffi`.
+//!
+//! Cases are passed as the same ids as `Case` in `main.ts`.  Strings cross the boundary as
+//! NUL-terminated UTF-8, and every string this library returns must be released with
+//! [`convert_case_free`].  Build the shared library with
+//! `cargo rustc -p convert-case-core --release --features convert-case-core/ffi --crate-type cdylib`.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+
+use convert_case::{Case, Casing, Converter};
+
+/// Returns the case with id `id`, if there is one.
+fn case(id: i32) -> Option<Case>
+{
+    Case::all_cases().into_iter().find(|case| *case as i32 == id)
+}
+
+/// Reads a NUL-terminated UTF-8 string, or returns `None` if `input` is null or isn't UTF-8.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+unsafe fn read<'a>(input: *const c_char) -> Option<&'a str>
+{
+    if input.is_null()
+    {
+        return None;
+    }
+    CStr::from_ptr(input).to_str().ok()
+}
+
+/// Converts `input` to the case `to_case`, splitting it into words as `from_case` would if that
+/// is a case id, or on every boundary if it is negative.
+///
+/// Returns a new string to be released with [`convert_case_free`], or null if `input` is null or
+/// isn't UTF-8, or if either case id is unknown.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn convert_case_convert(input: *const c_char, to_case: i32, from_case: i32) -> *mut c_char
+{
+    let (input, to_case) = match (read(input), case(to_case))
+    {
+        (Some(input), Some(to_case)) => (input, to_case),
+        _ => return ptr::null_mut(),
+    };
+
+    let mut conv = Converter::new().to_case(to_case);
+    if from_case >= 0
+    {
+        match case(from_case)
+        {
+            Some(from_case) => conv = conv.from_case(from_case),
+            None => return ptr::null_mut(),
+        }
+    }
+
+    // Converting never introduces a NUL that wasn't already in `input`
+    CString::new(conv.convert(input)).unwrap().into_raw()
+}
+
+/// Returns the set of cases `input` is already in, with bit `id` set for the case with that id.
+///
+/// A string is in a case if converting it to that case leaves it unchanged, so single words are
+/// in several cases at once; `"name"` is in lower, camel, snake, kebab, and flat case, among
+/// others.  The random cases are never reported.  Returns 0 if `input` is null or isn't UTF-8.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn convert_case_detect(input: *const c_char) -> u32
+{
+    let input = match read(input)
+    {
+        Some(input) => input,
+        None => return 0,
+    };
+
+    Case::deterministic_cases()
+        .into_iter()
+        .filter(|case| input.is_case(*case))
+        .fold(0, |cases, case| cases | 1 << case as u32)
+}
+
+/// Releases a string returned by [`convert_case_convert`].  Does nothing if `string` is null.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by this library that hasn't been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn convert_case_free(string: *mut c_char)
+{
+    if !string.is_null()
+    {
+        drop(CString::from_raw(string));
+    }
+}
@@ -0,0 +1,93 @@
+//! Finds an identifier in text whatever case it is written in.
+
+use convert_case::{Case, Converter};
+
+use crate::consistency;
+
+/// Where an identifier was found by [`find_identifier`].
+pub struct Occurrence
+{
+    /// The byte offset the occurrence starts at.
+    pub start: usize,
+    /// The byte offset just past the end of the occurrence.
+    pub end: usize,
+    /// The conventions the occurrence is written in, as [`consistency::conventions_of`] finds
+    /// them.
+    pub cases: Vec<Case>,
+}
+
+/// Splits `token` into words the way converting it would, returning the byte range of each.
+pub fn words(token: &str) -> Vec<(usize, usize)>
+{
+    let split = Converter::new().set_delim("\0").convert(token);
+
+    // Splitting only removes delimiters, so every word is found in order after the last one
+    let mut cursor = 0;
+    let mut spans = Vec::new();
+    for word in split.split('\0').filter(|word| !word.is_empty())
+    {
+        if let Some(offset) = token[cursor..].find(word)
+        {
+            spans.push((cursor + offset, cursor + offset + word.len()));
+            cursor += offset + word.len();
+        }
+    }
+    spans
+}
+
+/// Finds every occurrence of `name` in `text`, in any case: the words of `name` appearing in the
+/// same order, next to each other, in a run of letters, digits, `_`, and `-`.
+///
+/// Words are compared ignoring case, so `userId` finds `user_id`, `USER_ID`, `user-id`, and the
+/// `UserId` in `getUserId`.  Occurrences don't overlap and are returned in the order they appear.
+pub fn find_identifier(text: &str, name: &str) -> Vec<Occurrence>
+{
+    let wanted: Vec<String> = words(name).into_iter().map(|(start, end)| name[start..end].to_lowercase()).collect();
+    if wanted.is_empty()
+    {
+        return Vec::new();
+    }
+
+    let mut occurrences = Vec::new();
+    let is_token = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    let mut rest = text;
+    while let Some(token_start) = rest.find(is_token)
+    {
+        let token = &rest[token_start..];
+        let token = &token[..token.find(|c: char| !is_token(c)).unwrap_or(token.len())];
+        let offset = text.len() - rest.len() + token_start;
+
+        let spans = words(token);
+        let mut i = 0;
+        while i + wanted.len() <= spans.len()
+        {
+            let matches = spans[i..i + wanted.len()].iter()
+                .zip(&wanted)
+                .all(|((start, end), word)| token[*start..*end].to_lowercase() == *word);
+            if !matches
+            {
+                i += 1;
+                continue;
+            }
+
+            let (start, end) = (spans[i].0, spans[i + wanted.len() - 1].1);
+            occurrences.push(Occurrence {
+                start: offset + start,
+                end: offset + end,
+                cases: consistency::conventions_of(&token[start..end]),
+            });
+            i += wanted.len();
+        }
+
+        rest = &rest[token_start + token.len()..];
+    }
+
+    occurrences
+}
+
+/// Converts the byte offset `offset` of `text` to an index into its UTF-16 code units, as JS
+/// strings are indexed.
+pub fn utf16_index(text: &str, offset: usize) -> usize
+{
+    text[..offset].encode_utf16().count()
+}
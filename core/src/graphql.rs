@@ -0,0 +1,229 @@
+use convert_case::Converter;
+
+/// A field of an object, interface, or input type and the name it was converted to.
+pub struct FieldRename
+{
+    pub type_name: String,
+    pub field: String,
+    pub name: String,
+}
+
+#[derive(PartialEq)]
+enum Token<'a>
+{
+    Name(&'a str),
+    Punctuator(char),
+    /// Strings, numbers, and `...`, none of which ever need renaming.
+    Other,
+}
+
+/// Splits `sdl` into tokens along with their byte offsets, dropping whitespace, commas, and
+/// comments.
+fn tokenize(sdl: &str) -> Result<Vec<(usize, Token<'_>)>, String>
+{
+    let bytes = sdl.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len()
+    {
+        let start = i;
+        match bytes[i]
+        {
+            b' ' | b'\t' | b'\r' | b'\n' | b',' => i += 1,
+            _ if sdl[i..].starts_with('\u{feff}') => i += '\u{feff}'.len_utf8(),
+            b'#' => i = sdl[i..].find('\n').map_or(bytes.len(), |end| i + end),
+            b'"' if sdl[i..].starts_with("\"\"\"") =>
+            {
+                let mut end = i + 3;
+                loop
+                {
+                    match sdl[end..].find("\"\"\"")
+                    {
+                        Some(offset) if bytes[end + offset - 1] == b'\\' => end += offset + 3,
+                        Some(offset) => break end += offset + 3,
+                        None => return Err(String::from("Unterminated block string")),
+                    }
+                }
+                i = end;
+                tokens.push((start, Token::Other));
+            }
+            b'"' =>
+            {
+                i += 1;
+                loop
+                {
+                    match bytes.get(i)
+                    {
+                        Some(b'\\') => i += 2,
+                        Some(b'"') => break i += 1,
+                        Some(b'\n') | None => return Err(String::from("Unterminated string")),
+                        Some(_) => i += 1,
+                    }
+                }
+                tokens.push((start, Token::Other));
+            }
+            b'.' if sdl[i..].starts_with("...") =>
+            {
+                i += 3;
+                tokens.push((start, Token::Other));
+            }
+            c if c == b'_' || c.is_ascii_alphabetic() =>
+            {
+                while i < bytes.len() && (bytes[i] == b'_' || bytes[i].is_ascii_alphanumeric())
+                {
+                    i += 1;
+                }
+                tokens.push((start, Token::Name(&sdl[start..i])));
+            }
+            c if c == b'-' || c.is_ascii_digit() =>
+            {
+                i += 1;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.' || bytes[i] == b'+' || bytes[i] == b'-')
+                {
+                    i += 1;
+                }
+                tokens.push((start, Token::Other));
+            }
+            c if b"!$&()=:@[]{|}".contains(&c) =>
+            {
+                i += 1;
+                tokens.push((start, Token::Punctuator(c as char)));
+            }
+            _ => return Err(format!("Unexpected character at offset {}", i)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Converts the names of the fields and field arguments of every object, interface, and input
+/// type in a GraphQL SDL document, including type extensions.
+///
+/// Type names, enum values, directive names and arguments, default values, descriptions, and
+/// comments are copied verbatim.  Returns the converted document along with every field and its
+/// new name.
+pub fn convert_graphql_fields(sdl: &str, conv: &Converter) -> Result<(String, Vec<FieldRename>), String>
+{
+    let tokens = tokenize(sdl)?;
+    let mut edits: Vec<(usize, &str, String)> = Vec::new();
+    let mut renames = Vec::new();
+
+    // Braces and parentheses outside of type bodies, such as enum bodies and directive definitions
+    let mut depth = 0;
+    let mut i = 0;
+    while i < tokens.len()
+    {
+        match tokens[i].1
+        {
+            Token::Punctuator('{' | '(') => depth += 1,
+            Token::Punctuator('}' | ')') => depth -= 1,
+            Token::Name(keyword @ ("type" | "interface" | "input")) if depth == 0 && !follows_at(&tokens, i) =>
+            {
+                let type_name = match tokens.get(i + 1)
+                {
+                    Some((_, Token::Name(name))) => *name,
+                    _ => return Err(format!("Expected a name after \"{}\"", keyword)),
+                };
+
+                // Skip implemented interfaces and directives up to the body, if there is one
+                let mut j = i + 2;
+                let mut parens = 0;
+                while let Some((_, token)) = tokens.get(j)
+                {
+                    match token
+                    {
+                        Token::Punctuator('(') => parens += 1,
+                        Token::Punctuator(')') => parens -= 1,
+                        Token::Punctuator('{') if parens == 0 => break,
+                        Token::Name("type" | "interface" | "input" | "enum" | "union" | "scalar" | "schema" | "directive" | "extend")
+                            if parens == 0 && !follows_at(&tokens, j) => break,
+                        _ => {}
+                    }
+                    j += 1;
+                }
+
+                if let Some((_, Token::Punctuator('{'))) = tokens.get(j)
+                {
+                    i = convert_body(&tokens, j + 1, type_name, conv, &mut edits, &mut renames)?;
+                }
+                else
+                {
+                    i = j;
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    let mut converted = String::with_capacity(sdl.len());
+    let mut copied = 0;
+    for (start, name, replacement) in edits
+    {
+        converted.push_str(&sdl[copied..start]);
+        converted.push_str(&replacement);
+        copied = start + name.len();
+    }
+    converted.push_str(&sdl[copied..]);
+
+    Ok((converted, renames))
+}
+
+fn follows_at(tokens: &[(usize, Token)], i: usize) -> bool
+{
+    i > 0 && tokens[i - 1].1 == Token::Punctuator('@')
+}
+
+/// Renames the fields and arguments in the body of `type_name`, starting just after its `{`, and
+/// returns the index just past the closing `}`.
+fn convert_body<'a>(tokens: &[(usize, Token<'a>)], start: usize, type_name: &str, conv: &Converter, edits: &mut Vec<(usize, &'a str, String)>, renames: &mut Vec<FieldRename>) -> Result<usize, String>
+{
+    // Whether each open parenthesis holds field arguments rather than directive arguments
+    let mut parens: Vec<bool> = Vec::new();
+    let mut nesting = 0;
+    let mut i = start;
+
+    while let Some((offset, token)) = tokens.get(i)
+    {
+        let next = tokens.get(i + 1).map(|(_, token)| token);
+        match token
+        {
+            Token::Punctuator('(') =>
+            {
+                let after_field = matches!(tokens[i - 1].1, Token::Name(_)) && !follows_at(tokens, i - 1) && parens.is_empty() && nesting == 0;
+                parens.push(after_field);
+            }
+            Token::Punctuator(')') =>
+            {
+                parens.pop();
+            }
+            Token::Punctuator('{' | '[') => nesting += 1,
+            Token::Punctuator('}') if nesting == 0 && parens.is_empty() => return Ok(i + 1),
+            Token::Punctuator('}' | ']') => nesting -= 1,
+            Token::Name(name) if !follows_at(tokens, i) && nesting == 0 =>
+            {
+                let is_field = parens.is_empty() && matches!(next, Some(Token::Punctuator(':' | '(')));
+                let is_argument = parens == [true] && next == Some(&Token::Punctuator(':'));
+
+                if is_field || is_argument
+                {
+                    let converted = conv.convert(name);
+                    if is_field
+                    {
+                        renames.push(FieldRename { type_name: type_name.to_string(), field: name.to_string(), name: converted.clone() });
+                    }
+                    if converted != *name
+                    {
+                        edits.push((*offset, name, converted));
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Err(format!("Unterminated body of type \"{}\"", type_name))
+}
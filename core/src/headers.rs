@@ -0,0 +1,34 @@
+use convert_case::{Boundary, Case, Converter};
+
+/// Words whose canonical spelling in HTTP header names isn't simply capitalized.
+const EXCEPTIONS: &[&str] = &["DNT", "ETag", "MD5", "TE", "UA", "WebSocket", "WWW", "XSS"];
+
+/// Converts an HTTP header name to its canonical Train-Case spelling, such as `Content-Type`,
+/// `ETag`, or `WWW-Authenticate`.
+///
+/// Words are split on `-`, `_`, spaces, and changes of case, but never at digits, so
+/// `content-md5` becomes `Content-MD5` rather than `Content-Md-5`.
+pub fn canonicalize_header(name: &str) -> String
+{
+    let conv = Converter::new()
+        .set_boundaries(&[Boundary::LowerUpper, Boundary::Acronym])
+        .to_case(Case::Train);
+
+    let mut words = Vec::new();
+    for segment in name.trim().split(['-', '_', ' ']).filter(|segment| !segment.is_empty())
+    {
+        // Check whole segments first so that `ETag` and `WebSocket` aren't split by case
+        match exception(segment)
+        {
+            Some(exception) => words.push(exception.to_string()),
+            None => words.extend(conv.convert(segment).split('-').map(|word| exception(word).unwrap_or(word).to_string())),
+        }
+    }
+
+    words.join("-")
+}
+
+fn exception(word: &str) -> Option<&'static str>
+{
+    EXCEPTIONS.iter().find(|exception| exception.eq_ignore_ascii_case(word)).copied()
+}
@@ -0,0 +1,122 @@
+/// Which attribute names should be passed through unchanged when converting them to a case.
+#[derive(Default)]
+pub struct AttributeFilter
+{
+    pub exclude_data: bool,
+    pub exclude_aria: bool,
+}
+
+impl AttributeFilter
+{
+    pub fn skips(&self, name: &str) -> bool
+    {
+        let name = name.to_ascii_lowercase();
+        (self.exclude_data && name.starts_with("data-")) || (self.exclude_aria && name.starts_with("aria-"))
+    }
+}
+
+/// Renames the attributes of every start tag in `html`.
+///
+/// Tag names, text content, attribute values, comments, doctypes, and the raw contents of
+/// `<script>` and `<style>` elements are copied verbatim.
+pub fn rename_attributes(html: &str, rename: &impl Fn(&str) -> String) -> String
+{
+    let mut converted = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<')
+    {
+        converted.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let consumed = if rest.starts_with("<!--")
+        {
+            rest.find("-->").map_or(rest.len(), |end| end + 3)
+        }
+        else if rest.starts_with("<!") || rest.starts_with("<?") || rest.starts_with("</")
+        {
+            rest.find('>').map_or(rest.len(), |end| end + 1)
+        }
+        else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let (tag, consumed) = convert_start_tag(rest, rename);
+            converted.push_str(&tag);
+            rest = &rest[consumed..];
+
+            let name = tag_name(&tag).to_ascii_lowercase();
+            if name == "script" || name == "style"
+            {
+                let end = rest.to_ascii_lowercase().find(&format!("</{}", name)).unwrap_or(rest.len());
+                converted.push_str(&rest[..end]);
+                rest = &rest[end..];
+            }
+            continue;
+        }
+        else
+        {
+            1
+        };
+
+        converted.push_str(&rest[..consumed]);
+        rest = &rest[consumed..];
+    }
+
+    converted.push_str(rest);
+    converted
+}
+
+fn tag_name(tag: &str) -> &str
+{
+    let tag = &tag[1..];
+    let end = tag.find(|c: char| c.is_whitespace() || c == '/' || c == '>').unwrap_or(tag.len());
+    &tag[..end]
+}
+
+/// Converts a single start tag at the beginning of `s`, returning the converted tag and the
+/// number of bytes of `s` it spans.
+fn convert_start_tag(s: &str, rename: &impl Fn(&str) -> String) -> (String, usize)
+{
+    let mut tag = String::from("<");
+    tag.push_str(tag_name(s));
+    let mut i = tag.len();
+
+    while i < s.len()
+    {
+        let c = s[i..].chars().next().unwrap();
+
+        if c == '>'
+        {
+            tag.push(c);
+            return (tag, i + 1);
+        }
+
+        if c.is_whitespace() || c == '/'
+        {
+            tag.push(c);
+            i += c.len_utf8();
+            continue;
+        }
+
+        // Attribute name
+        let name_len = s[i..].find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(s.len() - i);
+        tag.push_str(&rename(&s[i..i + name_len]));
+        i += name_len;
+
+        // Optional value, copied verbatim
+        if let Some(after_eq) = s[i..].trim_start().strip_prefix('=')
+        {
+            let value_start = s.len() - after_eq.trim_start().len();
+            let value = &s[value_start..];
+            let value_len = match value.chars().next()
+            {
+                Some(quote @ '"') | Some(quote @ '\'') => value[1..].find(quote).map_or(value.len(), |end| end + 2),
+                _ => value.find(|c: char| c.is_whitespace() || c == '>').unwrap_or(value.len()),
+            };
+            tag.push_str(&s[i..value_start + value_len]);
+            i = value_start + value_len;
+        }
+    }
+
+    (tag, s.len())
+}
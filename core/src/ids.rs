@@ -0,0 +1,116 @@
+//! Checked conversions from the ids JS passes for a `Case`, `Pattern`, or `Boundary`.
+//!
+//! The bindings can be called without `main.ts` asserting that an id is a member of its enum, so
+//! an id is looked up among the variants instead of being transmuted into one, and one that
+//! isn't an id is an error the bindings throw as a `RangeError`.
+
+use std::convert::TryFrom;
+
+use convert_case::{Boundary, Case, Pattern};
+
+/// A `Case`, `Pattern`, or `Boundary` read from its id, as in `Id::<Case>::try_from(id)?.0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Id<T>(pub T);
+
+/// Every `Pattern`, which `convert_case` has no list of.
+pub fn patterns() -> Vec<Pattern>
+{
+    use Pattern::*;
+    vec![Lowercase, Uppercase, Capital, Sentence, Camel, Alternating, Toggle, Random, PseudoRandom]
+}
+
+impl TryFrom<u8> for Id<Case>
+{
+    type Error = String;
+
+    fn try_from(id: u8) -> Result<Self, String>
+    {
+        match Case::all_cases().into_iter().find(|case| *case as u8 == id)
+        {
+            Some(case) => Ok(Id(case)),
+            None => Err(format!("{} isn't the id of a Case", id)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Id<Pattern>
+{
+    type Error = String;
+
+    fn try_from(id: u8) -> Result<Self, String>
+    {
+        match patterns().into_iter().find(|pattern| *pattern as u8 == id)
+        {
+            Some(pattern) => Ok(Id(pattern)),
+            None => Err(format!("{} isn't the id of a Pattern", id)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Id<Boundary>
+{
+    type Error = String;
+
+    fn try_from(id: u8) -> Result<Self, String>
+    {
+        match Boundary::all().into_iter().find(|boundary| *boundary as u8 == id)
+        {
+            Some(boundary) => Ok(Id(boundary)),
+            None => Err(format!("{} isn't the id of a Boundary", id)),
+        }
+    }
+}
+
+/// Reads an id from a JS number, which has to be an integer from 0 to 255 before it is looked up
+/// as a `u8`, so that `1.5` or `257` isn't read as some other id.
+impl<T> TryFrom<f64> for Id<T>
+where
+    Id<T>: TryFrom<u8, Error = String>,
+{
+    type Error = String;
+
+    fn try_from(id: f64) -> Result<Self, String>
+    {
+        match id.fract() == 0.0 && (0.0..=u8::MAX as f64).contains(&id)
+        {
+            true => Id::try_from(id as u8),
+            false => Err(format!("{} isn't an id", id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn reads_every_id_back_as_its_variant()
+    {
+        for case in Case::all_cases()
+        {
+            assert_eq!(Id::try_from(case as u8), Ok(Id(case)));
+        }
+        for pattern in patterns()
+        {
+            assert_eq!(Id::try_from(pattern as u8), Ok(Id(pattern)));
+        }
+        for boundary in Boundary::all()
+        {
+            assert_eq!(Id::try_from(boundary as u8), Ok(Id(boundary)));
+        }
+    }
+
+    #[test]
+    fn refuses_what_isnt_an_id()
+    {
+        assert!(Id::<Case>::try_from(200u8).is_err());
+        assert!(Id::<Pattern>::try_from(patterns().len() as u8).is_err());
+        assert!(Id::<Boundary>::try_from(u8::MAX).is_err());
+        for id in [-1.0, 1.5, 256.0, f64::NAN, f64::INFINITY]
+        {
+            assert!(Id::<Case>::try_from(id).is_err());
+        }
+        assert_eq!(Id::try_from(2.0), Ok(Id(Pattern::Capital)));
+    }
+}
@@ -0,0 +1,56 @@
+//! What a build of the core supports, so that bindings can report it instead of callers probing
+//! for it.
+
+use std::ops::RangeInclusive;
+
+use convert_case::{Boundary, Case};
+
+use crate::ids;
+
+/// The version of `convert_case` in the core's `Cargo.toml`, which cargo doesn't expose.
+pub const CONVERT_CASE_VERSION: &str = "0.6.0";
+
+/// The cargo features this build of the core was compiled with, including `convert_case`'s
+/// `random` feature, which the random cases and patterns need.
+pub fn features() -> Vec<&'static str>
+{
+    let features = [
+        ("json", cfg!(feature = "json")),
+        ("yaml", cfg!(feature = "yaml")),
+        ("toml", cfg!(feature = "toml")),
+        ("csv", cfg!(feature = "csv")),
+        ("ffi", cfg!(feature = "ffi")),
+        ("random", true),
+    ];
+    features.iter().filter(|(_, enabled)| *enabled).map(|(feature, _)| *feature).collect()
+}
+
+/// The version of the Unicode tables letters are classified and cased with.
+pub fn unicode_version() -> String
+{
+    let (major, minor, update) = char::UNICODE_VERSION;
+    format!("{}.{}.{}", major, minor, update)
+}
+
+/// The ids of every `Case`.
+pub fn case_ids() -> RangeInclusive<u8>
+{
+    id_range(Case::all_cases().into_iter().map(|case| case as u8))
+}
+
+/// The ids of every `Pattern`.
+pub fn pattern_ids() -> RangeInclusive<u8>
+{
+    id_range(ids::patterns().into_iter().map(|pattern| pattern as u8))
+}
+
+/// The ids of every `Boundary`.
+pub fn boundary_ids() -> RangeInclusive<u8>
+{
+    id_range(Boundary::all().into_iter().map(|boundary| boundary as u8))
+}
+
+fn id_range(ids: impl Iterator<Item = u8> + Clone) -> RangeInclusive<u8>
+{
+    ids.clone().min().unwrap_or(0)..=ids.max().unwrap_or(0)
+}
@@ -0,0 +1,120 @@
+//! The JS values the functions in [`exports`](crate::exports) read their arguments from and build
+//! their results out of, which each binding provides for its own handles.
+//!
+//! [`Js`] extends the [`Engine`] of the key walk with what the other functions need: reading
+//! primitives, calling functions, throwing, and the state each instance of a binding keeps.  A
+//! binding implements it once, and every function it exports is then one of `exports`, so the
+//! Node addon, the napi-rs build, and the WebAssembly build read the same options the same way
+//! and return the same results.
+
+use std::cell::{Cell, RefCell};
+
+use crate::cache;
+use crate::key_walker::{Engine, WalkError};
+
+/// What a function of [`exports`](crate::exports) returns to the binding `J`: a JS value, unless
+/// it says otherwise, or the error it threw.
+pub type JsResult<J, T = <J as Engine>::Value> = Result<T, <J as Engine>::Error>;
+
+/// What [`Js::type_of`] found a value to be.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type
+{
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    String,
+    Function,
+    Array,
+    /// Any other object.
+    Object,
+    /// A `bigint` or a symbol.
+    Other,
+}
+
+impl Type
+{
+    /// Whether a value of this type is an object, as arrays and functions are.
+    pub fn is_object(self) -> bool
+    {
+        matches!(self, Type::Function | Type::Array | Type::Object)
+    }
+}
+
+/// An error a function of [`exports`](crate::exports) throws, as the JS class it names.
+pub enum Thrown
+{
+    Error(String),
+    TypeError(String),
+    RangeError(String),
+    /// A `RangeError` with the code `ERR_INPUT_TOO_LONG`, for an input over the length limit.
+    InputTooLong(String),
+}
+
+impl From<WalkError> for Thrown
+{
+    fn from(error: WalkError) -> Self
+    {
+        match error
+        {
+            WalkError::InputTooLong(message) => Thrown::InputTooLong(message),
+            WalkError::TypeError(message) => Thrown::TypeError(message),
+            WalkError::Error(message) => Thrown::Error(message),
+        }
+    }
+}
+
+/// The state of one instance of a binding, which each thread or context that loads it has its
+/// own of: the limit `setMaxInputLength` set and the cache `setCacheSize` turns on.  The trace
+/// callback is kept by the binding itself, as a handle only it can hold on to.
+#[derive(Default)]
+pub struct State
+{
+    pub max_input_length: Cell<Option<usize>>,
+    pub cache: RefCell<cache::Cache>,
+}
+
+/// The operations on JS values a binding provides for the functions it exports.
+pub trait Js: Engine
+{
+    /// The version of the binding, which `nativeInfo` reports.
+    const VERSION: &'static str;
+
+    fn type_of(&mut self, value: &Self::Value) -> Result<Type, Self::Error>;
+
+    /// The value as a boolean, if it is one.
+    fn boolean(&mut self, value: &Self::Value) -> Result<Option<bool>, Self::Error>;
+
+    /// The value as a number, if it is one.
+    fn number(&mut self, value: &Self::Value) -> Result<Option<f64>, Self::Error>;
+
+    /// What `String(value)` makes of the value, as for the decimal digits of a `bigint`.
+    fn to_string(&mut self, value: &Self::Value) -> Result<String, Self::Error>;
+
+    fn undefined(&mut self) -> Result<Self::Value, Self::Error>;
+
+    fn null(&mut self) -> Result<Self::Value, Self::Error>;
+
+    fn new_boolean(&mut self, boolean: bool) -> Result<Self::Value, Self::Error>;
+
+    fn new_number(&mut self, number: f64) -> Result<Self::Value, Self::Error>;
+
+    /// Calls `function` with `args`, and `undefined` as `this`.
+    fn call(&mut self, function: &Self::Value, args: &[Self::Value]) -> Result<Self::Value, Self::Error>;
+
+    fn throw(&mut self, error: Thrown) -> Self::Error;
+
+    /// Runs `f` on the state of the instance the function was called through, or returns `None`
+    /// if it wasn't called through one, as when a function of the Node addon is called on
+    /// something other than its exports.
+    fn with_state<R>(&mut self, f: impl FnOnce(&State) -> R) -> Result<Option<R>, Self::Error>;
+
+    /// The callback `setTraceCallback` set on the instance the function was called through, if
+    /// there is one.
+    fn trace_callback(&mut self) -> Result<Option<Self::Value>, Self::Error>;
+
+    /// Replaces the trace callback of the instance the function was called through, which
+    /// [`with_state`](Js::with_state) has found.
+    fn set_trace_callback(&mut self, callback: Option<Self::Value>) -> Result<(), Self::Error>;
+}
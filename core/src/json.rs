@@ -0,0 +1,176 @@
+use convert_case::Converter;
+use serde_json::{Map, Value};
+
+use crate::collisions::{self, Collision};
+use crate::keys::KeyOptions;
+use crate::mapping::KeyReport;
+
+/// Converts the keys of every object in a parsed JSON document, following the same depth rules as
+/// the addon's JS object walk.  Key collisions that the policy settled and the renames that were
+/// made are added to `report`.  Object keys keep their order.
+///
+/// Fails with the message of the first collision under
+/// [`CollisionPolicy::Error`](crate::collisions::CollisionPolicy), or of the first ambiguous key
+/// under strict mode.
+pub fn convert_value_keys(value: Value, conv: &Converter, options: &KeyOptions, report: &mut KeyReport) -> Result<Value, String>
+{
+    convert_at(value, conv, options, 0, &mut Vec::new(), report)
+}
+
+fn convert_at(value: Value, conv: &Converter, options: &KeyOptions, depth: usize, path: &mut Vec<String>, report: &mut KeyReport) -> Result<Value, String>
+{
+    if options.filter.converts_value(path)
+    {
+        match value
+        {
+            Value::String(string) => return Ok(Value::String(options.convert(conv, &string))),
+            Value::Array(array) => return Ok(Value::Array(
+                array.into_iter()
+                    .map(|element| convert_at(element, conv, options, depth, path, report))
+                    .collect::<Result<_, _>>()?
+            )),
+            _ => {}
+        }
+    }
+
+    if !options.converts_depth(depth)
+    {
+        return Ok(value);
+    }
+
+    match value
+    {
+        Value::Array(array) => Ok(Value::Array(
+            array.into_iter()
+                .map(|element| convert_at(element, conv, options, depth, path, report))
+                .collect::<Result<_, _>>()?
+        )),
+        Value::Object(object) =>
+        {
+            let (keys, values): (Vec<String>, Vec<Value>) = object.into_iter().unzip();
+            let names = keys.iter()
+                .map(|key|
+                {
+                    path.push(key.clone());
+                    let checked = options.check_strict(path);
+                    let renamed = options.renames(path);
+                    path.pop();
+                    checked.map(|()| if renamed { options.convert(conv, key) } else { key.clone() })
+                })
+                .collect::<Result<_, _>>()?;
+            let (names, found) = collisions::resolve(&keys, names, options.collisions, &conv.delim, path)
+                .map_err(|collision| collision.message())?;
+            report.collisions.extend(found);
+
+            let mut converted = Map::with_capacity(keys.len());
+            for ((key, name), value) in keys.into_iter().zip(names).zip(values)
+            {
+                let name = match name
+                {
+                    Some(name) => name,
+                    None => continue,
+                };
+
+                report.record(&key, &name);
+                path.push(key);
+                let value = if options.filter.excludes(path) { value } else { convert_at(value, conv, options, depth + 1, path, report)? };
+                path.pop();
+                converted.insert(name, value);
+            }
+            Ok(Value::Object(converted))
+        }
+        value => Ok(value),
+    }
+}
+
+/// Parses `json`, converts the keys of its objects, and serializes it again.
+pub fn convert_json_keys(json: &str, conv: &Converter, options: &KeyOptions, pretty: bool, report: &mut KeyReport) -> Result<String, String>
+{
+    let value = serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let value = convert_value_keys(value, conv, options, report)?;
+
+    if pretty
+    {
+        serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
+    }
+    else
+    {
+        serde_json::to_string(&value).map_err(|e| e.to_string())
+    }
+}
+
+/// Incrementally converts the keys of newline-delimited JSON.
+///
+/// Chunks may split records anywhere; a record is converted once its terminating newline has been
+/// written.  Line endings and blank lines are passed through unchanged.
+pub struct NdjsonStream
+{
+    conv: Converter,
+    options: KeyOptions,
+    pending: String,
+    line: usize,
+    report: KeyReport,
+}
+
+impl NdjsonStream
+{
+    pub fn new(conv: Converter, options: KeyOptions) -> Self
+    {
+        NdjsonStream { conv, options, pending: String::new(), line: 0, report: KeyReport::default() }
+    }
+
+    /// Buffers `chunk` and returns the converted form of every record it completes.
+    pub fn write(&mut self, chunk: &str) -> Result<String, String>
+    {
+        self.pending.push_str(chunk);
+
+        let complete = match self.pending.rfind('\n')
+        {
+            Some(end) => end + 1,
+            None => return Ok(String::new()),
+        };
+        let rest = self.pending.split_off(complete);
+        let lines = std::mem::replace(&mut self.pending, rest);
+
+        self.convert_lines(&lines)
+    }
+
+    /// Converts whatever is left after the last newline.
+    pub fn end(&mut self) -> Result<String, String>
+    {
+        let lines = std::mem::take(&mut self.pending);
+        self.convert_lines(&lines)
+    }
+
+    /// Returns the key collisions found since the last call.
+    pub fn take_collisions(&mut self) -> Vec<Collision>
+    {
+        std::mem::take(&mut self.report.collisions)
+    }
+
+    fn convert_lines(&mut self, lines: &str) -> Result<String, String>
+    {
+        let mut converted = String::with_capacity(lines.len());
+
+        for line in lines.split_inclusive('\n')
+        {
+            self.line += 1;
+
+            let record = line.trim_end_matches(['\n', '\r']);
+            if record.trim().is_empty()
+            {
+                converted.push_str(line);
+                continue;
+            }
+
+            let value = serde_json::from_str(record)
+                .map_err(|e| format!("Invalid JSON on line {}: {}", self.line, e))?;
+            let value = convert_value_keys(value, &self.conv, &self.options, &mut self.report)
+                .map_err(|e| format!("{} on line {}", e, self.line))?;
+            converted.push_str(&serde_json::to_string(&value).map_err(|e| e.to_string())?);
+            converted.push_str(&line[record.len()..]);
+        }
+
+        Ok(converted)
+    }
+}
@@ -0,0 +1,198 @@
+use convert_case::{Boundary, Case, Converter};
+
+/// HTML attributes whose JSX prop isn't a plain camelization of the name, as `(attribute, prop)`.
+const HTML_PROPS: &[(&str, &str)] = &[
+    ("accept-charset", "acceptCharset"),
+    ("accesskey", "accessKey"),
+    ("allowfullscreen", "allowFullScreen"),
+    ("autocapitalize", "autoCapitalize"),
+    ("autocomplete", "autoComplete"),
+    ("autofocus", "autoFocus"),
+    ("autoplay", "autoPlay"),
+    ("cellpadding", "cellPadding"),
+    ("cellspacing", "cellSpacing"),
+    ("charset", "charSet"),
+    ("class", "className"),
+    ("colspan", "colSpan"),
+    ("contenteditable", "contentEditable"),
+    ("contextmenu", "contextMenu"),
+    ("crossorigin", "crossOrigin"),
+    ("datetime", "dateTime"),
+    ("enctype", "encType"),
+    ("enterkeyhint", "enterKeyHint"),
+    ("fetchpriority", "fetchPriority"),
+    ("for", "htmlFor"),
+    ("formaction", "formAction"),
+    ("formenctype", "formEncType"),
+    ("formmethod", "formMethod"),
+    ("formnovalidate", "formNoValidate"),
+    ("formtarget", "formTarget"),
+    ("frameborder", "frameBorder"),
+    ("hreflang", "hrefLang"),
+    ("http-equiv", "httpEquiv"),
+    ("inputmode", "inputMode"),
+    ("itemid", "itemID"),
+    ("itemprop", "itemProp"),
+    ("itemref", "itemRef"),
+    ("itemscope", "itemScope"),
+    ("itemtype", "itemType"),
+    ("marginheight", "marginHeight"),
+    ("marginwidth", "marginWidth"),
+    ("maxlength", "maxLength"),
+    ("mediagroup", "mediaGroup"),
+    ("minlength", "minLength"),
+    ("nomodule", "noModule"),
+    ("novalidate", "noValidate"),
+    ("ondblclick", "onDoubleClick"),
+    ("playsinline", "playsInline"),
+    ("readonly", "readOnly"),
+    ("referrerpolicy", "referrerPolicy"),
+    ("rowspan", "rowSpan"),
+    ("spellcheck", "spellCheck"),
+    ("srcdoc", "srcDoc"),
+    ("srclang", "srcLang"),
+    ("srcset", "srcSet"),
+    ("tabindex", "tabIndex"),
+    ("usemap", "useMap"),
+];
+
+/// DOM events whose handler name has more than one word, so it can't be capitalized blindly.
+const EVENTS: &[&str] = &[
+    "AnimationEnd", "AnimationIteration", "AnimationStart", "BeforeInput",
+    "CanPlay", "CanPlayThrough", "CompositionEnd", "CompositionStart", "CompositionUpdate",
+    "ContextMenu", "DragEnd", "DragEnter", "DragExit", "DragLeave", "DragOver", "DragStart",
+    "DurationChange", "FocusIn", "FocusOut", "GotPointerCapture", "KeyDown", "KeyPress", "KeyUp",
+    "LoadedData", "LoadedMetadata", "LoadStart", "LostPointerCapture", "MouseDown", "MouseEnter",
+    "MouseLeave", "MouseMove", "MouseOut", "MouseOver", "MouseUp", "PointerCancel", "PointerDown",
+    "PointerEnter", "PointerLeave", "PointerMove", "PointerOut", "PointerOver", "PointerUp",
+    "RateChange", "TimeUpdate", "TouchCancel", "TouchEnd", "TouchMove", "TouchStart",
+    "TransitionEnd", "VolumeChange",
+];
+
+/// SVG attributes that are already camelCase and stay that way in both HTML and JSX.
+const SVG_CAMEL: &[&str] = &[
+    "attributeName", "attributeType", "baseFrequency", "baseProfile", "calcMode", "clipPathUnits",
+    "diffuseConstant", "edgeMode", "filterUnits", "glyphRef", "gradientTransform", "gradientUnits",
+    "kernelMatrix", "kernelUnitLength", "keyPoints", "keySplines", "keyTimes", "lengthAdjust",
+    "limitingConeAngle", "markerHeight", "markerUnits", "markerWidth", "maskContentUnits",
+    "maskUnits", "numOctaves", "pathLength", "patternContentUnits", "patternTransform",
+    "patternUnits", "pointsAtX", "pointsAtY", "pointsAtZ", "preserveAlpha", "preserveAspectRatio",
+    "primitiveUnits", "refX", "refY", "repeatCount", "repeatDur", "requiredExtensions",
+    "requiredFeatures", "specularConstant", "specularExponent", "spreadMethod", "startOffset",
+    "stdDeviation", "stitchTiles", "surfaceScale", "systemLanguage", "tableValues", "targetX",
+    "targetY", "textLength", "viewBox", "viewTarget", "xChannelSelector", "yChannelSelector",
+    "zoomAndPan",
+];
+
+fn is_verbatim(name: &str) -> bool
+{
+    let name = name.to_ascii_lowercase();
+    name.starts_with("data-") || name.starts_with("aria-")
+}
+
+/// Converts an HTML or SVG attribute name to the prop name JSX uses for it.
+///
+/// `data-*` and `aria-*` attributes are kept verbatim, known HTML attributes use React's spelling
+/// (`class` becomes `className`, `tabindex` becomes `tabIndex`), event handlers are camelized
+/// (`onmouseover` becomes `onMouseOver`), and hyphenated or namespaced SVG attributes are
+/// camelized (`stroke-width` becomes `strokeWidth`, `xlink:href` becomes `xlinkHref`).  Anything
+/// else, including single-word attributes and SVG's own camelCase attributes, is unchanged.
+pub fn html_to_jsx_prop(name: &str) -> String
+{
+    if is_verbatim(name) || SVG_CAMEL.contains(&name)
+    {
+        return name.to_string();
+    }
+
+    let lower = name.to_ascii_lowercase();
+    if let Some((_, prop)) = HTML_PROPS.iter().find(|(attribute, _)| *attribute == lower)
+    {
+        return prop.to_string();
+    }
+
+    if let Some(event) = lower.strip_prefix("on").filter(|event| !event.is_empty())
+    {
+        return match EVENTS.iter().find(|known| known.eq_ignore_ascii_case(event))
+        {
+            Some(known) => format!("on{}", known),
+            None =>
+            {
+                let first = event.chars().next().unwrap();
+                format!("on{}{}", first.to_uppercase(), &event[first.len_utf8()..])
+            }
+        };
+    }
+
+    if name.contains(['-', ':'])
+    {
+        return Converter::new()
+            .set_boundaries(&[Boundary::Hyphen])
+            .to_case(Case::Camel)
+            .convert(name.replace(':', "-"));
+    }
+
+    name.to_string()
+}
+
+/// Converts a JSX prop name back to the HTML or SVG attribute it stands for, the inverse of
+/// [`html_to_jsx_prop`].
+///
+/// Props that aren't known HTML attributes or event handlers are taken to be SVG presentation
+/// attributes and hyphenated (`strokeWidth` becomes `stroke-width`), except for SVG's own camelCase
+/// attributes such as `viewBox`.
+pub fn jsx_to_html_attribute(name: &str) -> String
+{
+    if is_verbatim(name) || SVG_CAMEL.contains(&name)
+    {
+        return name.to_string();
+    }
+
+    if let Some((attribute, _)) = HTML_PROPS.iter().find(|(_, prop)| *prop == name)
+    {
+        return attribute.to_string();
+    }
+
+    if let Some(event) = name.strip_prefix("on").filter(|event| event.starts_with(|c: char| c.is_ascii_uppercase()))
+    {
+        return format!("on{}", event.to_ascii_lowercase());
+    }
+
+    for namespace in ["xlink", "xmlns", "xml"]
+    {
+        if let Some(rest) = name.strip_prefix(namespace).filter(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+        {
+            return format!("{}:{}", namespace, Converter::new().to_case(Case::Kebab).convert(rest));
+        }
+    }
+
+    Converter::new()
+        .set_boundaries(&[Boundary::LowerUpper])
+        .to_case(Case::Kebab)
+        .convert(name)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn known_attributes_round_trip()
+    {
+        let pairs = [("class", "className"), ("tabindex", "tabIndex"), ("ondblclick", "onDoubleClick"), ("stroke-width", "strokeWidth"), ("xlink:href", "xlinkHref"), ("viewBox", "viewBox"), ("data-user-id", "data-user-id")];
+        for (html, jsx) in pairs
+        {
+            assert_eq!(html_to_jsx_prop(html), jsx);
+            assert_eq!(jsx_to_html_attribute(jsx), html);
+        }
+    }
+
+    #[test]
+    fn unknown_events_are_capitalized_by_character()
+    {
+        assert_eq!(html_to_jsx_prop("onéclick"), "onÉclick");
+        assert_eq!(html_to_jsx_prop("on日本"), "on日本");
+        assert_eq!(html_to_jsx_prop("onßwipe"), "onSSwipe");
+        assert_eq!(html_to_jsx_prop("on"), "on");
+    }
+}
@@ -0,0 +1,66 @@
+use convert_case::{Boundary, Case, Converter};
+
+use crate::trace;
+
+/// The longest name an RFC 1123 label, and so most Kubernetes resource names and label values,
+/// may have.
+pub const MAX_LENGTH: usize = 63;
+
+/// A Kubernetes resource name derived from an arbitrary string.
+pub struct K8sName
+{
+    pub name: String,
+    /// Whether words had to be dropped or cut to fit in [`MAX_LENGTH`].
+    pub truncated: bool,
+}
+
+/// Converts `input` to a valid RFC 1123 label: lowercase ASCII letters, digits, and hyphens,
+/// starting and ending with a letter or digit, and at most [`MAX_LENGTH`] characters long.
+///
+/// Words are split at ASCII punctuation, whitespace, and changes of case, and joined with hyphens.
+/// Other characters, such as accented letters, are dropped.  A name that is too long loses whole
+/// words from its end, and only its first word is cut mid-word if that alone is too long.
+/// Returns `None` if no letters or digits are left.
+pub fn to_k8s_name(input: &str) -> Option<K8sName>
+{
+    // Only ASCII letters and digits survive, so punctuation separates words and anything else goes
+    let cleaned: String = input
+        .chars()
+        .filter(char::is_ascii)
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    let kebab = Converter::new()
+        .set_boundaries(&[Boundary::Space, Boundary::LowerUpper, Boundary::Acronym, Boundary::DigitUpper])
+        .to_case(Case::Kebab)
+        .convert(cleaned);
+
+    let words: Vec<&str> = kebab.split('-').filter(|word| !word.is_empty()).collect();
+    let first = words.first()?;
+
+    let mut name = String::with_capacity(MAX_LENGTH);
+    let mut truncated = false;
+    for word in &words
+    {
+        let separator = usize::from(!name.is_empty());
+        if name.len() + separator + word.len() > MAX_LENGTH
+        {
+            trace::fallback("toK8sName", input, || format!("dropped the words from \"{}\" on to fit in {} characters", word, MAX_LENGTH));
+            truncated = true;
+            break;
+        }
+        if separator == 1
+        {
+            name.push('-');
+        }
+        name.push_str(word);
+    }
+
+    if name.is_empty()
+    {
+        trace::fallback("toK8sName", input, || format!("cut the first word, \"{}\", to {} characters", first, MAX_LENGTH));
+        name.push_str(&first[..MAX_LENGTH]);
+    }
+
+    Some(K8sName { name, truncated })
+}
@@ -0,0 +1,370 @@
+//! The walk over JS values that converts the keys of plain objects, `Map`s, and `Set`s, shared by
+//! the Node addon, the napi-rs build, and the WebAssembly build.
+//!
+//! Each binding implements [`Engine`] for its own handles to JS values, and [`KeyWalker`] does
+//! the rest: what is converted, at which depth and path, how collisions are settled, and what is
+//! refused, so that every build converts the same objects into the same results.
+
+use convert_case::Converter;
+
+use crate::collisions;
+use crate::conversion::{self, CaseOptions};
+use crate::keys::KeyOptions;
+use crate::mapping::{KeyMapping, KeyReport};
+
+/// What [`Engine::kind`] found a value to be.
+pub enum Kind
+{
+    Array,
+    /// A plain object, whose prototype is `Object.prototype`.
+    Object,
+    /// An object whose prototype is `null`.
+    NullObject,
+    Map,
+    Set,
+    /// Any other object, such as a class instance or a date.
+    Instance,
+    /// A primitive or a function.
+    Other,
+}
+
+/// Why [`KeyWalker`] refused a value, which the binding throws as the error it names.
+pub enum WalkError
+{
+    /// A `RangeError` with the code `ERR_INPUT_TOO_LONG`, as `toCase` throws.
+    InputTooLong(String),
+    TypeError(String),
+    /// An `Error`, for keys that collide under [`CollisionPolicy::Error`](collisions::CollisionPolicy::Error).
+    Error(String),
+}
+
+/// The `[key, value]` entries of a `Map`, in order.
+pub type Entries<V> = Vec<(V, V)>;
+
+/// The operations on JS values a binding provides for [`KeyWalker`].
+///
+/// Values passed to the methods for a container have been found to be that container by
+/// [`Engine::kind`].
+pub trait Engine
+{
+    type Value: Clone;
+    type Error;
+
+    fn kind(&mut self, value: &Self::Value) -> Result<Kind, Self::Error>;
+
+    /// The value as a string, if it is one.
+    fn string(&mut self, value: &Self::Value) -> Result<Option<String>, Self::Error>;
+
+    fn new_string(&mut self, string: &str) -> Result<Self::Value, Self::Error>;
+
+    /// Whether `a` and `b` are the same object.
+    fn same(&mut self, a: &Self::Value, b: &Self::Value) -> Result<bool, Self::Error>;
+
+    fn elements(&mut self, array: &Self::Value) -> Result<Vec<Self::Value>, Self::Error>;
+
+    fn new_array(&mut self, elements: Vec<Self::Value>) -> Result<Self::Value, Self::Error>;
+
+    /// The own enumerable string keys of an object, in order.
+    fn keys(&mut self, object: &Self::Value) -> Result<Vec<String>, Self::Error>;
+
+    fn get(&mut self, object: &Self::Value, key: &str) -> Result<Self::Value, Self::Error>;
+
+    fn new_object(&mut self, null_prototype: bool) -> Result<Self::Value, Self::Error>;
+
+    /// Stores `value` under `name`, defining `__proto__` as an own property rather than calling
+    /// its setter.
+    fn define(&mut self, object: &Self::Value, name: &str, value: Self::Value) -> Result<(), Self::Error>;
+
+    /// Copies the own enumerable symbol keys of `from` and their values onto `to`.
+    fn copy_symbols(&mut self, from: &Self::Value, to: &Self::Value) -> Result<(), Self::Error>;
+
+    fn entries(&mut self, map: &Self::Value) -> Result<Entries<Self::Value>, Self::Error>;
+
+    fn new_map(&mut self, entries: Entries<Self::Value>) -> Result<Self::Value, Self::Error>;
+
+    fn members(&mut self, set: &Self::Value) -> Result<Vec<Self::Value>, Self::Error>;
+
+    fn new_set(&mut self, members: Vec<Self::Value>) -> Result<Self::Value, Self::Error>;
+
+    fn error(&mut self, error: WalkError) -> Self::Error;
+}
+
+/// A container [`KeyWalker`] converts into a new one.
+enum Container
+{
+    Array,
+    Object
+    {
+        null_prototype: bool,
+    },
+    Map,
+    Set,
+}
+
+/// Renames the own enumerable string keys of plain JS objects and the string keys of `Map`s.
+///
+/// Only plain objects (those whose prototype is `Object.prototype` or `null`), `Map`s, and `Set`s
+/// are converted, always into new collections; every other value, including class instances,
+/// dates, and functions, is kept by reference.  Arrays and `Set`s are walked transparently, so an
+/// array of records has the keys of each record converted at the same depth as the array itself.
+/// The string members of a `Set` are converted as if they were keys.
+///
+/// Converted objects keep the insertion order of their keys and a `null` prototype if they had
+/// one.  Array index keys and enumerable symbol keys are copied without being renamed, and a
+/// `__proto__` key is defined as an own property instead of replacing the prototype.
+pub struct KeyWalker<V>
+{
+    conv: Converter,
+    options: KeyOptions,
+    ancestors: Vec<V>,
+    path: Vec<String>,
+    report: KeyReport,
+    max_input_length: Option<usize>,
+}
+
+impl<V: Clone> KeyWalker<V>
+{
+    pub fn new(conv: Converter, options: KeyOptions) -> Self
+    {
+        KeyWalker { conv, options, ancestors: Vec::new(), path: Vec::new(), report: KeyReport::default(), max_input_length: None }
+    }
+
+    /// Records the renames made by [`convert`](Self::convert) in the report.
+    pub fn with_mapping(mut self) -> Self
+    {
+        self.report.mapping = Some(KeyMapping::default());
+        self
+    }
+
+    /// Refuses keys and string values longer than `max_input_length` as `toCase` refuses strings.
+    pub fn with_max_input_length(mut self, max_input_length: Option<usize>) -> Self
+    {
+        self.max_input_length = max_input_length;
+        self
+    }
+
+    /// The key collisions found and the renames made so far.
+    pub fn report(&self) -> &KeyReport
+    {
+        &self.report
+    }
+
+    /// Returns a copy of `value` with its keys converted, or `value` itself if it isn't a plain
+    /// object or array.
+    pub fn convert<E: Engine<Value = V>>(&mut self, engine: &mut E, value: V) -> Result<V, E::Error>
+    {
+        self.convert_at(engine, value, 0)
+    }
+
+    fn check_length<E: Engine<Value = V>>(&self, engine: &mut E, input: &str) -> Result<(), E::Error>
+    {
+        let options = CaseOptions { max_input_length: self.max_input_length, ..Default::default() };
+        match conversion::input_too_long(input, &options)
+        {
+            Some(message) => Err(engine.error(WalkError::InputTooLong(message))),
+            None => Ok(()),
+        }
+    }
+
+    fn convert_at<E: Engine<Value = V>>(&mut self, engine: &mut E, value: V, depth: usize) -> Result<V, E::Error>
+    {
+        let converts_value = self.options.filter.converts_value(&self.path);
+        if converts_value
+        {
+            if let Some(string) = engine.string(&value)?
+            {
+                self.check_length(engine, &string)?;
+                return engine.new_string(&self.options.convert(&self.conv, &string));
+            }
+        }
+        else if !self.options.converts_depth(depth)
+        {
+            return Ok(value);
+        }
+
+        let container = match engine.kind(&value)?
+        {
+            Kind::Array if converts_value => return self.convert_array(engine, &value, depth),
+            _ if !self.options.converts_depth(depth) => return Ok(value),
+            Kind::Array => Container::Array,
+            Kind::Object => Container::Object { null_prototype: false },
+            Kind::NullObject => Container::Object { null_prototype: true },
+            Kind::Map => Container::Map,
+            Kind::Set => Container::Set,
+            Kind::Instance if self.options.strict =>
+            {
+                let message = format!("Strict key conversion found an object that isn't a plain object, array, Map, or Set at \"{}\"", self.path.join("."));
+                return Err(engine.error(WalkError::TypeError(message)));
+            }
+            Kind::Instance | Kind::Other => return Ok(value),
+        };
+
+        self.enter(engine, &value)?;
+        let converted = match container
+        {
+            Container::Array => self.convert_array(engine, &value, depth)?,
+            Container::Object { null_prototype } => self.convert_object(engine, &value, depth, null_prototype)?,
+            Container::Map => self.convert_map(engine, &value, depth)?,
+            Container::Set => self.convert_set(engine, &value, depth)?,
+        };
+        self.ancestors.pop();
+
+        Ok(converted)
+    }
+
+    fn convert_array<E: Engine<Value = V>>(&mut self, engine: &mut E, array: &V, depth: usize) -> Result<V, E::Error>
+    {
+        let elements = engine.elements(array)?
+            .into_iter()
+            .map(|element| self.convert_at(engine, element, depth))
+            .collect::<Result<_, _>>()?;
+
+        engine.new_array(elements)
+    }
+
+    fn convert_object<E: Engine<Value = V>>(&mut self, engine: &mut E, object: &V, depth: usize, null_prototype: bool) -> Result<V, E::Error>
+    {
+        let converted = engine.new_object(null_prototype)?;
+        let keys = engine.keys(object)?;
+        let names = self.resolve(engine, &keys)?;
+
+        for (key, name) in keys.into_iter().zip(names)
+        {
+            if let Some(name) = name
+            {
+                let value = engine.get(object, &key)?;
+                let value = self.convert_value(engine, key, value, depth)?;
+                engine.define(&converted, &name, value)?;
+            }
+        }
+        engine.copy_symbols(object, &converted)?;
+
+        Ok(converted)
+    }
+
+    /// Returns the name `key` is renamed to at the current path, honoring the path filter.
+    fn rename(&mut self, key: String) -> String
+    {
+        self.path.push(key);
+        let renamed = self.options.renames(&self.path);
+        let key = self.path.pop().unwrap();
+
+        if renamed { self.options.convert(&self.conv, &key) } else { key }
+    }
+
+    /// Renames the keys of one object and settles any collisions between them.
+    fn resolve<E: Engine<Value = V>>(&mut self, engine: &mut E, keys: &[String]) -> Result<Vec<Option<String>>, E::Error>
+    {
+        for key in keys
+        {
+            self.check_length(engine, key)?;
+            self.path.push(key.clone());
+            let checked = self.options.check_strict(&self.path);
+            self.path.pop();
+            if let Err(message) = checked
+            {
+                return Err(engine.error(WalkError::TypeError(message)));
+            }
+        }
+
+        let names = keys.iter().map(|key| self.rename(key.clone())).collect();
+
+        match collisions::resolve(keys, names, self.options.collisions, &self.conv.delim, &self.path)
+        {
+            Ok((names, collisions)) =>
+            {
+                self.report.collisions.extend(collisions);
+                for (key, name) in keys.iter().zip(&names)
+                {
+                    if let Some(name) = name
+                    {
+                        self.report.record(key, name);
+                    }
+                }
+                Ok(names)
+            }
+            Err(collision) => Err(engine.error(WalkError::Error(collision.message()))),
+        }
+    }
+
+    /// Converts the value stored under `key`, unless the path filter excludes it.
+    fn convert_value<E: Engine<Value = V>>(&mut self, engine: &mut E, key: String, value: V, depth: usize) -> Result<V, E::Error>
+    {
+        self.path.push(key);
+        let value = if self.options.filter.excludes(&self.path) { Ok(value) } else { self.convert_at(engine, value, depth + 1) };
+        self.path.pop();
+
+        value
+    }
+
+    fn convert_map<E: Engine<Value = V>>(&mut self, engine: &mut E, map: &V, depth: usize) -> Result<V, E::Error>
+    {
+        let mut pairs = Vec::new();
+        let mut keys = Vec::new();
+        for (key, value) in engine.entries(map)?
+        {
+            // String keys are renamed, so remember where they are in `keys`
+            let string_key = engine.string(&key)?.map(|string| {
+                keys.push(string);
+                keys.len() - 1
+            });
+            pairs.push((key, string_key, value));
+        }
+
+        let names = self.resolve(engine, &keys)?;
+        let mut converted = Vec::with_capacity(pairs.len());
+        for (key, string_key, value) in pairs
+        {
+            converted.push(match string_key
+            {
+                None => (key, self.convert_at(engine, value, depth + 1)?),
+                Some(i) => match &names[i]
+                {
+                    Some(name) => (engine.new_string(name)?, self.convert_value(engine, keys[i].clone(), value, depth)?),
+                    None => continue,
+                },
+            });
+        }
+
+        engine.new_map(converted)
+    }
+
+    fn convert_set<E: Engine<Value = V>>(&mut self, engine: &mut E, set: &V, depth: usize) -> Result<V, E::Error>
+    {
+        let mut converted = Vec::new();
+        for member in engine.members(set)?
+        {
+            converted.push(match engine.string(&member)?
+            {
+                Some(string) => self.convert_member(engine, string)?,
+                None => self.convert_at(engine, member, depth)?,
+            });
+        }
+
+        engine.new_set(converted)
+    }
+
+    /// Converts a string `Set` member as if it were a key at the current path.
+    fn convert_member<E: Engine<Value = V>>(&mut self, engine: &mut E, member: String) -> Result<V, E::Error>
+    {
+        self.check_length(engine, &member)?;
+        let name = self.rename(member.clone());
+        self.report.record(&member, &name);
+
+        engine.new_string(&name)
+    }
+
+    fn enter<E: Engine<Value = V>>(&mut self, engine: &mut E, object: &V) -> Result<(), E::Error>
+    {
+        for ancestor in &self.ancestors
+        {
+            if engine.same(ancestor, object)?
+            {
+                return Err(engine.error(WalkError::TypeError("Cannot convert the keys of a cyclic structure".to_string())));
+            }
+        }
+
+        self.ancestors.push(object.clone());
+        Ok(())
+    }
+}
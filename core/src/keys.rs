@@ -0,0 +1,84 @@
+use convert_case::Converter;
+
+use crate::collisions::CollisionPolicy;
+use crate::conversion;
+use crate::paths::PathFilter;
+use crate::rename_all::RenameRule;
+use crate::trace;
+
+/// How far into a value the key conversion functions rename keys, for both [`json`](crate::json)
+/// and the JS object walk of the Node binding.
+pub struct KeyOptions
+{
+    /// Convert the keys of nested plain objects, not just the top level.
+    pub deep: bool,
+
+    /// The number of object levels whose keys are converted when `deep` is set.  `None` means
+    /// there is no limit.
+    pub max_depth: Option<usize>,
+
+    /// Which keys are renamed, which subtrees are skipped, and which string values are converted.
+    pub filter: PathFilter,
+
+    /// What to do when keys of the same object convert to the same name.
+    pub collisions: CollisionPolicy,
+
+    /// Throw instead of guessing when a key or value can't be converted unambiguously.
+    pub strict: bool,
+
+    /// Rename keys and values exactly as serde's `rename_all` would instead of with the converter.
+    pub rename_all: Option<RenameRule>,
+}
+
+impl KeyOptions
+{
+    /// Converts a key or value, with the `rename_all` rule if there is one.
+    pub fn convert(&self, conv: &Converter, name: &str) -> String
+    {
+        match self.rename_all
+        {
+            Some(rule) => rule.apply(name),
+            None =>
+            {
+                trace::split_with(name, || conversion::words(conv, name));
+                conv.convert(name)
+            }
+        }
+    }
+
+    pub fn converts_depth(&self, depth: usize) -> bool
+    {
+        if !self.deep
+        {
+            return depth == 0;
+        }
+
+        self.max_depth.is_none_or(|max_depth| depth < max_depth)
+    }
+
+    /// Whether the last key of `path` is renamed.  Array index keys such as `"10"` never are, since
+    /// objects order them before every other key regardless of when they were inserted.
+    pub fn renames(&self, path: &[String]) -> bool
+    {
+        !path.last().is_some_and(|key| is_index(key))
+            && !self.filter.excludes(path)
+            && self.filter.includes(path)
+    }
+
+    /// Returns the error for the last key of `path` under strict mode, if it has one.
+    pub fn check_strict(&self, path: &[String]) -> Result<(), String>
+    {
+        if self.strict && path.last().is_some_and(|key| key == "__proto__")
+        {
+            return Err(format!("Strict key conversion found a \"__proto__\" key at \"{}\"", path.join(".")));
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether `key` is a canonical array index, a key JS objects always keep in numeric order.
+fn is_index(key: &str) -> bool
+{
+    key == "0" || (!key.starts_with('0') && key.parse::<u32>().is_ok_and(|index| index != u32::MAX))
+}
@@ -0,0 +1,66 @@
+//! The conversion logic behind the `node-convert-case` addon, free of any Node or neon
+//! dependency, so that Rust programs and tests get exactly the behavior the addon has.
+//!
+//! Every module works on plain Rust strings and values, except for `exports`, which reads the
+//! arguments of the functions the bindings export and builds their results over the JS values of
+//! `js`.  Each binding only converts between its own handles and those values.
+//!
+//! The document formats that need a parser are behind the `json`, `yaml`, `toml`, and `csv`
+//! features, all on by default, so that embedders who don't need them can leave the parsers out.
+
+pub mod accessors;
+pub mod acronyms;
+pub mod adjust;
+pub mod cache;
+pub mod collisions;
+pub mod columns;
+pub mod consistency;
+pub mod conversion;
+pub mod css;
+#[cfg(feature = "csv")]
+pub mod delimited;
+pub mod dropped;
+pub mod dts;
+pub mod exports;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod find;
+pub mod graphql;
+pub mod headers;
+pub mod html;
+pub mod ids;
+pub mod info;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod js;
+pub mod jsx;
+pub mod k8s;
+pub mod key_walker;
+pub mod keys;
+pub mod lines;
+pub mod lint;
+pub mod macros;
+pub mod mapping;
+pub mod matching;
+#[cfg(feature = "json")]
+pub mod openapi;
+pub mod packages;
+pub mod paths;
+pub mod presets;
+pub mod proto;
+pub mod query;
+pub mod random;
+pub mod rename_all;
+pub mod rename_map;
+pub mod reserved;
+pub mod reversibility;
+pub mod renames;
+pub mod resource;
+pub mod segmentation;
+pub mod sql;
+#[cfg(feature = "toml")]
+pub mod toml;
+pub mod trace;
+pub mod truncation;
+#[cfg(feature = "yaml")]
+pub mod yaml;
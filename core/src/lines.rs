@@ -0,0 +1,48 @@
+use convert_case::Converter;
+
+/// Converts only the key side of every `key<separator>value` line in `text`.
+///
+/// Values, line endings, and the whitespace around keys are kept exactly as they are.  Blank
+/// lines, lines without the separator, and comment lines starting with `#` or `!` are left
+/// untouched.  A leading `export ` (dotenv) or `- ` (YAML sequence item) is kept in front of
+/// the converted key.
+pub fn convert_line_keys(text: &str, conv: &Converter, separator: &str) -> String
+{
+    let mut converted = String::with_capacity(text.len());
+
+    for line in text.split_inclusive('\n')
+    {
+        match convert_line_key(line, conv, separator)
+        {
+            Some(line) => converted.push_str(&line),
+            None => converted.push_str(line),
+        }
+    }
+
+    converted
+}
+
+fn convert_line_key(line: &str, conv: &Converter, separator: &str) -> Option<String>
+{
+    let trimmed = line.trim_start();
+    if separator.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!')
+    {
+        return None;
+    }
+
+    let (key_side, rest) = line.split_at(line.find(separator)?);
+    let indent = &key_side[..key_side.len() - key_side.trim_start().len()];
+    let key_side = &key_side[indent.len()..];
+
+    let (prefix, key_side) = ["export ", "- "].iter()
+        .find_map(|prefix| key_side.strip_prefix(prefix).map(|stripped| (*prefix, stripped)))
+        .unwrap_or(("", key_side));
+
+    let key = key_side.trim_end();
+    if key.is_empty()
+    {
+        return None;
+    }
+
+    Some(format!("{}{}{}{}{}", indent, prefix, conv.convert(key), &key_side[key.len()..], rest))
+}
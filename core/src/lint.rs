@@ -0,0 +1,128 @@
+//! Checks identifiers against an expected case, explaining each violation and how to fix it.
+
+use convert_case::{Boundary, Case, Casing};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Severity
+{
+    Error,
+    Warning,
+}
+
+impl Severity
+{
+    pub fn name(self) -> &'static str
+    {
+        match self
+        {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// One way an identifier departs from the expected case.
+pub struct Problem
+{
+    /// The boundary between words the expected case doesn't have, or `None` if the words are
+    /// right and only their letters are cased wrongly.
+    pub boundary: Option<Boundary>,
+    pub message: String,
+}
+
+pub struct Violation
+{
+    /// The position of the identifier in the list that was linted.
+    pub index: usize,
+    pub identifier: String,
+    /// An error if the identifier splits into words differently than the expected case would,
+    /// and a warning if only the casing of its letters is wrong.
+    pub severity: Severity,
+    pub problems: Vec<Problem>,
+    /// The identifier converted to the expected case.
+    pub fix: String,
+}
+
+#[derive(Default)]
+pub struct LintOptions
+{
+    /// Identifiers that are never reported, such as names fixed by an external API.
+    pub allow: Vec<String>,
+    /// Whether underscores at the start of an identifier, as in `_private`, are kept as they are.
+    pub allow_leading_underscore: bool,
+}
+
+/// Describes what `boundary` means for an identifier that has it.
+pub fn describe(boundary: Boundary) -> &'static str
+{
+    match boundary
+    {
+        Boundary::Hyphen => "contains a hyphen",
+        Boundary::Underscore => "contains an underscore",
+        Boundary::Space => "contains a space",
+        Boundary::UpperLower => "contains lowercase after uppercase",
+        Boundary::LowerUpper => "contains uppercase after lowercase",
+        Boundary::DigitUpper => "contains uppercase after digit",
+        Boundary::UpperDigit => "contains digit after uppercase",
+        Boundary::DigitLower => "contains lowercase after digit",
+        Boundary::LowerDigit => "contains digit after lowercase",
+        Boundary::Acronym => "contains an acronym",
+    }
+}
+
+/// The name of `case` in words, as in `"upper snake"`.
+pub fn case_name(case: Case) -> String
+{
+    format!("{:?}", case).to_case(Case::Lower)
+}
+
+/// Reports every one of `identifiers` that isn't in `case`, with the boundaries between its words
+/// that `case` wouldn't produce and the name it would have in `case`.
+///
+/// The boundaries reported are those found in the identifier but not in its fix, except for an
+/// uppercase letter followed by a lowercase one, which only means a word is capitalized; an
+/// identifier whose words are already right gets a single problem about its casing instead.
+pub fn lint_identifiers(identifiers: &[String], case: Case, options: &LintOptions) -> Vec<Violation>
+{
+    identifiers.iter()
+        .enumerate()
+        .filter(|(_, identifier)| !options.allow.contains(identifier))
+        .filter_map(|(index, identifier)| lint(index, identifier, case, options))
+        .collect()
+}
+
+fn lint(index: usize, identifier: &str, case: Case, options: &LintOptions) -> Option<Violation>
+{
+    let (prefix, name) = match options.allow_leading_underscore
+    {
+        true => identifier.split_at(identifier.len() - identifier.trim_start_matches('_').len()),
+        false => ("", identifier),
+    };
+    if name.is_empty() || name.is_case(case)
+    {
+        return None;
+    }
+
+    let fixed = name.to_case(case);
+    let kept = Boundary::list_from(&fixed);
+    let mut problems: Vec<Problem> = Boundary::list_from(name)
+        .into_iter()
+        .filter(|boundary| *boundary != Boundary::UpperLower && !kept.contains(boundary))
+        .map(|boundary| Problem { boundary: Some(boundary), message: describe(boundary).to_string() })
+        .collect();
+
+    let severity = if problems.is_empty() { Severity::Warning } else { Severity::Error };
+    if problems.is_empty()
+    {
+        let message = format!("letters aren't cased as {} case requires", case_name(case));
+        problems.push(Problem { boundary: None, message });
+    }
+
+    Some(Violation {
+        index,
+        identifier: identifier.to_string(),
+        severity,
+        problems,
+        fix: format!("{}{}", prefix, fixed),
+    })
+}
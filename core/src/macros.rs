@@ -0,0 +1,58 @@
+use convert_case::{Boundary, Case, Converter};
+
+use crate::trace;
+
+/// Converts `input` to a SCREAMING_SNAKE_CASE C preprocessor identifier.
+///
+/// Every character other than an ASCII letter or digit separates words, except for other
+/// non-ASCII characters, which are dropped, and runs of separators become a single underscore.
+/// Words also split at changes of case, but never before a digit, so `sha256Sum` becomes
+/// `SHA256_SUM`.  Names never start or end with an underscore, since names starting with one are
+/// reserved, and a name that would start with a digit gets an `X_` prefix.  Returns `None` if no
+/// letters or digits are left.
+pub fn to_macro_name(input: &str) -> Option<String>
+{
+    let cleaned: String = input
+        .chars()
+        .filter(char::is_ascii)
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    let name = Converter::new()
+        .set_boundaries(&[Boundary::Space, Boundary::LowerUpper, Boundary::Acronym, Boundary::DigitUpper])
+        .to_case(Case::UpperSnake)
+        .convert(cleaned.trim());
+
+    // Converting leaves an empty word, and so an extra underscore, for each extra space
+    let name = name.split('_').filter(|word| !word.is_empty()).collect::<Vec<_>>().join("_");
+    if name.is_empty()
+    {
+        return None;
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        trace::fallback("toMacroName", input, || format!("prefixed \"{}\" with X_ since it starts with a digit", name));
+        return Some(format!("X_{}", name));
+    }
+    Some(name)
+}
+
+/// Derives an include guard from the path of a header, such as `FOO_BAR_H` from `foo/bar.h`.
+///
+/// The path is converted as in [`to_macro_name`] after dropping a drive letter and any `.` and
+/// `..` segments, so `./src/util.h` and `../src/util.h` both give `SRC_UTIL_H`.
+pub fn to_include_guard(path: &str) -> Option<String>
+{
+    let path = match path.as_bytes()
+    {
+        [drive, b':', ..] if drive.is_ascii_alphabetic() => &path[2..],
+        _ => path,
+    };
+
+    let segments: Vec<&str> = path
+        .split(['/', '\\'])
+        .filter(|segment| !matches!(*segment, "" | "." | ".."))
+        .collect();
+    to_macro_name(&segments.join(" "))
+}
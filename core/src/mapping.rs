@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use crate::collisions::Collision;
+
+/// The renames made while converting the keys of one value, in the order they were first made.
+///
+/// The forward direction maps each original key to the first name it was stored under, and the
+/// reverse direction maps each stored name back to the first key that produced it, so renames that
+/// a collision policy introduced (`foo_bar_2`) still lead back to their original key.
+#[derive(Default)]
+pub struct KeyMapping
+{
+    forward: Vec<(String, String)>,
+    reverse: Vec<(String, String)>,
+    keys: HashSet<String>,
+    names: HashSet<String>,
+}
+
+impl KeyMapping
+{
+    pub fn record(&mut self, key: &str, name: &str)
+    {
+        if self.keys.insert(key.to_string())
+        {
+            self.forward.push((key.to_string(), name.to_string()));
+        }
+        if self.names.insert(name.to_string())
+        {
+            self.reverse.push((name.to_string(), key.to_string()));
+        }
+    }
+
+    /// `(original key, name)` pairs.
+    pub fn forward(&self) -> &[(String, String)]
+    {
+        &self.forward
+    }
+
+    /// `(name, original key)` pairs.
+    pub fn reverse(&self) -> &[(String, String)]
+    {
+        &self.reverse
+    }
+}
+
+/// What a key conversion found besides the converted value.
+#[derive(Default)]
+pub struct KeyReport
+{
+    /// The collisions the policy settled.
+    pub collisions: Vec<Collision>,
+
+    /// The renames that were made, if they are being recorded.
+    pub mapping: Option<KeyMapping>,
+}
+
+impl KeyReport
+{
+    pub fn record(&mut self, key: &str, name: &str)
+    {
+        if let Some(mapping) = &mut self.mapping
+        {
+            mapping.record(key, name);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Compares identifiers by their words rather than by their spelling, so that the case they are
+//! written in doesn't matter.
+
+use std::collections::HashMap;
+
+use convert_case::{Converter, Pattern};
+
+/// The words of `identifier` in lowercase, split the way converting it would split them.
+pub fn normalized_words(identifier: &str) -> Vec<String>
+{
+    let split = Converter::new().set_pattern(Pattern::Lowercase).set_delim("\0").convert(identifier);
+    split.split('\0').filter(|word| !word.is_empty()).map(String::from).collect()
+}
+
+/// How well the query word `query` stands for the candidate word `word`, from 0 to 1: 1 if they
+/// are the same, less if `query` starts `word` or is an abbreviation of it, as `usr` is of `user`,
+/// and 0 otherwise.  Longer abbreviations score higher.
+fn word_similarity(query: &str, word: &str) -> f64
+{
+    let coverage = query.chars().count() as f64 / word.chars().count() as f64;
+    if query == word
+    {
+        1.0
+    }
+    else if word.starts_with(query)
+    {
+        0.5 + 0.5 * coverage
+    }
+    else if query.chars().next() == word.chars().next() && is_subsequence(query, word)
+    {
+        0.25 + 0.5 * coverage
+    }
+    else
+    {
+        0.0
+    }
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool
+{
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+/// Scores how well `query` matches `candidate`, from 0 for no words in common to 1 for the same
+/// words in the same order.
+///
+/// Each query word is matched to the best remaining candidate word after the previous match, or,
+/// at half the score, to the best one before it, so words in a different order still count for
+/// something.  The total is averaged over the words of both, so extra words on either side lower
+/// the score.
+pub fn score(query: &[String], candidate: &[String]) -> f64
+{
+    if query.is_empty() || candidate.is_empty()
+    {
+        return 0.0;
+    }
+
+    let mut used = vec![false; candidate.len()];
+    let mut next = 0;
+    let mut total = 0.0;
+    for word in query
+    {
+        let best = |range: std::ops::Range<usize>, used: &[bool]| {
+            range.filter(|&i| !used[i])
+                .map(|i| (i, word_similarity(word, &candidate[i])))
+                .filter(|(_, similarity)| *similarity > 0.0)
+                .fold(None, |best: Option<(usize, f64)>, (i, similarity)| match best
+                {
+                    Some((_, best_similarity)) if best_similarity >= similarity => best,
+                    _ => Some((i, similarity)),
+                })
+        };
+
+        if let Some((i, similarity)) = best(next..candidate.len(), &used)
+        {
+            used[i] = true;
+            next = i + 1;
+            total += similarity;
+        }
+        else if let Some((i, similarity)) = best(0..next, &used)
+        {
+            used[i] = true;
+            total += similarity / 2.0;
+        }
+    }
+
+    total / ((query.len() + candidate.len()) as f64 / 2.0)
+}
+
+/// A candidate found by [`match_identifiers`].
+pub struct Match
+{
+    /// The position of the candidate in the list that was searched.
+    pub index: usize,
+    pub score: f64,
+}
+
+/// Scores every one of `candidates` against `query` by their words, returning those that share
+/// any, best first and in their original order when tied.  At most `limit` are returned, if it is
+/// given, and none that score under `min_score`.
+pub fn match_identifiers(query: &str, candidates: &[String], limit: Option<usize>, min_score: f64) -> Vec<Match>
+{
+    let query = normalized_words(query);
+    let mut matches: Vec<Match> = candidates.iter()
+        .enumerate()
+        .map(|(index, candidate)| Match { index, score: score(&query, &normalized_words(candidate)) })
+        .filter(|found| found.score > 0.0 && found.score >= min_score)
+        .collect();
+
+    // Sorting is stable, so tied candidates stay in their original order
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    if let Some(limit) = limit
+    {
+        matches.truncate(limit);
+    }
+    matches
+}
+
+/// Identifiers found by [`group_by_signature`] to have the same words.
+pub struct SignatureGroup
+{
+    /// The words the identifiers share, in lowercase.
+    pub words: Vec<String>,
+    /// The distinct identifiers with those words, in the order they first appear.
+    pub identifiers: Vec<String>,
+}
+
+/// Groups `identifiers` by their [`normalized_words`], so that `userId`, `user_id`, and `USER_ID`
+/// end up together.  Groups are returned in the order their first identifier appears, including
+/// those of a single identifier, and identifiers without any words are left out.
+pub fn group_by_signature(identifiers: &[String]) -> Vec<SignatureGroup>
+{
+    let mut groups: Vec<SignatureGroup> = Vec::new();
+    let mut positions: HashMap<Vec<String>, usize> = HashMap::new();
+    for identifier in identifiers
+    {
+        let words = normalized_words(identifier);
+        if words.is_empty()
+        {
+            continue;
+        }
+
+        match positions.get(&words)
+        {
+            Some(&position) =>
+            {
+                let group = &mut groups[position];
+                if !group.identifiers.contains(identifier)
+                {
+                    group.identifiers.push(identifier.clone());
+                }
+            }
+            None =>
+            {
+                positions.insert(words.clone(), groups.len());
+                groups.push(SignatureGroup { words, identifiers: vec![identifier.clone()] });
+            }
+        }
+    }
+    groups
+}
+
+/// What happened to a word between the identifiers [`diff_identifiers`] compares.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WordChange
+{
+    Same,
+    Added,
+    Removed,
+    Changed,
+}
+
+impl WordChange
+{
+    pub fn name(self) -> &'static str
+    {
+        match self
+        {
+            WordChange::Same => "same",
+            WordChange::Added => "added",
+            WordChange::Removed => "removed",
+            WordChange::Changed => "changed",
+        }
+    }
+}
+
+/// One step of a [`diff_identifiers`] diff, with the word as each identifier spells it.
+pub struct WordDiff
+{
+    pub change: WordChange,
+    /// The word in the first identifier, unless it was added.
+    pub from: Option<String>,
+    /// The word in the second identifier, unless it was removed.
+    pub to: Option<String>,
+}
+
+fn spelled_words(identifier: &str) -> Vec<String>
+{
+    let split = Converter::new().set_delim("\0").convert(identifier);
+    split.split('\0').filter(|word| !word.is_empty()).map(String::from).collect()
+}
+
+/// Diffs the words of `a` and `b`, ignoring case, so that `getUserName` and `get_user_full_name`
+/// differ only by an added `full`.
+///
+/// The words both have are the longest sequence common to them.  Between two of those, words of
+/// `a` and `b` are paired up in order as changed, and any left over on one side are removed or
+/// added.
+pub fn diff_identifiers(a: &str, b: &str) -> Vec<WordDiff>
+{
+    let (a, b) = (spelled_words(a), spelled_words(b));
+    let (lower_a, lower_b): (Vec<String>, Vec<String>) = (
+        a.iter().map(|word| word.to_lowercase()).collect(),
+        b.iter().map(|word| word.to_lowercase()).collect(),
+    );
+
+    // common[i][j] is the length of the longest common sequence of a[i..] and b[j..]
+    let mut common = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev()
+    {
+        for j in (0..b.len()).rev()
+        {
+            common[i][j] = match lower_a[i] == lower_b[j]
+            {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let flush = |diff: &mut Vec<WordDiff>, removed: &mut Vec<String>, added: &mut Vec<String>| {
+        let mut added = added.drain(..);
+        for from in removed.drain(..)
+        {
+            diff.push(match added.next()
+            {
+                Some(to) => WordDiff { change: WordChange::Changed, from: Some(from), to: Some(to) },
+                None => WordDiff { change: WordChange::Removed, from: Some(from), to: None },
+            });
+        }
+        diff.extend(added.map(|to| WordDiff { change: WordChange::Added, from: None, to: Some(to) }));
+    };
+
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len()
+    {
+        if i < a.len() && j < b.len() && lower_a[i] == lower_b[j]
+        {
+            flush(&mut diff, &mut removed, &mut added);
+            diff.push(WordDiff { change: WordChange::Same, from: Some(a[i].clone()), to: Some(b[j].clone()) });
+            i += 1;
+            j += 1;
+        }
+        else if j == b.len() || (i < a.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            removed.push(a[i].clone());
+            i += 1;
+        }
+        else
+        {
+            added.push(b[j].clone());
+            j += 1;
+        }
+    }
+    flush(&mut diff, &mut removed, &mut added);
+
+    diff
+}
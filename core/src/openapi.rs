@@ -0,0 +1,151 @@
+use convert_case::Converter;
+use serde_json::{Map, Value};
+
+/// Parses an OpenAPI or Swagger document, renames its schema properties and its query, cookie, and
+/// form parameters, and serializes it again.
+///
+/// Property names are renamed wherever a schema lists them: `properties`, `required`,
+/// `discriminator.propertyName`, and the `encoding` of a media type.  Everything that names or
+/// addresses something else is kept, including `$ref` targets, schema and component names, path
+/// templates and the path parameters they refer to, header names, enum values, defaults, examples,
+/// and `x-` extensions.
+pub fn convert_openapi(json: &str, conv: &Converter, pretty: bool) -> Result<String, String>
+{
+    let mut document: Value = serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    walk(&mut document, conv);
+
+    if pretty
+    {
+        serde_json::to_string_pretty(&document).map_err(|e| e.to_string())
+    }
+    else
+    {
+        serde_json::to_string(&document).map_err(|e| e.to_string())
+    }
+}
+
+/// Whether the value of `key` is data or a vendor extension, never parts of the document itself.
+fn is_opaque(key: &str) -> bool
+{
+    matches!(key, "example" | "examples" | "default" | "enum" | "const") || key.starts_with("x-")
+}
+
+/// Looks for schemas and parameters in any part of the document that isn't a schema.
+fn walk(value: &mut Value, conv: &Converter)
+{
+    match value
+    {
+        Value::Array(array) => array.iter_mut().for_each(|element| walk(element, conv)),
+        Value::Object(object) =>
+        {
+            for (key, value) in object.iter_mut()
+            {
+                match key.as_str()
+                {
+                    _ if is_opaque(key) => {}
+                    "schema" => schema(value, conv),
+                    // Named schemas under `components` and Swagger's top level
+                    "schemas" | "definitions" => map_values(value, |schema_value| schema(schema_value, conv)),
+                    // Arrays in path items and operations, maps under `components`
+                    "parameters" => match value
+                    {
+                        Value::Array(parameters) => parameters.iter_mut().for_each(|parameter_value| parameter(parameter_value, conv)),
+                        _ => map_values(value, |parameter_value| parameter(parameter_value, conv)),
+                    },
+                    "encoding" => rename_keys(value, conv),
+                    _ => walk(value, conv),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn map_values(value: &mut Value, mut f: impl FnMut(&mut Value))
+{
+    if let Value::Object(object) = value
+    {
+        object.values_mut().for_each(&mut f);
+    }
+}
+
+/// Renames the keys of an object whose keys are property names, keeping their order.
+fn rename_keys(value: &mut Value, conv: &Converter)
+{
+    if let Value::Object(object) = value
+    {
+        *object = std::mem::take(object)
+            .into_iter()
+            .map(|(key, value)| (conv.convert(key), value))
+            .collect::<Map<_, _>>();
+    }
+}
+
+fn parameter(value: &mut Value, conv: &Converter)
+{
+    if let Value::Object(object) = value
+    {
+        // Path parameters have to keep matching their templates, and header names aren't ours to
+        // choose
+        let renamed = matches!(object.get("in").and_then(Value::as_str), Some("query" | "cookie" | "formData"));
+        if let (true, Some(Value::String(name))) = (renamed, object.get_mut("name"))
+        {
+            *name = conv.convert(&*name);
+        }
+    }
+    walk(value, conv);
+}
+
+/// Renames the properties a schema declares and walks its subschemas.
+fn schema(value: &mut Value, conv: &Converter)
+{
+    let object = match value
+    {
+        Value::Object(object) => object,
+        _ => return,
+    };
+
+    for (key, value) in object.iter_mut()
+    {
+        match key.as_str()
+        {
+            "properties" =>
+            {
+                rename_keys(value, conv);
+                map_values(value, |property| schema(property, conv));
+            }
+            "required" =>
+            {
+                if let Value::Array(required) = value
+                {
+                    for name in required.iter_mut()
+                    {
+                        if let Value::String(name) = name
+                        {
+                            *name = conv.convert(&*name);
+                        }
+                    }
+                }
+            }
+            "discriminator" =>
+            {
+                if let Some(Value::String(name)) = value.get_mut("propertyName")
+                {
+                    *name = conv.convert(&*name);
+                }
+            }
+            // `items` may also be a list of schemas, in the tuple form of older JSON Schema drafts
+            "items" | "allOf" | "anyOf" | "oneOf" | "prefixItems" if value.is_array() =>
+            {
+                if let Value::Array(schemas) = value
+                {
+                    schemas.iter_mut().for_each(|subschema| schema(subschema, conv));
+                }
+            }
+            "items" | "not" | "additionalProperties" | "additionalItems" | "contains" | "propertyNames"
+                | "if" | "then" | "else" | "unevaluatedProperties" | "unevaluatedItems" => schema(value, conv),
+            "patternProperties" | "$defs" | "definitions" => map_values(value, |subschema| schema(subschema, conv)),
+            _ => {}
+        }
+    }
+}
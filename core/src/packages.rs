@@ -0,0 +1,146 @@
+use convert_case::{Boundary, Case, Converter};
+
+/// Names cargo refuses for new packages: Rust keywords and the crates that ship with Rust.
+const RESERVED_CRATES: &[&str] = &[
+    "abstract", "alloc", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "core", "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+    "proc_macro", "pub", "ref", "return", "self", "static", "std", "struct", "super", "test",
+    "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+    "while", "yield",
+];
+
+/// A package registry with naming rules of its own.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Registry
+{
+    /// Lowercase kebab case, at most 214 characters including an optional `@scope/`.
+    Npm,
+    /// Snake case starting with a letter, at most 64 characters, and not a keyword.
+    Crates,
+    /// Lowercase kebab case, the normalized form of PEP 503.
+    Pypi,
+}
+
+impl Registry
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "npm" => Some(Registry::Npm),
+            "crates" => Some(Registry::Crates),
+            "pypi" => Some(Registry::Pypi),
+            _ => None,
+        }
+    }
+
+    fn max_length(self) -> Option<usize>
+    {
+        match self
+        {
+            Registry::Npm => Some(214),
+            Registry::Crates => Some(64),
+            Registry::Pypi => None,
+        }
+    }
+}
+
+/// Splits `input` into lowercase words of ASCII letters and digits.  Punctuation and whitespace
+/// separate words, other characters are dropped, and digits never start a word of their own, so
+/// `Web3 Utils` gives `web3` and `utils`.
+fn words(input: &str) -> Vec<String>
+{
+    let cleaned: String = input
+        .chars()
+        .filter(char::is_ascii)
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    Converter::new()
+        .set_boundaries(&[Boundary::Space, Boundary::LowerUpper, Boundary::Acronym, Boundary::DigitUpper])
+        .to_case(Case::Lower)
+        .convert(cleaned)
+        .split(' ')
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Joins as many whole words as fit in `max_length`, cutting the first word only if it doesn't
+/// fit on its own.
+fn join_within(words: &[String], delim: &str, max_length: Option<usize>) -> String
+{
+    let max_length = max_length.unwrap_or(usize::MAX);
+    let mut name = String::new();
+    for word in words
+    {
+        let separator = if name.is_empty() { 0 } else { delim.len() };
+        if name.len() + separator + word.len() > max_length
+        {
+            break;
+        }
+        if separator > 0
+        {
+            name.push_str(delim);
+        }
+        name.push_str(word);
+    }
+
+    if name.is_empty()
+    {
+        name = words[0][..max_length.min(words[0].len())].to_string();
+    }
+    name
+}
+
+/// Derives a name `registry` accepts from a human-entered title such as `My Cool Project!`.
+///
+/// For npm, a title of the form `@scope/name` keeps its scope, converted the same way as the name.
+/// Fails if no letters or digits are left, or if the name breaks a rule that can't be fixed by
+/// dropping characters, such as a crate name starting with a digit.
+pub fn to_package_name(input: &str, registry: Registry) -> Result<String, String>
+{
+    let (scope, input) = match input.trim().strip_prefix('@').and_then(|scoped| scoped.split_once('/'))
+    {
+        Some((scope, name)) if registry == Registry::Npm =>
+        {
+            let scope = words(scope);
+            if scope.is_empty()
+            {
+                return Err(String::from("the scope has no ASCII letters or digits"));
+            }
+            (Some(join_within(&scope, "-", None)), name)
+        }
+        _ => (None, input),
+    };
+
+    let words = words(input);
+    if words.is_empty()
+    {
+        return Err(String::from("it has no ASCII letters or digits"));
+    }
+
+    let delim = if registry == Registry::Crates { "_" } else { "-" };
+    let prefix = scope.map_or(String::new(), |scope| format!("@{}/", scope));
+    let max_length = registry.max_length().map(|max_length| max_length.saturating_sub(prefix.len()));
+    if max_length == Some(0)
+    {
+        return Err(String::from("the scope leaves no room for a name"));
+    }
+    let name = join_within(&words, delim, max_length);
+
+    if registry == Registry::Crates
+    {
+        if name.starts_with(|c: char| c.is_ascii_digit())
+        {
+            return Err(format!("crate names can't start with a digit, as \"{}\" does", name));
+        }
+        if RESERVED_CRATES.contains(&name.as_str())
+        {
+            return Err(format!("\"{}\" is reserved and can't be used as a crate name", name));
+        }
+    }
+
+    Ok(prefix + &name)
+}
@@ -0,0 +1,87 @@
+/// A dot-separated path pattern such as `metadata.*` or `**.rawHeaders`.
+///
+/// `*` matches exactly one key and may also be used inside a segment (`raw*`), while a `**`
+/// segment matches any number of keys, including none.
+pub struct PathGlob
+{
+    segments: Vec<String>,
+}
+
+impl PathGlob
+{
+    pub fn new(pattern: &str) -> Self
+    {
+        PathGlob { segments: pattern.split('.').map(String::from).collect() }
+    }
+
+    pub fn matches(&self, path: &[String]) -> bool
+    {
+        matches_segments(&self.segments, path)
+    }
+}
+
+fn matches_segments(segments: &[String], path: &[String]) -> bool
+{
+    match segments.split_first()
+    {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" =>
+        {
+            (0..=path.len()).any(|skip| matches_segments(rest, &path[skip..]))
+        }
+        Some((first, rest)) => match path.split_first()
+        {
+            Some((key, path)) => matches_wildcard(first, key) && matches_segments(rest, path),
+            None => false,
+        },
+    }
+}
+
+/// Matches a single key against a segment in which `*` stands for any run of characters.
+fn matches_wildcard(segment: &str, key: &str) -> bool
+{
+    match segment.split_once('*')
+    {
+        None => segment == key,
+        Some((prefix, rest)) =>
+        {
+            key.starts_with(prefix)
+                && (0..=key.len() - prefix.len())
+                    .filter(|&i| key.is_char_boundary(prefix.len() + i))
+                    .any(|i| matches_wildcard(rest, &key[prefix.len() + i..]))
+        }
+    }
+}
+
+/// Include and exclude patterns deciding which keys are renamed during deep key conversion.
+///
+/// Paths are made of the original, unconverted keys from the root; arrays don't add a segment.
+/// A key matching an exclude pattern is kept verbatim along with everything beneath it.  When
+/// include patterns are given, only keys matching one of them are renamed, though their
+/// descendants are still visited.  String values whose path matches a value pattern, such as the
+/// field name in `sortBy`, are converted as if they were keys.
+#[derive(Default)]
+pub struct PathFilter
+{
+    pub include: Vec<PathGlob>,
+    pub exclude: Vec<PathGlob>,
+    pub values: Vec<PathGlob>,
+}
+
+impl PathFilter
+{
+    pub fn excludes(&self, path: &[String]) -> bool
+    {
+        self.exclude.iter().any(|glob| glob.matches(path))
+    }
+
+    pub fn includes(&self, path: &[String]) -> bool
+    {
+        self.include.is_empty() || self.include.iter().any(|glob| glob.matches(path))
+    }
+
+    pub fn converts_value(&self, path: &[String]) -> bool
+    {
+        self.values.iter().any(|glob| glob.matches(path))
+    }
+}
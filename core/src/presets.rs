@@ -0,0 +1,71 @@
+use convert_case::{Boundary, Pattern};
+
+/// Where words start in identifiers written in any of the conventions below.  Digits belong to
+/// the word before them, so `utf8Decoder` and `MP3Player` split into `utf8 decoder` and
+/// `mp3 player` rather than leaving the digits as words of their own.
+const WORDS: &[Boundary] = &[
+    Boundary::Underscore,
+    Boundary::Hyphen,
+    Boundary::Space,
+    Boundary::LowerUpper,
+    Boundary::Acronym,
+    Boundary::DigitUpper,
+];
+
+/// How a kind of identifier is written under one language's style guide.
+pub struct Preset
+{
+    pub boundaries: &'static [Boundary],
+    pub pattern: Pattern,
+    pub delim: &'static str,
+}
+
+const PASCAL: Preset = Preset { boundaries: WORDS, pattern: Pattern::Capital, delim: "" };
+const CAMEL: Preset = Preset { boundaries: WORDS, pattern: Pattern::Camel, delim: "" };
+const SNAKE: Preset = Preset { boundaries: WORDS, pattern: Pattern::Lowercase, delim: "_" };
+const SCREAMING_SNAKE: Preset = Preset { boundaries: WORDS, pattern: Pattern::Uppercase, delim: "_" };
+const FLAT: Preset = Preset { boundaries: WORDS, pattern: Pattern::Lowercase, delim: "" };
+
+/// Every preset by name.  Acronyms are treated as ordinary words everywhere, as all of these style
+/// guides ask, so `HTTPServer` becomes `HttpServer` rather than staying as it is.
+const PRESETS: &[(&str, Preset)] = &[
+    // The Rust API guidelines (RFC 430)
+    ("rust.struct", PASCAL),
+    ("rust.enum", PASCAL),
+    ("rust.variant", PASCAL),
+    ("rust.trait", PASCAL),
+    ("rust.type", PASCAL),
+    ("rust.function", SNAKE),
+    ("rust.method", SNAKE),
+    ("rust.variable", SNAKE),
+    ("rust.field", SNAKE),
+    ("rust.module", SNAKE),
+    ("rust.constant", SCREAMING_SNAKE),
+    ("rust.static", SCREAMING_SNAKE),
+    // The Google Java style guide
+    ("java.class", PASCAL),
+    ("java.interface", PASCAL),
+    ("java.method", CAMEL),
+    ("java.variable", CAMEL),
+    ("java.field", CAMEL),
+    ("java.constant", SCREAMING_SNAKE),
+    ("java.package", FLAT),
+    // PEP 8
+    ("python.class", PASCAL),
+    ("python.function", SNAKE),
+    ("python.method", SNAKE),
+    ("python.variable", SNAKE),
+    ("python.constant", SCREAMING_SNAKE),
+    ("python.module", SNAKE),
+    ("python.package", FLAT),
+    // The Google JavaScript and TypeScript style guides
+    ("javascript.class", PASCAL),
+    ("javascript.function", CAMEL),
+    ("javascript.variable", CAMEL),
+    ("javascript.constant", SCREAMING_SNAKE),
+];
+
+pub fn preset(name: &str) -> Option<&'static Preset>
+{
+    PRESETS.iter().find(|(preset_name, _)| *preset_name == name).map(|(_, preset)| preset)
+}
@@ -0,0 +1,55 @@
+/// Derives the `json_name` protoc gives a field, exactly as `ToJsonName` in protoc does.
+///
+/// Every underscore is dropped and the character after it is uppercased, if it is an ASCII
+/// letter.  Nothing else changes, so existing capitals are kept (`FOO_bar` becomes `FOOBar`), a
+/// digit after an underscore stays as it is and doesn't capitalize the letter after it
+/// (`field_1_name` becomes `field1Name`, `foo_1bar` becomes `foo1bar`), and trailing underscores
+/// vanish.  This differs from camel case conversion, which would split and lowercase words.
+pub fn proto_json_name(field: &str) -> String
+{
+    let mut json_name = String::with_capacity(field.len());
+    let mut capitalize_next = false;
+    for c in field.chars()
+    {
+        if c == '_'
+        {
+            capitalize_next = true;
+        }
+        else if capitalize_next
+        {
+            json_name.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        }
+        else
+        {
+            json_name.push(c);
+        }
+    }
+    json_name
+}
+
+/// Recovers a snake_case field name from a protobuf JSON name, the way protobuf's
+/// `FieldMaskUtil::CamelCaseToSnakeCase` does.
+///
+/// Every ASCII capital becomes an underscore followed by its lowercase letter, and nothing else
+/// changes.  JSON names can't contain underscores, so `None` is returned for any that does.  This
+/// inverts [`proto_json_name`] for every field name made of lowercase words that each start with a
+/// letter, which is what the protobuf style guide asks for.
+pub fn proto_field_name(json_name: &str) -> Option<String>
+{
+    let mut field = String::with_capacity(json_name.len() + 4);
+    for c in json_name.chars()
+    {
+        match c
+        {
+            '_' => return None,
+            'A'..='Z' =>
+            {
+                field.push('_');
+                field.push(c.to_ascii_lowercase());
+            }
+            _ => field.push(c),
+        }
+    }
+    Some(field)
+}
@@ -0,0 +1,73 @@
+use convert_case::Converter;
+
+/// Converts the parameter names of a query string, keeping the values and a leading `?` as they
+/// are.
+///
+/// Each name is percent-decoded (treating `+` as a space) before conversion and encoded again
+/// afterwards.  Names that can't be decoded as UTF-8 are left untouched.
+pub fn convert_query_keys(query: &str, conv: &Converter) -> String
+{
+    let (prefix, pairs) = match query.strip_prefix('?')
+    {
+        Some(pairs) => ("?", pairs),
+        None => ("", query),
+    };
+
+    let converted: Vec<String> = pairs.split('&').map(|pair| {
+        let (name, value) = match pair.find('=')
+        {
+            Some(i) => pair.split_at(i),
+            None => (pair, ""),
+        };
+
+        match decode(name)
+        {
+            Some(decoded) if !decoded.is_empty() => format!("{}{}", encode(&conv.convert(decoded)), value),
+            _ => pair.to_string(),
+        }
+    }).collect();
+
+    format!("{}{}", prefix, converted.join("&"))
+}
+
+fn decode(s: &str) -> Option<String>
+{
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len()
+    {
+        match bytes[i]
+        {
+            b'+' => decoded.push(b' '),
+            b'%' =>
+            {
+                let hex = s.get(i + 1..i + 3)?;
+                decoded.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+fn encode(s: &str) -> String
+{
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes()
+    {
+        match byte
+        {
+            b'&' | b'=' | b'#' | b'+' | b'%' => encoded.push_str(&format!("%{:02X}", byte)),
+            0x21..=0x7E => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,112 @@
+//! The `Random` and `PseudoRandom` patterns driven by a seed, so that the same seed cases the same
+//! words the same way on every run and platform.
+
+use convert_case::Pattern;
+
+/// SplitMix64, which is small, fast, and fully specified, so its output never changes with a
+/// dependency's version.
+struct SplitMix64(u64);
+
+impl SplitMix64
+{
+    fn next(&mut self) -> u64
+    {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn coin(&mut self) -> bool
+    {
+        self.next() >> 63 == 1
+    }
+}
+
+fn cased(letter: char, upper: bool) -> String
+{
+    match upper
+    {
+        true => letter.to_uppercase().to_string(),
+        false => letter.to_lowercase().to_string(),
+    }
+}
+
+/// Cases `words` with `pattern` as `Pattern::mutate` does, but with randomness drawn from `seed`,
+/// or returns `None` if `pattern` isn't [`Pattern::Random`] or [`Pattern::PseudoRandom`].
+///
+/// `Random` uppercases each letter on a coin flip.  `PseudoRandom` flips a coin for every pair of
+/// letters and cases one of them up and the other down, so no more than two letters in a row are
+/// ever cased the same.
+pub fn mutate(pattern: Pattern, words: &[&str], seed: u64) -> Option<Vec<String>>
+{
+    let mut rng = SplitMix64(seed);
+    match pattern
+    {
+        Pattern::Random => Some(words.iter().map(|word| word.chars().map(|letter| cased(letter, rng.coin())).collect()).collect()),
+        Pattern::PseudoRandom =>
+        {
+            // The case the next letter has to be to finish a pair, if a pair was started
+            let mut next: Option<bool> = None;
+            let words = words.iter()
+                .map(|word| {
+                    word.chars()
+                        .map(|letter| {
+                            let upper = match next.take()
+                            {
+                                Some(upper) => upper,
+                                None =>
+                                {
+                                    let upper = rng.coin();
+                                    next = Some(!upper);
+                                    upper
+                                }
+                            };
+                            cased(letter, upper)
+                        })
+                        .collect()
+                })
+                .collect();
+            Some(words)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn seeds_are_reproducible()
+    {
+        let words = ["sponge", "case", "fixture"];
+        for pattern in [Pattern::Random, Pattern::PseudoRandom]
+        {
+            let cased = mutate(pattern, &words, 42).unwrap();
+            assert_eq!(mutate(pattern, &words, 42).unwrap(), cased);
+            assert_eq!(cased.join(" ").to_lowercase(), "sponge case fixture");
+        }
+        assert_ne!(mutate(Pattern::PseudoRandom, &words, u64::MAX), mutate(Pattern::PseudoRandom, &words, 7));
+    }
+
+    #[test]
+    fn pseudo_random_never_cases_three_letters_in_a_row_the_same()
+    {
+        for seed in 0..100
+        {
+            let cased = mutate(Pattern::PseudoRandom, &["abcdefghij", "klmnop"], seed).unwrap().concat();
+            let upper: Vec<bool> = cased.chars().map(char::is_uppercase).collect();
+            assert!(upper.windows(3).all(|run| run[0] != run[1] || run[1] != run[2]), "{}", cased);
+        }
+    }
+
+    #[test]
+    fn other_patterns_are_left_to_convert_case()
+    {
+        assert!(mutate(Pattern::Lowercase, &["a"], 1).is_none());
+        assert_eq!(mutate(Pattern::Random, &[], 1).unwrap(), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,136 @@
+use convert_case::Case;
+
+/// The cases of serde's `#[serde(rename_all = "...")]`, applied exactly the way `serde_derive`
+/// applies them.
+#[derive(Clone, Copy)]
+pub enum RenameRule
+{
+    /// `"lowercase"`
+    Lower,
+    /// `"UPPERCASE"`
+    Upper,
+    /// `"PascalCase"`
+    Pascal,
+    /// `"camelCase"`
+    Camel,
+    /// `"snake_case"`
+    Snake,
+    /// `"SCREAMING_SNAKE_CASE"`
+    ScreamingSnake,
+    /// `"kebab-case"`
+    Kebab,
+    /// `"SCREAMING-KEBAB-CASE"`
+    ScreamingKebab,
+}
+
+impl RenameRule
+{
+    /// The rule whose output has the shape of `case`, if serde has one.  serde's `"lowercase"`
+    /// and `"UPPERCASE"` never add spaces, so they correspond to the flat cases.
+    pub fn from_case(case: Case) -> Option<Self>
+    {
+        match case
+        {
+            Case::Flat => Some(RenameRule::Lower),
+            Case::UpperFlat => Some(RenameRule::Upper),
+            Case::Pascal | Case::UpperCamel => Some(RenameRule::Pascal),
+            Case::Camel => Some(RenameRule::Camel),
+            Case::Snake => Some(RenameRule::Snake),
+            Case::UpperSnake | Case::ScreamingSnake => Some(RenameRule::ScreamingSnake),
+            Case::Kebab => Some(RenameRule::Kebab),
+            Case::Cobol | Case::UpperKebab => Some(RenameRule::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// Renames a Rust identifier the way serde would.
+    ///
+    /// serde renames struct fields on the assumption that they are snake_case and enum variants on
+    /// the assumption that they are PascalCase, and the two rules differ, so identifiers starting
+    /// with an uppercase letter are renamed as variants and all others as fields.  A raw
+    /// identifier's `r#` is dropped first, as serde does.
+    pub fn apply(self, ident: &str) -> String
+    {
+        let ident = ident.strip_prefix("r#").unwrap_or(ident);
+        if ident.starts_with(char::is_uppercase)
+        {
+            self.apply_to_variant(ident)
+        }
+        else
+        {
+            self.apply_to_field(ident)
+        }
+    }
+
+    fn apply_to_variant(self, variant: &str) -> String
+    {
+        match self
+        {
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => lowercase_first(variant),
+            RenameRule::Snake =>
+            {
+                let mut snake = String::new();
+                for (i, c) in variant.char_indices()
+                {
+                    if i > 0 && c.is_uppercase()
+                    {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake.apply_to_variant(variant).replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake.apply_to_variant(variant).replace('_', "-"),
+        }
+    }
+
+    fn apply_to_field(self, field: &str) -> String
+    {
+        match self
+        {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal =>
+            {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for c in field.chars()
+                {
+                    if c == '_'
+                    {
+                        capitalize = true;
+                    }
+                    else if capitalize
+                    {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    }
+                    else
+                    {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => lowercase_first(&RenameRule::Pascal.apply_to_field(field)),
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+/// Lowercases the first character of `s`, which serde does with `to_ascii_lowercase`.
+fn lowercase_first(s: &str) -> String
+{
+    let mut chars = s.chars();
+    match chars.next()
+    {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,36 @@
+//! Converts a whole set of identifiers at once, so that no two of them end up with the same name.
+
+use convert_case::Converter;
+
+use crate::collisions::{self, CollisionPolicy};
+
+/// Returns the new name of every distinct one of `identifiers` under `conv`, as `(old, new)`
+/// pairs sorted by the old name.
+///
+/// The result depends only on the set of identifiers, not on their order or on duplicates, so the
+/// same set always gets the same map.  Identifiers that convert to the same name are settled like
+/// [`CollisionPolicy::Suffix`] settles keys, after sorting them by code point: an identifier that
+/// is already spelled like the name keeps it, or else the one that sorts first does, and the
+/// others, in sorted order, get the first of `<delim>2`, `<delim>3`, ... that no other identifier
+/// is converted to.
+pub fn build_rename_map(identifiers: &[String], conv: &Converter) -> Vec<(String, String)>
+{
+    let mut sorted = identifiers.to_vec();
+    sorted.sort();
+    sorted.dedup();
+
+    // Stable sorting keeps the rest of each group sorted, behind the identifier that keeps its name
+    let mut names: Vec<String> = sorted.iter().map(|identifier| conv.convert(identifier)).collect();
+    let mut order: Vec<usize> = (0..sorted.len()).collect();
+    order.sort_by_key(|&i| sorted[i] != names[i]);
+    let keys: Vec<String> = order.iter().map(|&i| sorted[i].clone()).collect();
+    let converted = order.iter().map(|&i| std::mem::take(&mut names[i])).collect();
+
+    // Suffixing never fails or drops a name, so every identifier gets one
+    let (resolved, _) = collisions::resolve(&keys, converted, CollisionPolicy::Suffix, &conv.delim, &[])
+        .unwrap_or_else(|_| unreachable!());
+
+    let mut map: Vec<(String, String)> = keys.into_iter().zip(resolved).map(|(key, name)| (key, name.unwrap())).collect();
+    map.sort();
+    map
+}
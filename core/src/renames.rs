@@ -0,0 +1,272 @@
+use std::collections::{HashMap, HashSet};
+
+use convert_case::Converter;
+
+/// One step of a [`RenamePlan`].
+pub struct Rename
+{
+    pub from: String,
+    pub to: String,
+}
+
+/// Files that would all end up at `target`, ignoring case, and so are left where they are.  One of
+/// them may be a file that already has the name and keeps it.
+pub struct RenameCollision
+{
+    pub target: String,
+    pub sources: Vec<String>,
+}
+
+pub struct RenamePlan
+{
+    pub steps: Vec<Rename>,
+    pub collisions: Vec<RenameCollision>,
+}
+
+/// Splits `path` into its directory, including the trailing separator, and its file name.
+fn split_path(path: &str) -> (&str, &str)
+{
+    match path.rfind(['/', '\\'])
+    {
+        Some(separator) => path.split_at(separator + 1),
+        None => ("", path),
+    }
+}
+
+/// Converts the stem of a file name, keeping its extension and any leading dots.
+fn convert_name(name: &str, conv: &Converter) -> String
+{
+    let stem_start = name.len() - name.trim_start_matches('.').len();
+    let (prefix, rest) = name.split_at(stem_start);
+    let (stem, extension) = match rest.rfind('.')
+    {
+        Some(dot) => rest.split_at(dot),
+        None => (rest, ""),
+    };
+
+    let stem = conv.convert(stem);
+    if stem.is_empty()
+    {
+        return name.to_string();
+    }
+    format!("{}{}{}", prefix, stem, extension)
+}
+
+/// The name a case-insensitive file system compares paths by.
+fn fold(path: &str) -> String
+{
+    path.to_lowercase()
+}
+
+/// State of a pending rename while the plan is ordered.
+#[derive(Clone, Copy, PartialEq)]
+enum Progress
+{
+    Pending,
+    Visiting,
+    Parked,
+    Done,
+}
+
+/// Plans how to rename `paths` so that their file names are in the converter's case.
+///
+/// Directories are kept as they are and only the stem of each file name is converted.  Paths are
+/// compared case-insensitively, as macOS and Windows compare them, so files whose new names would
+/// differ only in case from each other, or from a file that keeps its name, are all left alone
+/// and reported as a collision.  The steps are ordered so that no file is ever renamed onto one
+/// that hasn't moved out of the way yet.  Cycles of renames, and renames that only change case,
+/// which some file systems ignore, go through a temporary name.
+pub fn plan_renames(paths: &[String], conv: &Converter) -> RenamePlan
+{
+    let mut seen = HashSet::new();
+    let paths: Vec<&String> = paths.iter().filter(|path| seen.insert(path.as_str())).collect();
+
+    let targets: Vec<String> = paths
+        .iter()
+        .map(|path|
+        {
+            let (directory, name) = split_path(path);
+            format!("{}{}", directory, convert_name(name, conv))
+        })
+        .collect();
+
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, target) in targets.iter().enumerate()
+    {
+        groups.entry(fold(target)).or_default().push(i);
+    }
+
+    // Files that stay where they are, including every file of a collision, by folded path
+    let mut staying: HashMap<String, usize> = HashMap::new();
+    let mut stays = vec![false; paths.len()];
+    let mut collisions: Vec<(String, Vec<usize>)> = Vec::new();
+    for (i, target) in targets.iter().enumerate()
+    {
+        let group = &groups[&fold(target)];
+        if group.len() > 1 && group[0] == i
+        {
+            collisions.push((target.clone(), group.clone()));
+        }
+        if group.len() > 1 || *target == *paths[i]
+        {
+            staying.insert(fold(paths[i]), i);
+            stays[i] = true;
+        }
+    }
+
+    // A file renamed onto one that stays collides with it, and then stays too
+    loop
+    {
+        let blocked: Vec<usize> = (0..paths.len()).filter(|i| !stays[*i] && staying.contains_key(&fold(&targets[*i]))).collect();
+        if blocked.is_empty()
+        {
+            break;
+        }
+        for i in blocked
+        {
+            let occupant = staying[&fold(&targets[i])];
+            collisions.push((targets[i].clone(), vec![occupant, i]));
+            staying.insert(fold(paths[i]), i);
+            stays[i] = true;
+        }
+    }
+    let moving: Vec<usize> = (0..paths.len()).filter(|i| !stays[*i]).collect();
+
+    let sources: HashMap<String, usize> = moving.iter().map(|i| (fold(paths[*i]), *i)).collect();
+    let mut taken: HashSet<String> = paths.iter().map(|path| fold(path)).chain(targets.iter().map(|target| fold(target))).collect();
+    let mut temporary = |path: &str|
+    {
+        let mut n = 1;
+        loop
+        {
+            let candidate = if n == 1 { format!("{}.renaming", path) } else { format!("{}.renaming{}", path, n) };
+            if taken.insert(fold(&candidate))
+            {
+                return candidate;
+            }
+            n += 1;
+        }
+    };
+
+    // Each rename waits for at most one other, the one moving out of its target
+    let mut steps = Vec::new();
+    let mut progress: HashMap<usize, Progress> = moving.iter().map(|i| (*i, Progress::Pending)).collect();
+    let mut parked: HashMap<usize, (usize, String)> = HashMap::new();
+    for &start in &moving
+    {
+        let mut stack = vec![start];
+        while let Some(&i) = stack.last()
+        {
+            if progress[&i] != Progress::Pending && progress[&i] != Progress::Visiting
+            {
+                stack.pop();
+                continue;
+            }
+            progress.insert(i, Progress::Visiting);
+
+            let blocker = sources.get(&fold(&targets[i])).copied().filter(|j| *j != i);
+            match blocker.map(|j| (j, progress[&j]))
+            {
+                Some((j, Progress::Pending)) =>
+                {
+                    stack.push(j);
+                    continue;
+                }
+                Some((j, Progress::Visiting)) =>
+                {
+                    // A cycle: move out of the way and finish once the rename this one waits for is done
+                    let parking = temporary(paths[i]);
+                    steps.push(Rename { from: paths[i].clone(), to: parking.clone() });
+                    parked.insert(j, (i, parking));
+                    progress.insert(i, Progress::Parked);
+                }
+                _ =>
+                {
+                    if fold(paths[i]) == fold(&targets[i])
+                    {
+                        let parking = temporary(paths[i]);
+                        steps.push(Rename { from: paths[i].clone(), to: parking.clone() });
+                        steps.push(Rename { from: parking, to: targets[i].clone() });
+                    }
+                    else
+                    {
+                        steps.push(Rename { from: paths[i].clone(), to: targets[i].clone() });
+                    }
+                    progress.insert(i, Progress::Done);
+
+                    if let Some((waiting, parking)) = parked.remove(&i)
+                    {
+                        steps.push(Rename { from: parking, to: targets[waiting].clone() });
+                        progress.insert(waiting, Progress::Done);
+                    }
+                }
+            }
+            stack.pop();
+        }
+    }
+
+    let collisions = collisions
+        .into_iter()
+        .map(|(target, sources)| RenameCollision { target, sources: sources.into_iter().map(|i| paths[i].clone()).collect() })
+        .collect();
+    RenamePlan { steps, collisions }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use convert_case::Case;
+
+    use super::*;
+
+    fn plan(paths: &[&str], case: Case) -> RenamePlan
+    {
+        let paths: Vec<String> = paths.iter().map(|path| path.to_string()).collect();
+        plan_renames(&paths, &Converter::new().to_case(case))
+    }
+
+    fn steps(plan: &RenamePlan) -> Vec<(&str, &str)>
+    {
+        plan.steps.iter().map(|step| (step.from.as_str(), step.to.as_str())).collect()
+    }
+
+    #[test]
+    fn only_stems_are_converted()
+    {
+        let plan = plan(&["src/myComponent.test.tsx", "C:\\Users\\Me\\toDoList.TXT", ".eslintRc", "fooBar"], Case::Snake);
+        assert_eq!(steps(&plan), [
+            ("src/myComponent.test.tsx", "src/my_component.test.tsx"),
+            ("C:\\Users\\Me\\toDoList.TXT", "C:\\Users\\Me\\to_do_list.TXT"),
+            (".eslintRc", ".eslint_rc"),
+            ("fooBar", "foo_bar"),
+        ]);
+        assert!(plan.collisions.is_empty());
+    }
+
+    #[test]
+    fn case_only_renames_go_through_a_temporary_name()
+    {
+        let plan = plan(&["Readme.md"], Case::Lower);
+        assert_eq!(steps(&plan), [("Readme.md", "Readme.md.renaming"), ("Readme.md.renaming", "readme.md")]);
+    }
+
+    #[test]
+    fn a_file_keeping_its_name_collides_with_those_renamed_onto_it()
+    {
+        let plan = plan(&["a_b", "aB"], Case::Camel);
+        assert!(plan.steps.is_empty());
+        assert_eq!(plan.collisions.len(), 1);
+        assert_eq!(plan.collisions[0].target, "aB");
+        assert_eq!(plan.collisions[0].sources, ["a_b", "aB"]);
+    }
+
+    #[test]
+    fn names_that_fold_together_collide()
+    {
+        let plan = plan(&["x/FooBar", "x/foo-bar"], Case::Kebab);
+        assert!(plan.steps.is_empty());
+        assert_eq!(plan.collisions.len(), 1);
+        assert_eq!(plan.collisions[0].target, "x/foo-bar");
+        assert_eq!(plan.collisions[0].sources, ["x/FooBar", "x/foo-bar"]);
+    }
+}
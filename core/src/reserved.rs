@@ -0,0 +1,107 @@
+//! Keeps converted names from colliding with the reserved words of the language they are
+//! generated for.
+
+use crate::sql;
+
+/// A language whose reserved words [`avoid_reserved`] steers clear of.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Language
+{
+    /// JavaScript and TypeScript, whose reserved words are the same.
+    JavaScript,
+    Rust,
+    Python,
+    /// The common SQL dialects, whose reserved words are compared ignoring case.
+    Sql,
+}
+
+impl Language
+{
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name
+        {
+            "javascript" => Some(Language::JavaScript),
+            "rust" => Some(Language::Rust),
+            "python" => Some(Language::Python),
+            "sql" => Some(Language::Sql),
+            _ => None,
+        }
+    }
+}
+
+/// Words that can't be used as identifiers in strict mode JavaScript, or in TypeScript.
+const JAVASCRIPT: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+    "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for", "function",
+    "if", "implements", "import", "in", "instanceof", "interface", "let", "new", "null", "package",
+    "private", "protected", "public", "return", "static", "super", "switch", "this", "throw",
+    "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+/// Rust's strict and reserved keywords, as of the 2021 edition.
+const RUST: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Python's keywords, leaving out the soft keywords like `match` that are only keywords in some
+/// places.
+const PYTHON: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// The reserved words of `language`, in alphabetical order.
+pub fn reserved_words(language: Language) -> &'static [&'static str]
+{
+    match language
+    {
+        Language::JavaScript => JAVASCRIPT,
+        Language::Rust => RUST,
+        Language::Python => PYTHON,
+        Language::Sql => sql::RESERVED,
+    }
+}
+
+/// Whether `word` is reserved in `language`.
+pub fn is_reserved(word: &str, language: Language) -> bool
+{
+    match language
+    {
+        Language::Sql => sql::RESERVED.iter().any(|reserved| reserved.eq_ignore_ascii_case(word)),
+        _ => reserved_words(language).contains(&word),
+    }
+}
+
+/// How [`avoid_reserved`] renames a reserved word.
+#[derive(Clone, Debug)]
+pub struct Avoidance
+{
+    pub language: Language,
+    pub prefix: String,
+    pub suffix: String,
+    /// Names to use for particular reserved words instead of adding the prefix and suffix, as
+    /// `klass` for `class`.
+    pub replacements: Vec<(String, String)>,
+}
+
+/// Returns `name`, or, if it is reserved, its replacement in `avoidance`, if it has one, or else
+/// `name` between the prefix and suffix of `avoidance`.
+pub fn avoid_reserved(name: String, avoidance: &Avoidance) -> String
+{
+    if !is_reserved(&name, avoidance.language)
+    {
+        return name;
+    }
+    match avoidance.replacements.iter().find(|(word, _)| *word == name)
+    {
+        Some((_, replacement)) => replacement.clone(),
+        None => format!("{}{}{}", avoidance.prefix, name, avoidance.suffix),
+    }
+}
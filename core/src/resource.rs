@@ -0,0 +1,202 @@
+use std::ops::RangeInclusive;
+
+use convert_case::{Case, Converter};
+
+use crate::trace;
+
+/// How many hexadecimal digits of the name's hash are appended to truncated names.
+const HASH_LENGTH: usize = 6;
+
+/// The characters a resource name may contain, written like a regular expression character class
+/// without the brackets, such as `a-z0-9-`.
+pub struct Charset(Vec<RangeInclusive<char>>);
+
+impl Charset
+{
+    /// Parses a charset.  A `-` between two characters makes a range, and anywhere else it stands
+    /// for itself.
+    pub fn parse(charset: &str) -> Result<Self, String>
+    {
+        let chars: Vec<char> = charset.chars().collect();
+        let mut ranges = Vec::new();
+        let mut i = 0;
+        while i < chars.len()
+        {
+            if i + 2 < chars.len() && chars[i + 1] == '-'
+            {
+                if chars[i] > chars[i + 2]
+                {
+                    return Err(format!("Invalid charset range \"{}-{}\"", chars[i], chars[i + 2]));
+                }
+                ranges.push(chars[i]..=chars[i + 2]);
+                i += 3;
+            }
+            else
+            {
+                ranges.push(chars[i]..=chars[i]);
+                i += 1;
+            }
+        }
+
+        if ranges.is_empty()
+        {
+            return Err(String::from("The charset is empty"));
+        }
+        Ok(Charset(ranges))
+    }
+
+    /// ASCII letters and digits, the charset used when none is given.
+    fn alphanumeric() -> Self
+    {
+        Charset(vec!['a'..='z', 'A'..='Z', '0'..='9'])
+    }
+
+    pub fn contains(&self, c: char) -> bool
+    {
+        self.0.iter().any(|range| range.contains(&c))
+    }
+
+    fn contains_all(&self, s: &str) -> bool
+    {
+        s.chars().all(|c| self.contains(c))
+    }
+}
+
+/// Builds names that fit a cloud provider's naming rules out of arbitrary strings.
+pub struct ResourceNamer
+{
+    words: Converter,
+    delim: String,
+    max_length: Option<usize>,
+    charset: Charset,
+    /// Whether hashes are written in uppercase, for charsets that only allow uppercase letters
+    upper_hash: Option<bool>,
+}
+
+impl ResourceNamer
+{
+    /// Checks that names in `case` can be built from `charset` and still fit in `max_length`, with
+    /// room for a hash if `hash_suffix` is set.  Without a charset, names are limited to ASCII
+    /// letters, digits, and the delimiter of `case`.
+    pub fn new(case: Case, max_length: Option<usize>, charset: Option<Charset>, hash_suffix: bool) -> Result<Self, String>
+    {
+        let delim = Converter::new().to_case(case).delim;
+        let charset = match charset
+        {
+            Some(charset) =>
+            {
+                if !charset.contains_all(&delim)
+                {
+                    return Err(format!("The charset doesn't allow \"{}\", which {:?} case joins words with", delim, case));
+                }
+                charset
+            }
+            None =>
+            {
+                let mut charset = Charset::alphanumeric();
+                charset.0.extend(delim.chars().map(|c| c..=c));
+                charset
+            }
+        };
+
+        if max_length == Some(0)
+        {
+            return Err(String::from("maxLength must be at least 1"));
+        }
+
+        let upper_hash = if hash_suffix
+        {
+            let upper_hash = match (charset.contains_all("0123456789abcdef"), charset.contains_all("0123456789ABCDEF"))
+            {
+                (true, _) => false,
+                (false, true) => true,
+                (false, false) => return Err(String::from("The charset doesn't allow the hexadecimal digits of a hash suffix")),
+            };
+            if max_length.is_some_and(|max_length| max_length <= HASH_LENGTH + delim.chars().count())
+            {
+                return Err(format!("maxLength must be over {} to leave room for a hash suffix", HASH_LENGTH + delim.chars().count()));
+            }
+            Some(upper_hash)
+        }
+        else
+        {
+            None
+        };
+
+        // Words come out in the case's pattern with NUL between them, so they can be dropped from the
+        // end without disturbing the words before
+        let words = Converter::new().to_case(case).set_delim("\0");
+        Ok(ResourceNamer { words, delim, max_length, charset, upper_hash })
+    }
+
+    /// Converts `input` to a name.  Characters outside the charset are dropped, and a name that is
+    /// too long loses whole words from its end, then gets a hash of the full name appended if hash
+    /// suffixes are on.  Returns `None` if every character was dropped.
+    pub fn name(&self, input: &str) -> Option<String>
+    {
+        let converted = self.words.convert(input);
+        let words: Vec<String> = converted
+            .split('\0')
+            .map(|word| word.chars().filter(|c| self.charset.contains(*c)).collect::<String>())
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.is_empty()
+        {
+            return None;
+        }
+
+        let name = words.join(&self.delim);
+        let max_length = match self.max_length
+        {
+            Some(max_length) if name.chars().count() > max_length => max_length,
+            _ => return Some(name),
+        };
+
+        let suffix = match self.upper_hash
+        {
+            Some(upper) =>
+            {
+                let hash = format!("{:08x}", fnv1a(&name));
+                let hash = if upper { hash.to_ascii_uppercase() } else { hash };
+                format!("{}{}", self.delim, &hash[..HASH_LENGTH])
+            }
+            None => String::new(),
+        };
+        let budget = max_length - suffix.chars().count();
+
+        let mut truncated = String::new();
+        let mut length = 0;
+        for word in &words
+        {
+            let separator = if truncated.is_empty() { 0 } else { self.delim.chars().count() };
+            let word_length = word.chars().count();
+            if length + separator + word_length > budget
+            {
+                break;
+            }
+            if separator > 0
+            {
+                truncated.push_str(&self.delim);
+            }
+            truncated.push_str(word);
+            length += separator + word_length;
+        }
+
+        // Only the first word is ever cut, and only if it doesn't fit on its own
+        if truncated.is_empty()
+        {
+            truncated = words[0].chars().take(budget).collect();
+        }
+        trace::fallback("toResourceName", input, || format!("truncated \"{}\" to {} characters", name, max_length));
+
+        truncated.push_str(&suffix);
+        Some(truncated)
+    }
+}
+
+/// The 32-bit FNV-1a hash of `s`, which unlike the standard library's hashers is guaranteed to be
+/// the same on every platform and release, so truncated names stay stable.
+fn fnv1a(s: &str) -> u32
+{
+    s.bytes().fold(0x811c_9dc5, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193))
+}
@@ -0,0 +1,118 @@
+//! Checks whether converting an identifier to another case and back gives the identifier back.
+
+use convert_case::{Case, Converter};
+
+/// One way a round trip lost information.
+pub struct Loss
+{
+    /// `"delimiters"`, `"acronym"`, `"casing"`, `"digits"`, or `"boundaries"`.
+    pub kind: &'static str,
+    pub message: String,
+}
+
+pub struct Reversibility
+{
+    pub reversible: bool,
+    /// The identifier converted to the target case.
+    pub forward: String,
+    /// `forward` converted back to the original case.
+    pub back: String,
+    pub losses: Vec<Loss>,
+}
+
+/// The words `input` has in `case`, as written.
+fn words(input: &str, case: Case) -> Vec<String>
+{
+    let split = Converter::new().from_case(case).set_delim("\0").convert(input);
+    split.split('\0').filter(|word| !word.is_empty()).map(String::from).collect()
+}
+
+fn quoted(words: &[String]) -> String
+{
+    words.iter().map(|word| format!("`{}`", word)).collect::<Vec<_>>().join(", ")
+}
+
+/// Pairs up runs of `original` and `back` that spell the same letters, ignoring case, so that
+/// words that were split or merged line up with what they became.
+fn align<'a>(original: &'a [String], back: &'a [String]) -> Vec<(&'a [String], &'a [String])>
+{
+    let mut groups = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < original.len() && j < back.len()
+    {
+        let (start_i, start_j) = (i, j);
+        let (mut left, mut right) = (original[i].chars().count(), back[j].chars().count());
+        i += 1;
+        j += 1;
+        while left != right
+        {
+            if left < right && i < original.len()
+            {
+                left += original[i].chars().count();
+                i += 1;
+            }
+            else if right < left && j < back.len()
+            {
+                right += back[j].chars().count();
+                j += 1;
+            }
+            else
+            {
+                break;
+            }
+        }
+        groups.push((&original[start_i..i], &back[start_j..j]));
+    }
+    if i < original.len() || j < back.len()
+    {
+        groups.push((&original[i..], &back[j..]));
+    }
+    groups
+}
+
+/// Converts `input` from `from_case` to `to_case` and back, and explains every difference
+/// between what comes back and `input`.
+///
+/// Delimiters that don't separate words, as in `_private` or `a__b`, are always dropped.  Words
+/// whose letters come back cased differently are reported as flattened acronyms if they were all
+/// uppercase, or as changed casing otherwise.  Words that come back split or merged are reported
+/// as such, as digits if any of them have digits and as lost boundaries otherwise.
+pub fn check_reversible(input: &str, from_case: Case, to_case: Case) -> Reversibility
+{
+    let forward = Converter::new().from_case(from_case).to_case(to_case).convert(input);
+    let back = Converter::new().from_case(to_case).to_case(from_case).convert(&forward);
+    let mut losses = Vec::new();
+
+    let trimmed = input.trim_matches(|c| c == '_' || c == '-' || c == ' ');
+    if trimmed.len() != input.len() || ["__", "--", "  "].iter().any(|double| input.contains(double))
+    {
+        let message = "delimiters that don't separate words are dropped".to_string();
+        losses.push(Loss { kind: "delimiters", message });
+    }
+
+    let original_words = words(input, from_case);
+    let back_words = words(&back, from_case);
+    for (original, back) in align(&original_words, &back_words)
+    {
+        if original.len() == 1 && back.len() == 1
+        {
+            let (original, back) = (&original[0], &back[0]);
+            if original == back
+            {
+                continue;
+            }
+            let is_acronym = original.chars().filter(|c| c.is_alphabetic()).count() > 1
+                && !original.chars().any(|c| c.is_lowercase());
+            let message = format!("`{}` comes back as `{}`", original, back);
+            losses.push(Loss { kind: if is_acronym { "acronym" } else { "casing" }, message });
+        }
+        else
+        {
+            let has_digits = original.iter().chain(back).any(|word| word.chars().any(|c| c.is_ascii_digit()));
+            let message = format!("{} comes back as {}", quoted(original), quoted(back));
+            losses.push(Loss { kind: if has_digits { "digits" } else { "boundaries" }, message });
+        }
+    }
+
+    Reversibility { reversible: back == input, forward, back, losses }
+}
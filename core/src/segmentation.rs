@@ -0,0 +1,82 @@
+//! Statistics on how strings are split into words, for characterizing names and tuning boundaries.
+
+use convert_case::{Boundary, Converter};
+
+/// The kinds of characters [`segment_stats`] reports, in the order it reports them.
+pub const CHARACTER_CLASSES: [&str; 6] = ["lowercase", "uppercase", "digit", "delimiter", "uncased", "other"];
+
+pub struct SegmentStats
+{
+    pub words: Vec<String>,
+    /// Which of [`CHARACTER_CLASSES`] the string has characters of.
+    pub character_classes: Vec<&'static str>,
+    /// How many times each boundary split the string, for the boundaries that did, in the order
+    /// of [`Boundary::all`].
+    pub boundaries: Vec<(Boundary, usize)>,
+}
+
+fn character_class(c: char) -> &'static str
+{
+    if c.is_lowercase()
+    {
+        "lowercase"
+    }
+    else if c.is_uppercase()
+    {
+        "uppercase"
+    }
+    else if c.is_ascii_digit()
+    {
+        "digit"
+    }
+    else if c == '_' || c == '-' || c == ' '
+    {
+        "delimiter"
+    }
+    else if c.is_alphabetic()
+    {
+        "uncased"
+    }
+    else
+    {
+        "other"
+    }
+}
+
+/// Splits `input` on `boundaries` and counts how often each of them fired.
+///
+/// A boundary fires wherever splitting would split on it, so a boundary that only ever fires
+/// where another already did, as `UpperDigit` and `Acronym` can, is still counted for each place.
+pub fn segment_stats(input: &str, boundaries: &[Boundary]) -> SegmentStats
+{
+    let split = Converter::new().set_boundaries(boundaries).set_delim("\0").convert(input);
+    let words = split.split('\0').filter(|word| !word.is_empty()).map(String::from).collect();
+
+    let character_classes = CHARACTER_CLASSES.iter()
+        .copied()
+        .filter(|class| input.chars().any(|c| character_class(c) == *class))
+        .collect();
+
+    // Each boundary looks at one, two, or three characters at a time, so it fires once for every
+    // window of that many characters it is found in
+    let chars: Vec<char> = input.chars().collect();
+    let mut counts: Vec<(Boundary, usize)> = Boundary::all()
+        .into_iter()
+        .filter(|boundary| boundaries.contains(boundary))
+        .map(|boundary| {
+            let width = match boundary
+            {
+                Boundary::Hyphen | Boundary::Underscore | Boundary::Space => 1,
+                Boundary::Acronym => 3,
+                _ => 2,
+            };
+            let count = chars.windows(width)
+                .filter(|window| Boundary::list_from(&window.iter().collect::<String>()).contains(&boundary))
+                .count();
+            (boundary, count)
+        })
+        .collect();
+    counts.retain(|(_, count)| *count > 0);
+
+    SegmentStats { words, character_classes, boundaries: counts }
+}
@@ -0,0 +1,495 @@
+use convert_case::Converter;
+
+/// Words that can't be used as an unquoted identifier in common SQL dialects.
+pub const RESERVED: &[&str] = &[
+    "ALL", "ALTER", "AND", "ANY", "AS", "ASC", "BETWEEN", "BY", "CASE", "CAST", "CHECK", "COLLATE",
+    "COLUMN", "CONSTRAINT", "CREATE", "CROSS", "CURRENT_DATE", "CURRENT_TIME", "CURRENT_TIMESTAMP",
+    "CURRENT_USER", "DEFAULT", "DELETE", "DESC", "DISTINCT", "DROP", "ELSE", "END", "EXCEPT",
+    "EXISTS", "FALSE", "FETCH", "FOR", "FOREIGN", "FROM", "FULL", "GRANT", "GROUP", "HAVING", "IN",
+    "INDEX", "INNER", "INSERT", "INTERSECT", "INTO", "IS", "JOIN", "KEY", "LEFT", "LIKE", "LIMIT",
+    "NATURAL", "NOT", "NULL", "OFFSET", "ON", "OR", "ORDER", "OUTER", "PRIMARY", "REFERENCES",
+    "RIGHT", "SELECT", "SET", "SOME", "TABLE", "THEN", "TO", "TRUE", "UNION", "UNIQUE", "UPDATE",
+    "USER", "USING", "VALUES", "WHEN", "WHERE", "WITH",
+];
+
+/// Words that start a table constraint rather than a column definition.
+const TABLE_CONSTRAINTS: &[&str] = &["CHECK", "EXCLUDE", "FOREIGN", "FULLTEXT", "INDEX", "KEY", "LIKE", "PRIMARY", "SPATIAL", "UNIQUE"];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind
+{
+    Word,
+    /// A `"double"`, `` `backtick` ``, or `[bracket]` quoted identifier.
+    Quoted,
+    Punctuator(char),
+    /// Strings and numbers.
+    Literal,
+}
+
+#[derive(Clone, Copy)]
+struct Token
+{
+    start: usize,
+    end: usize,
+    kind: Kind,
+}
+
+fn tokenize(sql: &str) -> Result<Vec<Token>, String>
+{
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len()
+    {
+        let start = i;
+        let kind = match bytes[i]
+        {
+            c if c.is_ascii_whitespace() =>
+            {
+                i += 1;
+                continue;
+            }
+            b'-' if sql[i..].starts_with("--") =>
+            {
+                i = sql[i..].find('\n').map_or(bytes.len(), |end| i + end);
+                continue;
+            }
+            b'/' if sql[i..].starts_with("/*") =>
+            {
+                i = sql[i + 2..].find("*/").map(|end| i + 2 + end + 2).ok_or("Unterminated comment")?;
+                continue;
+            }
+            quote @ (b'\'' | b'"' | b'`' | b'[') =>
+            {
+                let close = if quote == b'[' { b']' } else { quote };
+                i += 1;
+                loop
+                {
+                    match bytes.get(i)
+                    {
+                        // A doubled quote stands for itself
+                        Some(&c) if c == close && bytes.get(i + 1) == Some(&close) && close != b']' => i += 2,
+                        Some(&c) if c == close => break i += 1,
+                        Some(_) => i += 1,
+                        None => return Err(String::from("Unterminated quoted string or identifier")),
+                    }
+                }
+                if quote == b'\'' { Kind::Literal } else { Kind::Quoted }
+            }
+            c if c == b'_' || c.is_ascii_alphabetic() || c >= 0x80 =>
+            {
+                while i < bytes.len() && (bytes[i] == b'_' || bytes[i] == b'$' || bytes[i].is_ascii_alphanumeric() || bytes[i] >= 0x80)
+                {
+                    i += 1;
+                }
+                Kind::Word
+            }
+            c if c.is_ascii_digit() =>
+            {
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.')
+                {
+                    i += 1;
+                }
+                Kind::Literal
+            }
+            c =>
+            {
+                i += 1;
+                Kind::Punctuator(c as char)
+            }
+        };
+        tokens.push(Token { start, end: i, kind });
+    }
+
+    Ok(tokens)
+}
+
+/// Converts the column and constraint names in the `CREATE TABLE` and `ALTER TABLE` statements of
+/// `sql`.
+///
+/// Quoted identifiers are kept exactly as written, as are table names, types, keywords, literals,
+/// comments, and every other statement.  Column names used in `CHECK`, `DEFAULT`, and generated
+/// column expressions are converted along with their definitions.  A converted name that would be
+/// a reserved word is double-quoted.
+pub fn convert_ddl_identifiers(sql: &str, conv: &Converter) -> Result<String, String>
+{
+    let tokens = tokenize(sql)?;
+    let mut ddl = Ddl { sql, conv, tokens: &tokens, columns: Vec::new(), edits: Vec::new() };
+
+    let mut start = 0;
+    for end in 0..=tokens.len()
+    {
+        if end == tokens.len() || tokens[end].kind == Kind::Punctuator(';')
+        {
+            ddl.convert_statement(start, end);
+            start = end + 1;
+        }
+    }
+
+    ddl.edits.sort_by_key(|(token, _)| token.start);
+    ddl.edits.dedup_by_key(|(token, _)| token.start);
+
+    let mut converted = String::with_capacity(sql.len());
+    let mut copied = 0;
+    for (token, replacement) in ddl.edits
+    {
+        converted.push_str(&sql[copied..token.start]);
+        converted.push_str(&replacement);
+        copied = token.end;
+    }
+    converted.push_str(&sql[copied..]);
+
+    Ok(converted)
+}
+
+struct Ddl<'a>
+{
+    sql: &'a str,
+    conv: &'a Converter,
+    tokens: &'a [Token],
+    /// The unquoted column names defined by the current statement, lowercased.
+    columns: Vec<String>,
+    edits: Vec<(Token, String)>,
+}
+
+impl Ddl<'_>
+{
+    fn text(&self, i: usize) -> &str
+    {
+        let token = self.tokens[i];
+        &self.sql[token.start..token.end]
+    }
+
+    fn is_word(&self, i: usize, word: &str) -> bool
+    {
+        i < self.tokens.len() && self.tokens[i].kind == Kind::Word && self.text(i).eq_ignore_ascii_case(word)
+    }
+
+    fn is(&self, i: usize, kind: Kind) -> bool
+    {
+        i < self.tokens.len() && self.tokens[i].kind == kind
+    }
+
+    /// Renames the identifier at `i` unless it is quoted.
+    fn rename(&mut self, i: usize)
+    {
+        if !self.is(i, Kind::Word)
+        {
+            return;
+        }
+
+        let name = self.text(i);
+        let mut converted = self.conv.convert(name);
+        if RESERVED.iter().any(|word| word.eq_ignore_ascii_case(&converted))
+        {
+            converted = format!("\"{}\"", converted);
+        }
+        if converted != name
+        {
+            self.edits.push((self.tokens[i], converted));
+        }
+    }
+
+    /// Returns the index just past the parenthesis that closes the one at `open`.
+    fn close(&self, open: usize, end: usize) -> usize
+    {
+        let mut depth = 0;
+        for i in open..end
+        {
+            match self.tokens[i].kind
+            {
+                Kind::Punctuator('(') => depth += 1,
+                Kind::Punctuator(')') =>
+                {
+                    depth -= 1;
+                    if depth == 0
+                    {
+                        return i + 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        end
+    }
+
+    /// Splits `start..end` at the commas outside of parentheses.
+    fn items(&self, start: usize, end: usize) -> Vec<(usize, usize)>
+    {
+        let mut items = Vec::new();
+        let mut item_start = start;
+        let mut i = start;
+        while i < end
+        {
+            match self.tokens[i].kind
+            {
+                Kind::Punctuator('(') =>
+                {
+                    i = self.close(i, end);
+                    continue;
+                }
+                Kind::Punctuator(',') =>
+                {
+                    items.push((item_start, i));
+                    item_start = i + 1;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        if item_start < end
+        {
+            items.push((item_start, end));
+        }
+        items
+    }
+
+    /// Skips a possibly schema-qualified name at `i`.
+    fn skip_name(&self, mut i: usize) -> usize
+    {
+        i += 1;
+        while self.is(i, Kind::Punctuator('.'))
+        {
+            i += 2;
+        }
+        i
+    }
+
+    fn skip_words(&self, mut i: usize, words: &[&str]) -> usize
+    {
+        if words.iter().enumerate().all(|(offset, word)| self.is_word(i + offset, word))
+        {
+            i += words.len();
+        }
+        i
+    }
+
+    fn convert_statement(&mut self, start: usize, end: usize)
+    {
+        self.columns.clear();
+
+        if self.is_word(start, "CREATE")
+        {
+            let table = match (start..end).take_while(|&i| !self.is(i, Kind::Punctuator('('))).find(|&i| self.is_word(i, "TABLE"))
+            {
+                Some(table) => table,
+                None => return,
+            };
+            let name = self.skip_words(table + 1, &["IF", "NOT", "EXISTS"]);
+            let open = self.skip_name(name);
+            if self.is(open, Kind::Punctuator('('))
+            {
+                let close = self.close(open, end) - 1;
+                self.convert_elements(open + 1, close);
+            }
+        }
+        else if self.is_word(start, "ALTER") && self.is_word(start + 1, "TABLE")
+        {
+            let name = self.skip_words(start + 2, &["IF", "EXISTS"]);
+            let name = self.skip_words(name, &["ONLY"]);
+            let mut actions = self.skip_name(name);
+            if self.is(actions, Kind::Punctuator('*'))
+            {
+                actions += 1;
+            }
+
+            // Learn the added columns first so that expressions can refer to them
+            for (item_start, _) in self.items(actions, end)
+            {
+                let column = self.skip_words(item_start, &["ADD"]);
+                if column > item_start && !self.is_word(column, "CONSTRAINT") && !self.starts_table_constraint(column)
+                {
+                    let column = self.skip_words(column, &["COLUMN"]);
+                    let column = self.skip_words(column, &["IF", "NOT", "EXISTS"]);
+                    self.learn_column(column);
+                }
+            }
+            for (item_start, item_end) in self.items(actions, end)
+            {
+                self.convert_action(item_start, item_end);
+            }
+        }
+    }
+
+    fn learn_column(&mut self, i: usize)
+    {
+        if self.is(i, Kind::Word)
+        {
+            let column = self.text(i).to_ascii_lowercase();
+            self.columns.push(column);
+        }
+    }
+
+    fn starts_table_constraint(&self, i: usize) -> bool
+    {
+        TABLE_CONSTRAINTS.iter().any(|word| self.is_word(i, word))
+    }
+
+    fn convert_elements(&mut self, start: usize, end: usize)
+    {
+        let items = self.items(start, end);
+        for &(item_start, _) in &items
+        {
+            if !self.is_word(item_start, "CONSTRAINT") && !self.starts_table_constraint(item_start)
+            {
+                self.learn_column(item_start);
+            }
+        }
+        for (item_start, item_end) in items
+        {
+            self.convert_element(item_start, item_end);
+        }
+    }
+
+    /// Converts a column definition or table constraint.
+    fn convert_element(&mut self, start: usize, end: usize)
+    {
+        if self.is_word(start, "CONSTRAINT")
+        {
+            self.rename(start + 1);
+            self.convert_constraint(start + 2, end);
+        }
+        else if self.starts_table_constraint(start)
+        {
+            self.convert_constraint(start, end);
+        }
+        else
+        {
+            self.rename(start);
+            self.convert_constraint(start + 1, end);
+        }
+    }
+
+    /// Converts the names in the constraints and expressions of `start..end`: constraint names,
+    /// key column lists, referenced columns, and column references in expressions.
+    fn convert_constraint(&mut self, start: usize, end: usize)
+    {
+        let mut i = start;
+        while i < end
+        {
+            if self.is_word(i, "CONSTRAINT")
+            {
+                self.rename(i + 1);
+                i += 2;
+            }
+            else if self.is_word(i, "REFERENCES")
+            {
+                let open = self.skip_name(i + 1);
+                if self.is(open, Kind::Punctuator('('))
+                {
+                    i = self.convert_column_list(open, end);
+                }
+                else
+                {
+                    i = open;
+                }
+            }
+            else if ["KEY", "INDEX", "UNIQUE"].iter().any(|word| self.is_word(i, word))
+            {
+                // An optional index name, as in MySQL's `UNIQUE KEY name (columns)`
+                let mut open = i + 1;
+                if self.is_word(open, "KEY") || self.is_word(open, "INDEX")
+                {
+                    open += 1;
+                }
+                if self.is(open, Kind::Word) && self.is(open + 1, Kind::Punctuator('('))
+                {
+                    self.rename(open);
+                    open += 1;
+                }
+                i = if self.is(open, Kind::Punctuator('(')) { self.convert_column_list(open, end) } else { open };
+            }
+            else if self.is(i, Kind::Punctuator('('))
+            {
+                let close = self.close(i, end);
+                self.convert_expression(i + 1, close - 1);
+                i = close;
+            }
+            else
+            {
+                i += 1;
+            }
+        }
+    }
+
+    /// Renames the first identifier of every item in the parenthesized list at `open`.
+    fn convert_column_list(&mut self, open: usize, end: usize) -> usize
+    {
+        let close = self.close(open, end);
+        for (item_start, _) in self.items(open + 1, close - 1)
+        {
+            self.rename(item_start);
+        }
+        close
+    }
+
+    /// Renames the words in an expression that name a column of the current statement.
+    fn convert_expression(&mut self, start: usize, end: usize)
+    {
+        for i in start..end
+        {
+            let is_column = self.is(i, Kind::Word)
+                && !self.is(i + 1, Kind::Punctuator('('))
+                && self.columns.iter().any(|column| column.eq_ignore_ascii_case(self.text(i)));
+            if is_column
+            {
+                self.rename(i);
+            }
+        }
+    }
+
+    fn convert_action(&mut self, start: usize, end: usize)
+    {
+        let mut i = start;
+        if self.is_word(i, "ADD")
+        {
+            i = self.skip_words(i + 1, &["COLUMN"]);
+            i = self.skip_words(i, &["IF", "NOT", "EXISTS"]);
+            self.convert_element(i, end);
+        }
+        else if self.is_word(i, "DROP")
+        {
+            if self.is_word(i + 1, "CONSTRAINT") || self.is_word(i + 1, "COLUMN")
+            {
+                i += 1;
+            }
+            else if self.is_word(i + 1, "PRIMARY") || self.is_word(i + 1, "DEFAULT")
+            {
+                return;
+            }
+            else if self.is_word(i + 1, "INDEX") || self.is_word(i + 1, "KEY") || self.is_word(i + 1, "FOREIGN")
+            {
+                i = self.skip_words(i + 1, &["FOREIGN"]);
+            }
+            i = self.skip_words(i + 1, &["IF", "EXISTS"]);
+            self.rename(i);
+        }
+        else if self.is_word(i, "RENAME")
+        {
+            if self.is_word(i + 1, "TO")
+            {
+                return;
+            }
+            i = if self.is_word(i + 1, "COLUMN") || self.is_word(i + 1, "CONSTRAINT") { i + 2 } else { i + 1 };
+            self.rename(i);
+            if self.is_word(i + 1, "TO")
+            {
+                self.rename(i + 2);
+            }
+        }
+        else if self.is_word(i, "ALTER") || self.is_word(i, "MODIFY")
+        {
+            i = self.skip_words(i + 1, &["COLUMN"]);
+            self.rename(i);
+            self.convert_constraint(i + 1, end);
+        }
+        else if self.is_word(i, "CHANGE")
+        {
+            i = self.skip_words(i + 1, &["COLUMN"]);
+            self.rename(i);
+            self.rename(i + 1);
+            self.convert_constraint(i + 2, end);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use convert_case::Converter;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Key, Table, Value};
+
+use crate::collisions::{self, CollisionPolicy};
+
+/// Converts every table and key name in a TOML document.
+///
+/// The document is edited in place with `toml_edit`, so comments, whitespace, and the order of
+/// keys and tables are preserved.  Renamed keys are written bare when possible and quoted
+/// otherwise.  TOML doesn't allow a key twice, so keys of one table that convert to the same name
+/// fail with the message of the [`Collision`](crate::collisions::Collision) instead of one of them
+/// being lost.
+pub fn convert_toml_keys(toml: &str, conv: &Converter) -> Result<String, String>
+{
+    let mut document: DocumentMut = toml.parse().map_err(|e| format!("Invalid TOML: {}", e))?;
+    convert_table(document.as_table_mut(), conv, &mut Vec::new())?;
+    Ok(document.to_string())
+}
+
+fn renamed(key: &Key, name: String) -> Key
+{
+    Key::new(name)
+        .with_leaf_decor(key.leaf_decor().clone())
+        .with_dotted_decor(key.dotted_decor().clone())
+}
+
+/// Converts `keys` and fails if any two of them convert to the same name.
+fn names(keys: &[String], conv: &Converter, path: &[String]) -> Result<Vec<String>, String>
+{
+    let names = keys.iter().map(|key| conv.convert(key)).collect();
+    let (names, _) = collisions::resolve(keys, names, CollisionPolicy::Error, &conv.delim, path)
+        .map_err(|collision| collision.message())?;
+    Ok(names.into_iter().flatten().collect())
+}
+
+fn convert_table(table: &mut Table, conv: &Converter, path: &mut Vec<String>) -> Result<(), String>
+{
+    let keys: Vec<String> = table.iter().map(|(key, _)| key.to_owned()).collect();
+    let names = names(&keys, conv, path)?;
+
+    for (key, name) in keys.into_iter().zip(names)
+    {
+        if let Some((key, mut item)) = table.remove_entry(&key)
+        {
+            path.push(key.get().to_owned());
+            convert_item(&mut item, conv, path)?;
+            path.pop();
+            table.insert_formatted(&renamed(&key, name), item);
+        }
+    }
+    Ok(())
+}
+
+fn convert_inline_table(table: &mut InlineTable, conv: &Converter, path: &mut Vec<String>) -> Result<(), String>
+{
+    let keys: Vec<String> = table.iter().map(|(key, _)| key.to_owned()).collect();
+    let names = names(&keys, conv, path)?;
+
+    for (key, name) in keys.into_iter().zip(names)
+    {
+        if let Some((key, mut value)) = table.remove_entry(&key)
+        {
+            path.push(key.get().to_owned());
+            convert_value(&mut value, conv, path)?;
+            path.pop();
+            table.insert_formatted(&renamed(&key, name), value);
+        }
+    }
+    Ok(())
+}
+
+fn convert_array(array: &mut Array, conv: &Converter, path: &mut Vec<String>) -> Result<(), String>
+{
+    array.iter_mut().try_for_each(|value| convert_value(value, conv, path))
+}
+
+fn convert_item(item: &mut Item, conv: &Converter, path: &mut Vec<String>) -> Result<(), String>
+{
+    match item
+    {
+        Item::Table(table) => convert_table(table, conv, path),
+        Item::ArrayOfTables(tables) => tables.iter_mut().try_for_each(|table| convert_table(table, conv, path)),
+        Item::Value(value) => convert_value(value, conv, path),
+        Item::None => Ok(()),
+    }
+}
+
+fn convert_value(value: &mut Value, conv: &Converter, path: &mut Vec<String>) -> Result<(), String>
+{
+    match value
+    {
+        Value::InlineTable(table) => convert_inline_table(table, conv, path),
+        Value::Array(array) => convert_array(array, conv, path),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use convert_case::Case;
+
+    use super::*;
+
+    #[test]
+    fn tables_and_keys_are_renamed_keeping_comments()
+    {
+        let conv = Converter::new().to_case(Case::Kebab);
+        let toml = "# manifest\n[packageInfo]\nzetaKey = 1 # keep\nalphaKey = { innerKey = 2 }\n";
+        assert_eq!(convert_toml_keys(toml, &conv).unwrap(), "# manifest\n[package-info]\nzeta-key = 1 # keep\nalpha-key = { inner-key = 2 }\n");
+    }
+
+    #[test]
+    fn colliding_keys_fail_with_their_path()
+    {
+        let conv = Converter::new().to_case(Case::Snake);
+        let error = convert_toml_keys("fooBar = 1\nfoo_bar = 2\n", &conv).unwrap_err();
+        assert!(error.contains(r#"Keys "fooBar", "foo_bar" all convert to "foo_bar""#), "{}", error);
+
+        let error = convert_toml_keys("[outer]\ninner = { aB = 1, a_b = 2 }\n", &conv).unwrap_err();
+        assert!(error.contains(r#"at "outer.inner""#), "{}", error);
+    }
+
+    #[test]
+    fn invalid_documents_fail()
+    {
+        let conv = Converter::new().to_case(Case::Snake);
+        assert!(convert_toml_keys("[unclosed", &conv).unwrap_err().starts_with("Invalid TOML: "));
+    }
+}
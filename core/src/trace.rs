@@ -0,0 +1,246 @@
+//! Events explaining how a conversion arrived at its output, for debugging unexpected results.
+//!
+//! The conversions emit these as [`tracing`] events with this module as their target, so any
+//! subscriber a Rust program installs sees them.  The bindings collect them with [`capture`],
+//! which installs a subscriber of its own while a call runs.  Nothing is built unless some
+//! subscriber is interested, so conversions that aren't being traced only pay for the check.
+
+use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Something a conversion decided that isn't visible in its output.
+pub enum TraceEvent
+{
+    /// `input` was split into `words`.
+    Split
+    {
+        input: String,
+        words: Vec<String>,
+    },
+
+    /// `input` was converted to `result` before, and the cache had kept it.
+    CacheHit
+    {
+        input: String,
+        result: String,
+    },
+
+    /// `function` couldn't convert `input` in the usual way and did something else, as `reason`
+    /// explains.
+    Fallback
+    {
+        function: String,
+        input: String,
+        reason: String,
+    },
+}
+
+/// The words of a split, each followed by a NUL, which none of them can contain since the
+/// splitter puts one between every two words.  The terminators keep an empty word apart from
+/// no words at all.
+struct Words<'a>(&'a [String]);
+
+impl Display for Words<'_>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        self.0.iter().try_for_each(|word| write!(f, "{}\0", word))
+    }
+}
+
+/// Emits the event that `input` was split into `words`.
+pub fn split(input: &str, words: &[String])
+{
+    tracing::debug!(kind = "split", input, words = %Words(words));
+}
+
+/// Emits the event that `input` was split into the words `words` gives, only building them if
+/// some subscriber is interested.
+pub fn split_with(input: &str, words: impl FnOnce() -> Vec<String>)
+{
+    if tracing::enabled!(tracing::Level::DEBUG)
+    {
+        split(input, &words());
+    }
+}
+
+/// Emits the event that the cache had kept `result` for `input`.
+pub fn cache_hit(input: &str, result: &str)
+{
+    tracing::debug!(kind = "cacheHit", input, result);
+}
+
+/// Emits the event that `function` fell back on something else for `input`.
+pub fn fallback(function: &str, input: &str, reason: impl FnOnce() -> String)
+{
+    // The reason is only built if the event is
+    tracing::debug!(kind = "fallback", function, input, reason = %reason());
+}
+
+/// Runs `f`, returning what it returned along with every event this module emitted while it ran.
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<TraceEvent>)
+{
+    let collector = Collector::default();
+    let events = collector.events.clone();
+    let result = tracing::subscriber::with_default(collector, f);
+    let events = std::mem::take(&mut *events.lock().unwrap());
+
+    (result, events)
+}
+
+/// The subscriber [`capture`] installs, which keeps the events of this module and ignores the
+/// rest.
+#[derive(Default)]
+struct Collector
+{
+    events: Arc<Mutex<Vec<TraceEvent>>>,
+}
+
+impl Subscriber for Collector
+{
+    fn enabled(&self, metadata: &Metadata) -> bool
+    {
+        metadata.target() == module_path!()
+    }
+
+    fn new_span(&self, _: &Attributes) -> Id
+    {
+        // No spans are enabled, so none get this far
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event)
+    {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let Fields { kind, function, input, words, result, reason } = fields;
+        let event = match kind.as_str()
+        {
+            "split" =>
+            {
+                let mut words: Vec<String> = words.split('\0').map(String::from).collect();
+                words.pop();
+                TraceEvent::Split { input, words }
+            }
+            "cacheHit" => TraceEvent::CacheHit { input, result },
+            "fallback" => TraceEvent::Fallback { function, input, reason },
+            _ => return,
+        };
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// The fields of an event of this module.
+#[derive(Default)]
+struct Fields
+{
+    kind: String,
+    function: String,
+    input: String,
+    words: String,
+    result: String,
+    reason: String,
+}
+
+impl Visit for Fields
+{
+    fn record_str(&mut self, field: &Field, value: &str)
+    {
+        match field.name()
+        {
+            "kind" => self.kind = value.to_string(),
+            "input" => self.input = value.to_string(),
+            "result" => self.result = value.to_string(),
+            "function" => self.function = value.to_string(),
+            _ => (),
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug)
+    {
+        match field.name()
+        {
+            "words" => self.words = format!("{:?}", value),
+            "reason" => self.reason = format!("{:?}", value),
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use convert_case::{Case, Converter};
+
+    use super::*;
+    use crate::conversion::{self, Apostrophes, CaseOptions};
+
+    /// The words of each split `f` emits.
+    fn splits(f: impl FnOnce()) -> Vec<Vec<String>>
+    {
+        let ((), events) = capture(f);
+        events.into_iter()
+            .filter_map(|event| match event
+            {
+                TraceEvent::Split { words, .. } => Some(words),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_are_reported_as_the_options_make_them()
+    {
+        let camel = Converter::new().to_case(Case::Camel);
+        let options = CaseOptions { keep_numeric_separators: true, ..CaseOptions::default() };
+        let words = splits(|| assert_eq!(conversion::convert("MAX_1_000_RETRIES", &camel, &options).unwrap(), "max1_000Retries"));
+        assert_eq!(words, [["MAX", "1_000", "RETRIES"]]);
+
+        let snake = Converter::new().to_case(Case::Snake);
+        let options = CaseOptions { apostrophes: Some(Apostrophes::Strip), ..CaseOptions::default() };
+        assert_eq!(splits(|| drop(conversion::convert("don't stop", &snake, &options))), [["dont", "stop"]]);
+
+        let options = CaseOptions { collapse_delims: false, ..CaseOptions::default() };
+        assert_eq!(splits(|| drop(conversion::convert("foo__bar", &snake, &options))), [["foo", "", "bar"]]);
+        assert_eq!(splits(|| drop(conversion::convert("", &snake, &options))), [Vec::<String>::new()]);
+    }
+
+    #[test]
+    fn checks_and_untraced_calls_emit_nothing()
+    {
+        assert!(splits(|| assert!(conversion::is_case("foo_bar", Case::Snake, &CaseOptions::default()))).is_empty());
+
+        let ((), events) = capture(|| ());
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn fallbacks_and_cache_hits_keep_their_fields()
+    {
+        let ((), events) = capture(|| {
+            fallback("toMacroName", "3d", || "prefixed".to_string());
+            cache_hit("fooBar", "foo_bar");
+        });
+        match &events[..]
+        {
+            [TraceEvent::Fallback { function, input, reason }, TraceEvent::CacheHit { input: hit, result }] =>
+            {
+                assert_eq!((function.as_str(), input.as_str(), reason.as_str()), ("toMacroName", "3d", "prefixed"));
+                assert_eq!((hit.as_str(), result.as_str()), ("fooBar", "foo_bar"));
+            }
+            _ => panic!("expected a fallback and a cache hit"),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Shortens strings to fit a length limit without cutting any word.
+
+use convert_case::{Case, Converter};
+
+use crate::find;
+
+/// The length of `text` in UTF-16 code units, as JS counts it.
+fn length(text: &str) -> usize
+{
+    text.encode_utf16().count()
+}
+
+/// Converts `input` to `case`, if it is given, and keeps as many of its words as fit in
+/// `max_length`, counted in UTF-16 code units with `ellipsis` after them if any words were
+/// dropped.  Words are never cut, so a string whose first word doesn't fit becomes empty.
+///
+/// Without a case, the string is kept as it is up to the end of the last word that fits.
+pub fn truncate_words(input: &str, max_length: usize, case: Option<Case>, ellipsis: &str) -> String
+{
+    let (text, ends) = match case
+    {
+        Some(case) =>
+        {
+            let conv = Converter::new().to_case(case);
+            let words = Converter::new().set_delim("\0").convert(input);
+            let words: Vec<&str> = words.split('\0').filter(|word| !word.is_empty()).collect();
+            let cased = match conv.pattern
+            {
+                Some(pattern) => pattern.mutate(&words),
+                None => words.iter().map(|word| word.to_string()).collect(),
+            };
+
+            // Every prefix of the words joined with the delimiter is a prefix of the whole
+            let mut text = String::new();
+            let mut ends = Vec::new();
+            for (i, word) in cased.iter().enumerate()
+            {
+                if i > 0
+                {
+                    text.push_str(&conv.delim);
+                }
+                text.push_str(word);
+                ends.push(text.len());
+            }
+            (text, ends)
+        }
+        None => (input.to_string(), find::words(input).into_iter().map(|(_, end)| end).collect()),
+    };
+
+    if length(&text) <= max_length || ends.is_empty()
+    {
+        return text;
+    }
+    let budget = max_length.saturating_sub(length(ellipsis));
+    match ends.iter().rev().find(|end| length(&text[..**end]) <= budget)
+    {
+        Some(end) => format!("{}{}", &text[..*end], ellipsis),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,74 @@
+use convert_case::Converter;
+use yaml_rust2::scanner::{ScanError, Scanner, TScalarStyle, TokenType};
+
+/// Converts the mapping keys of a YAML document by rewriting them in place.
+///
+/// The document is tokenized with a YAML scanner to find every scalar mapping key, and only the
+/// text of those keys is replaced, so comments, anchors, aliases, tags, quoting, and layout are
+/// all preserved.  Keys that are themselves collections are left alone, as are quoted keys
+/// containing escapes.
+pub fn convert_yaml_keys(yaml: &str, conv: &Converter) -> Result<String, ScanError>
+{
+    let mut spans = Vec::new();
+    let mut scanner = Scanner::new(yaml.chars());
+    let mut in_key = false;
+    let mut key_start = None;
+
+    while let Some(token) = scanner.next_token()?
+    {
+        match token.1
+        {
+            TokenType::StreamEnd => break,
+            TokenType::Key =>
+            {
+                in_key = true;
+                key_start = None;
+            }
+            TokenType::Anchor(_) | TokenType::Tag(..) if in_key => {}
+            TokenType::Scalar(style, value) if in_key =>
+            {
+                in_key = false;
+                key_start = Some((token.0.index(), style, value));
+            }
+            TokenType::Value =>
+            {
+                in_key = false;
+                if let Some((start, style, value)) = key_start.take()
+                {
+                    spans.push((start, token.0.index(), style, value));
+                }
+            }
+            _ =>
+            {
+                in_key = false;
+                key_start = None;
+            }
+        }
+    }
+
+    let chars: Vec<(usize, char)> = yaml.char_indices().collect();
+    let byte_offset = |index: usize| chars.get(index).map_or(yaml.len(), |&(offset, _)| offset);
+
+    let mut converted = String::with_capacity(yaml.len());
+    let mut copied = 0;
+    for (start, value_mark, style, value) in spans
+    {
+        let start = byte_offset(start);
+        let raw = yaml[start..byte_offset(value_mark)].trim_end();
+
+        let replacement = match style
+        {
+            TScalarStyle::Plain if raw == value => conv.convert(&value),
+            TScalarStyle::SingleQuoted if raw == format!("'{}'", value) => format!("'{}'", conv.convert(&value)),
+            TScalarStyle::DoubleQuoted if raw == format!("\"{}\"", value) => format!("\"{}\"", conv.convert(&value)),
+            _ => continue,
+        };
+
+        converted.push_str(&yaml[copied..start]);
+        converted.push_str(&replacement);
+        copied = start + raw.len();
+    }
+    converted.push_str(&yaml[copied..]);
+
+    Ok(converted)
+}
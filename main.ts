@@ -1,4 +1,6 @@
 import assert from "assert";
+import { Transform } from "stream";
+import { StringDecoder } from "string_decoder";
 
 const rsBind = require("./index.node");
 
@@ -717,10 +719,25 @@ namespace Boundary
      * // [Boundary.Underscore, Boundary.LowerUpper, Boundary.DigitUpper]
      * Boundary.listFrom("aA:6A:_")
      * ```
+     *
+     * Only the boundaries in `candidates` are looked for, if it is given, which keeps formats
+     * that only split on delimiters from picking up boundaries between letters.
+     * ```
+     * // [Boundary.Underscore]
+     * Boundary.listFrom("userId_list", Boundary.delims())
+     * ```
      */
-    export function listFrom(str: string): Boundary[]
+    export function listFrom(str: string, candidates?: Boundary[]): Boundary[]
     {
-        return rsBind.listFrom(str);
+        if (candidates !== undefined)
+        {
+            for (let i = 0; i < candidates.length; i++)
+            {
+                assert(candidates[i] in Boundary);
+            }
+        }
+
+        return rsBind.listFrom(str, candidates);
     }
 }
 
@@ -838,6 +855,197 @@ enum Pattern
     PseudoRandom,
 }
 
+/**
+ * A pattern written in JS, which is given each word with its index and returns what the word
+ * becomes.  See {@link CaseString.mutate}.
+ */
+type WordPattern = (word: string, index: number) => string;
+
+/**
+ * What to do with delimiters at the start or end of a string, as in `_private` or `value_`:
+ * `"strip"` drops them, so `"_foo_"` converts to `"foo"` and is in snake case; `"preserve"`
+ * keeps them as they are around the converted string, so `"_foo_"` converts to `"_foo_"`, and
+ * the string is in a case if the rest of it is; and `"reject"` makes converting throw a
+ * `RangeError` and the string not be in any case.
+ */
+type EdgeDelimiters = "strip" | "preserve" | "reject";
+
+/**
+ * What to do with a string without any words, one that is empty or only has whitespace, `_`, and
+ * `-` in it: `"unchanged"` leaves it as it is, `"empty"` converts it to `""`, and `"throw"` makes
+ * converting throw a `RangeError` and the string not be in any case.
+ */
+type EmptyInput = "unchanged" | "empty" | "throw";
+
+/**
+ * What to do with apostrophes, `'` and `’`, in words like `don't` and `users'`: `"keep"` leaves
+ * them in the word, so `"Don't Stop"` converts to `"don't_stop"` in snake case; `"strip"` drops
+ * them, giving `"dont_stop"`; and `"split"` splits the word on them, giving `"don_t_stop"`.
+ */
+type Apostrophes = "keep" | "strip" | "split";
+
+/**
+ * What to do with a string whose first word starts with a digit, as `"3dModel"` does, which
+ * isn't a valid identifier in most languages: `"prefixUnderscore"` puts an `_` before it, so
+ * `"3d model"` converts to `"_3_d_model"` in snake case; `"spellOut"` spells out each leading
+ * digit as a word, giving `"three_d_model"`; `"keep"` leaves it as it is; and `"error"` makes
+ * converting throw a `RangeError`.
+ */
+type LeadingDigit = "prefixUnderscore" | "spellOut" | "keep" | "error";
+
+/**
+ * Characters whose case {@link CaseOptions} `keepCase` keeps: `"greek"` and `"cyrillic"` are the
+ * letters of those alphabets, `"nonLatin"` the letters of any alphabet but the Latin one, and
+ * `"bracketed"` every character inside `()`, `[]`, or `{}`.  A bracketed span is also kept whole,
+ * delimiters included, rather than being split into words, so `"get [fooBar] value"` converts to
+ * `"get_[fooBar]_value"` in snake case.
+ */
+type KeepCase = "greek" | "cyrillic" | "nonLatin" | "bracketed";
+
+/**
+ * A language whose reserved words {@link reservedWords} lists.  `"javascript"` covers
+ * TypeScript too, and `"sql"` the words common SQL dialects reserve, which are compared ignoring
+ * case.
+ */
+type ReservedLanguage = "javascript" | "rust" | "python" | "sql";
+
+/**
+ * How to rename a converted string that is a reserved word of `language`: to its entry in
+ * `replacements`, if it has one, as `{class: "klass"}`, or else between `prefix` and `suffix`,
+ * which default to a suffix of `"_"` if neither is given.
+ */
+type ReservedWords = {language: ReservedLanguage, prefix?: string, suffix?: string, replacements?: Record<string, string>};
+
+/**
+ * Options shared by {@link CaseString.toCase}, {@link CaseString.isCase},
+ * {@link CaseString.mutate}, and the functions that detect which cases identifiers are in.
+ * Without `edgeDelimiters`, converting strips delimiters at the edges and checking a case rejects
+ * them.
+ *
+ * Several delimiters in a row separate two words unless `collapseDelims` is `false`, in which
+ * case every delimiter separates two words, with an empty word between each pair in a row, so
+ * `"foo__bar"` stays `"foo__bar"` in snake case and is in it.  Delimiters at the edges aren't
+ * between words, so they are left to `edgeDelimiters`.
+ *
+ * Strings without any words follow `emptyInput` if it is given, before any other option.  If it
+ * isn't, they convert like any other string, which usually gives `""`, but keeps whitespace
+ * other than spaces, as in `"\t"`, and with `edgeDelimiters: "preserve"`, the delimiters.
+ *
+ * A string longer than `maxInputLength`, or than {@link setMaxInputLength} last set if it isn't
+ * given, is refused with a `RangeError` whose `code` is `"ERR_INPUT_TOO_LONG"` before any work
+ * is done on it.  `Infinity` lifts the module's limit for one call.
+ *
+ * With `keepNumericSeparators`, an `_` between digits that starts a group of three stays inside
+ * the number, so `"MAX_1_000_RETRIES"` converts to `"max1_000Retries"` in camel case rather than
+ * `"max1000Retries"`.  Commas aren't delimiters, so `"1,000"` is always one word.
+ *
+ * With `wordRange`, only the words from its first index up to its second are converted, counting
+ * from the end for negative indices as `slice` does, and the rest of the string is kept as it
+ * is, but for the delimiters next to the range, which are written as the converted words are.
+ * The pattern still counts the words before the range, so camel case capitalizes a first
+ * converted word that isn't the first word.  A string is in a case if that range of it is.
+ *
+ * Apostrophes stay in the words they are in unless `apostrophes` says otherwise, so slugs made
+ * from titles usually want `"strip"`.
+ *
+ * A word after one that ends in a digit is capitalized in camel and pascal case, as in
+ * `"utf8String"` and `"base64Encode"`, unless `capitalizeAfterDigit` is `false`, which gives
+ * `"utf8string"` and `"base64encode"` instead.  Cases with a delimiter aren't affected.
+ *
+ * Strings whose first word starts with a digit are converted as they are unless `leadingDigit`
+ * says otherwise, which identifier cases for generated code usually should.
+ *
+ * A converted string that is a reserved word of the language `reservedWords` names is renamed
+ * as it says, so code generators don't emit `type` as a Rust field.  Since converting such a
+ * string changes it, it isn't in any case with that option.
+ * ```
+ * assert("type_" === CS("Type").toCase(Case.Snake, undefined, {reservedWords: {language: "rust"}}).toString());
+ * ```
+ *
+ * With a `seed`, an integer from 0 to 2^64 - 1, {@link Case.Random} and {@link Case.PseudoRandom}
+ * case the same string the same way on every run and platform, for stable test fixtures.
+ *
+ * The characters in any of the classes in `keepCase` keep their case while the pattern cases the
+ * rest.
+ * ```
+ * assert("DELTA_ΔT_[Hz]" === CS("delta ΔT [mV]").toCase(Case.UpperSnake, undefined, {keepCase: ["greek", "bracketed"]}).toString());
+ * ```
+ * ```
+ * assert("legacy_HTTPClientFactory" === CS("legacy_HTTP_client_factory").toCase(Case.Pascal, undefined, {wordRange: [-2, Infinity]}).toString());
+ * ```
+ */
+type CaseOptions = {edgeDelimiters?: EdgeDelimiters, collapseDelims?: boolean, emptyInput?: EmptyInput, maxInputLength?: number, keepNumericSeparators?: boolean, wordRange?: [number, number], apostrophes?: Apostrophes, capitalizeAfterDigit?: boolean, leadingDigit?: LeadingDigit, reservedWords?: ReservedWords, seed?: number | bigint, keepCase?: KeepCase[]};
+
+/**
+ * Sets the longest string the module accepts, or with `null`, lets it accept any.  Every native
+ * function measures the strings it is given, including documents such as the JSON of
+ * {@link convertJsonKeys}, every string of an array of identifiers, and the keys and converted
+ * values of {@link convertKeys} and {@link renameColumns}, and refuses longer ones with a
+ * `RangeError` whose `code` is `"ERR_INPUT_TOO_LONG"`.  The functions taking
+ * {@link CaseOptions} use this limit only when they aren't given a `maxInputLength`.  Strings are
+ * measured in UTF-16 code units, as `length` measures them.  The limit belongs to this instance
+ * of the module, so each worker thread has its own.
+ * ```
+ * setMaxInputLength(10_000);
+ * // RangeError, with code "ERR_INPUT_TOO_LONG"
+ * CS("a".repeat(20_000)).toCase(Case.Snake);
+ * ```
+ */
+function setMaxInputLength(maxInputLength: number | null): void
+{
+    assert(maxInputLength === null || maxInputLength >= 0);
+    rsBind.setMaxInputLength(maxInputLength ?? undefined);
+}
+
+/**
+ * A run of characters {@link droppedCharacters} found a conversion to have `"removed"` from the
+ * original string, `"replaced"` with others, or `"added"` to it.  `index` is where the run starts
+ * in the original string, or, for added characters, the index of the character after them, and
+ * `original` or `replacement` is `""` for characters that were added or removed.
+ */
+type DroppedCharacter = {
+    kind: "removed" | "replaced" | "added",
+    index: number,
+    original: string,
+    replacement: string,
+};
+
+/**
+ * Called by {@link CaseString.toCase} and {@link CaseString.mutate} with the characters a
+ * conversion dropped.
+ */
+type DroppedCallback = (dropped: DroppedCharacter[]) => void;
+
+/**
+ * Compares a string with what converting it with a delimiter of `delim` gave, and returns each
+ * run of characters that was removed, replaced, or added, in order, for logging every lossy
+ * change to a name.  Characters that only changed case aren't reported, and neither are
+ * delimiters that `delim` or another delimiter replaced.  The strings are aligned on the
+ * characters they share, which takes time proportional to their lengths times the number of
+ * characters that differ.
+ * ```
+ * // [{kind: "removed", index: 0, original: "_", replacement: ""},
+ * //  {kind: "removed", index: 4, original: "'", replacement: ""}]
+ * droppedCharacters("_Don't stop", "dont_stop", "_");
+ * ```
+ */
+function droppedCharacters(before: string, after: string, delim: string = ""): DroppedCharacter[]
+{
+    return rsBind.droppedCharacters(before, after, delim);
+}
+
+/**
+ * Calls `onDropped`, if it is given, with what converting `before` to `after` dropped, if
+ * anything.
+ */
+function reportDropped(before: string, after: string, delim: string, onDropped?: DroppedCallback): void
+{
+    if (onDropped === undefined) return;
+
+    const dropped = droppedCharacters(before, after, delim);
+    if (dropped.length > 0) onDropped(dropped);
+}
+
 /**
  * Extends `String` with `toCase`, `isCase`, and `mutate`.
  */
@@ -853,13 +1061,40 @@ class CaseString extends String
      * // Convert from kebab to cobol
      * assert("TOBE_OR NOT-TO-BE" === CS("toBe_or not-to-BE").toCase(Case.Cobol, Case.Kebab).toString());
      * ```
+     *
+     * Delimiters at the start or end of the string are dropped unless `options.edgeDelimiters`
+     * says otherwise.
+     * ```
+     * assert("_myVariable" === CS("_my_variable").toCase(Case.Camel, undefined, {edgeDelimiters: "preserve"}).toString());
+     * ```
+     *
+     * `options.onDropped` is called with the characters converting removed or changed other
+     * than by casing them, if there are any, as {@link droppedCharacters} finds them.
+     *
+     * `options.pattern` replaces the pattern of `caseType` and keeps its delimiter, for
+     * combinations no case has.
+     * ```
+     * assert("My_Variable_Name" === CS("myVariableName").toCase(Case.Snake, undefined, {pattern: Pattern.Capital}).toString());
+     * ```
+     *
+     * A `fromCase` of `"auto"` splits the string on the boundaries of the case it is detected to
+     * be in: the first of the cases {@link caseConsistencyReport} counts that it is in, or, if it
+     * is in none, snake, kebab, or lower case for whichever of `_`, `-`, and spaces it has most of,
+     * or camel case if it has none.
+     * ```
+     * // The default boundaries would also split "v2", giving "mixed-case-v-2"
+     * assert("mixed-case-v2" === CS("MIXED_Case_v2").toCase(Case.Kebab, "auto").toString());
+     * ```
      */
-    toCase(caseType: Case, fromCase?: Case): CaseString
+    toCase(caseType: Case, fromCase?: Case | "auto", options: CaseOptions & {pattern?: Pattern, onDropped?: DroppedCallback} = {}): CaseString
     {
         assert(caseType in Case);
-        if (fromCase !== undefined) assert(fromCase in Case);
+        if (fromCase !== undefined && fromCase !== "auto") assert(fromCase in Case);
+        if (options.pattern !== undefined) assert(options.pattern in Pattern);
 
-        return CS(rsBind.toCase(this.toString(), caseType, fromCase));
+        const converted = rsBind.toCase(this.toString(), caseType, fromCase, options);
+        reportDropped(this.toString(), converted, Case.delim(caseType), options.onDropped);
+        return CS(converted);
     }
 
     /**
@@ -874,19 +1109,46 @@ class CaseString extends String
      * assert("kebab-case-string".isCase(Case.Snake));
      * assert("kebab-case-string".isCase(Case.Train));
      * ```
+     *
+     * A string with delimiters at its start or end isn't in any case unless
+     * `options.edgeDelimiters` says otherwise.
+     * ```
+     * assert(CS("_private_name").isCase(Case.Snake, {edgeDelimiters: "strip"}));
+     * ```
      */
-    isCase(caseType: Case): boolean
+    isCase(caseType: Case, options: CaseOptions = {}): boolean
     {
         assert(caseType in Case);
-        return rsBind.isCase(this.toString(), caseType);
+        return rsBind.isCase(this.toString(), caseType, options);
     }
 
     /**
      * Wrapper for the `set_pattern`, `add_boundaries` (+ `remove_boundaries`), and `set_delim` Converter methods.
+     *
+     * `pattern` can also be a function, for one-off rules no `Pattern` covers.  The string is still
+     * split into words natively, but the function is then called once per word, and each of those
+     * calls crosses from Rust back into JS.  That overhead grows with the number of words, while a
+     * `Pattern` never leaves Rust, so prefer a `Pattern` wherever one fits.
+     * ```
+     * // "xml_HTTP_request"
+     * CS("xmlHttpRequest").mutate({pattern: (word, i) => i == 1 ? word.toUpperCase() : word.toLowerCase(), delim: "_"});
+     * ```
+     *
+     * The {@link CaseOptions} apply here too, and a pattern function is given the empty words
+     * that `collapseDelims: false` keeps.  `onDropped` is called as {@link CaseString.toCase}
+     * calls it.
+     *
+     * `boundaries` replaces the default boundaries unless `defaultBoundaries` is `true`, in which
+     * case they are added to them.  Without `boundaries`, `defaultBoundaries: false` splits on
+     * nothing at all.
+     * ```
+     * // "my.var.N.ame", where the boundaries alone would give "my_varN.ame"
+     * CS("my_varName").mutate({boundaries: [Boundary.UpperLower], defaultBoundaries: true, delim: "."});
+     * ```
      */
-    mutate(options: {pattern?: Pattern, boundaries?: Boundary[], delim?: string}): CaseString
+    mutate(options: CaseOptions & {pattern?: Pattern | WordPattern, boundaries?: Boundary[], defaultBoundaries?: boolean, delim?: string, onDropped?: DroppedCallback}): CaseString
     {
-        if (options.pattern !== undefined) assert(options.pattern in Pattern);
+        if (typeof options.pattern === "number") assert(options.pattern in Pattern);
         if (options.boundaries !== undefined)
         {
             for (let i = 0; i < options.boundaries.length; i++)
@@ -895,10 +1157,36 @@ class CaseString extends String
             }
         }
     
-        return CS(rsBind.mutate(this.toString(), options));
+        const mutated = rsBind.mutate(this.toString(), options);
+        reportDropped(this.toString(), mutated, options.delim ?? "", options.onDropped);
+        return CS(mutated);
     }
 }
 
+/**
+ * What {@link CS} does when it is given `null` or `undefined` instead of a string: `"throw"`
+ * throws a `TypeError`, and `"coerce"` takes it to be the empty string.
+ */
+type NullInput = "throw" | "coerce";
+
+let defaultNullInput: NullInput = "throw";
+
+/**
+ * Sets what {@link CS} does with `null` or `undefined` when it isn't told per call, which is
+ * `"throw"` until this is called.  The setting belongs to this instance of the module, so each
+ * worker thread has its own.
+ * ```
+ * setNullInput("coerce");
+ * // ""
+ * CS(record.nickname).toCase(Case.Snake).toString();
+ * ```
+ */
+function setNullInput(policy: NullInput): void
+{
+    assert(policy === "throw" || policy === "coerce");
+    defaultNullInput = policy;
+}
+
 /**
  * Convenience function for creating a new `CaseString`.
  * 
@@ -914,16 +1202,1605 @@ class CaseString extends String
  * console.log(CS("Testing string I have here").toCase(Case.Pascal).toString());
  * console.log(`${CS("Testing string I have here").toCase(Case.Pascal)}`);
  * ```
+ *
+ * `null` and `undefined` are handled as `options.nullInput` says, or as {@link setNullInput}
+ * last set if it isn't given.
  */
-function CS(str: string): CaseString
+function CS(str: string | null | undefined, options: {nullInput?: NullInput} = {}): CaseString
 {
+    if (str === null || str === undefined)
+    {
+        if ((options.nullInput ?? defaultNullInput) === "throw")
+        {
+            throw new TypeError(`CS expected a string, but was given ${str}`);
+        }
+        str = "";
+    }
+
     return new CaseString(str);
 }
 
+/**
+ * Converts only the key side of every `key=value` line in a block of text, such as a dotenv or
+ * properties file.  Values, line endings, and whitespace are left untouched, as are blank lines,
+ * lines without the separator, and comment lines starting with `#` or `!`.  A leading `export `
+ * or YAML `- ` is kept in front of the key.
+ *
+ * The separator defaults to `=`; use `":"` for `key: value` lines.
+ * ```
+ * assert("db_host=localhost\n# keep me\n" === convertLineKeys("dbHost=localhost\n# keep me\n", Case.Snake));
+ * assert("db-host: fooBar" === convertLineKeys("dbHost: fooBar", Case.Kebab, {separator: ":"}));
+ * ```
+ */
+function convertLineKeys(text: string, caseType: Case, options: {separator?: string} = {}): string
+{
+    assert(caseType in Case);
+    return rsBind.convertLineKeys(text, caseType, options);
+}
+
+/**
+ * Converts the attribute names of every tag in an HTML string.  Tag names, text content, attribute
+ * values, comments, and the contents of `<script>` and `<style>` elements are left untouched.
+ *
+ * Set `excludeData` or `excludeAria` to keep `data-*` or `aria-*` attributes as they are.
+ * ```
+ * assert('<div class-name="fooBar" data-userId="1">Hi</div>' ===
+ *     convertHtmlAttributes('<div className="fooBar" data-userId="1">Hi</div>', Case.Kebab, {excludeData: true}));
+ * ```
+ */
+function convertHtmlAttributes(html: string, caseType: Case, options: {excludeData?: boolean, excludeAria?: boolean} = {}): string
+{
+    assert(caseType in Case);
+    return rsBind.convertHtmlAttributes(html, caseType, options);
+}
+
+/**
+ * How the key conversion functions settle keys of one object that convert to the same name.  See
+ * {@link KeyOptions}.
+ */
+type CollisionPolicy = "lastWins" | "firstWins" | "suffix" | "error";
+
+/**
+ * Several keys of the object at `path` that were all converted to `name`.  `path` is the dot
+ * separated path of the object, as in {@link KeyOptions}, and is empty for the root.
+ */
+type KeyCollision = {path: string, keys: string[], name: string};
+
+/**
+ * Options shared by the key conversion functions {@link convertKeys}, {@link convertJsonKeys}, and
+ * {@link NdjsonKeyConverter}.
+ *
+ * * `deep`: convert the keys of nested objects, not just the top level.
+ * * `maxDepth`: the number of object levels whose keys are converted when `deep` is set.
+ * * `include`: when given, only keys whose path matches one of these patterns are renamed.  Their
+ * descendants are still visited.
+ * * `exclude`: keys whose path matches one of these patterns are kept verbatim, along with
+ * everything beneath them.
+ * * `values`: string values whose path matches one of these patterns are converted to the same
+ * case as the keys, for fields such as `sortBy` whose values name other fields.  The strings of
+ * an array at a matching path are converted too.  A value is converted whenever the walk
+ * reaches the key it belongs to, so `deep` and `maxDepth` limit values the same way as keys.
+ * * `collisions`: what to do when several keys of one object convert to the same name, such as
+ * `fooBar` and `foo_bar` in snake case.  `"lastWins"` (the default) keeps the last of them, as
+ * plain assignment would, and `"firstWins"` keeps the first.  `"suffix"` keeps every key by
+ * numbering the later ones (`foo_bar_2`), and `"error"` throws instead of converting.
+ * * `onCollision`: called with a {@link KeyCollision} for every collision that was settled.
+ * * `strict`: throw instead of guessing when the conversion is ambiguous: on a
+ * `__proto__` key, and, for {@link convertKeys}, on an object that isn't a plain object, array,
+ * `Map`, or `Set`.  Collisions throw too unless `collisions` says otherwise.
+ * * `renameAllCompat`: rename keys, and the values chosen by `values`, exactly as serde's
+ * `#[serde(rename_all = "...")]` would rename Rust identifiers, for mirroring Rust types whose
+ * serialized names must match byte for byte.  serde treats fields as snake_case and variants as
+ * PascalCase, so names starting with an uppercase letter are renamed as variants and all others
+ * as fields.  Its rules differ from ordinary conversion around digits and acronyms: the field
+ * `version_2beta` becomes `version2beta` in camel case, and the variant `IOError` becomes
+ * `i_o_error` in snake case.  The case chooses the rule: {@link Case.Flat} for `"lowercase"`,
+ * {@link Case.UpperFlat} for `"UPPERCASE"`, and {@link Case.Pascal}, {@link Case.Camel},
+ * {@link Case.Snake}, {@link Case.UpperSnake}, {@link Case.Kebab}, and {@link Case.Cobol} for the
+ * rest.  Any other case throws a `RangeError`.
+ *
+ * Whatever the options, keys keep their insertion order and objects with a `null` prototype are
+ * converted into objects with a `null` prototype.  Array index keys such as `"10"`, which JS
+ * always orders first, and symbol keys are copied without being renamed.  A `__proto__` key is
+ * always stored as an own property; it never replaces the prototype of the converted object.
+ *
+ * Paths are the original, unconverted keys from the root joined with `.`; arrays don't add a
+ * segment.  In a pattern, `*` matches exactly one key (or any run of characters within a key,
+ * as in `raw*`), and `**` matches any number of keys.
+ * ```
+ * // {user_id: 1, metadata: {rawBlob: {keepThis: 1}}, http_info: {rawHeaders: {"X-Id": 1}}}
+ * convertKeys(
+ *     {userId: 1, metadata: {rawBlob: {keepThis: 1}}, httpInfo: {rawHeaders: {"X-Id": 1}}},
+ *     Case.Snake,
+ *     {deep: true, exclude: ["metadata.*", "**.rawHeaders"]}
+ * );
+ * ```
+ */
+type KeyOptions = {
+    deep?: boolean,
+    maxDepth?: number,
+    include?: string[],
+    exclude?: string[],
+    values?: string[],
+    collisions?: CollisionPolicy,
+    onCollision?: (collision: KeyCollision) => void,
+    strict?: boolean,
+    renameAllCompat?: boolean,
+};
+
+/**
+ * A conversion result along with the renames that produced it, as returned by {@link convertKeys}
+ * and {@link convertJsonKeys} when `mappings` is set.
+ *
+ * `forwardMap` maps every original key to the name it was stored under, and `reverseMap` maps
+ * every stored name back to its original key, including names a `"suffix"` collision policy
+ * made up.  Applying `reverseMap` to a converted object restores the original keys exactly,
+ * without having to convert them back and hope the two conversions agree.  Keys that were renamed
+ * differently in different places, which only `include` and `exclude` can cause, keep the first
+ * rename.
+ * ```
+ * const {result, forwardMap, reverseMap} = convertKeys({userID: 1}, Case.Snake, {mappings: true});
+ * // {user_id: 1}, Map { "userID" => "user_id" }, Map { "user_id" => "userID" }
+ * ```
+ */
+type KeyMappings<T> = {result: T, forwardMap: Map<string, string>, reverseMap: Map<string, string>};
+
+/**
+ * Returns a copy of `obj` with its own enumerable string keys converted to the given case.  The
+ * result is always a new container, and with `deep`, so is every plain object, array, `Map`, and
+ * `Set` within `maxDepth`, whether or not any of its keys change.  All other values, and the
+ * containers past `maxDepth`, are kept by reference.
+ *
+ * By default only the top-level keys are converted.  With `deep` set, the keys of nested plain
+ * objects are converted too, down to `maxDepth` levels if given.  Arrays are walked transparently,
+ * so an array of records has the keys of every record converted.  Use the `include` and `exclude`
+ * path patterns described in {@link KeyOptions} to limit which keys are renamed.
+ *
+ * `Map`s are converted into new `Map`s with their string keys converted, and `Set`s into new `Set`s
+ * with their string members converted; keys and members of other types are kept as they are.
+ * Values that aren't plain objects, arrays, maps, or sets, such as class instances and dates, are
+ * never copied or modified.
+ * ```
+ * const user = {userId: 1, lastLogin: {timeZone: "UTC"}};
+ *
+ * // {user_id: 1, last_login: {timeZone: "UTC"}}
+ * convertKeys(user, Case.Snake);
+ *
+ * // {user_id: 1, last_login: {time_zone: "UTC"}}
+ * convertKeys(user, Case.Snake, {deep: true});
+ *
+ * // Map { "content-type" => "text/html" }
+ * convertKeys(new Map([["contentType", "text/html"]]), Case.Kebab);
+ * ```
+ *
+ * Set `mappings` to get a {@link KeyMappings} back instead of just the converted copy.
+ */
+function convertKeys(obj: object, caseType: Case, options: KeyOptions & {mappings: true}): KeyMappings<any>;
+function convertKeys(obj: object, caseType: Case, options?: KeyOptions & {mappings?: false}): any;
+function convertKeys(obj: object, caseType: Case, options: KeyOptions & {mappings?: boolean} = {}): any
+{
+    assert(caseType in Case);
+    if (options.maxDepth !== undefined) assert(options.maxDepth >= 0);
+
+    return rsBind.convertKeys(obj, caseType, options);
+}
+
+/**
+ * Converts the keys of every object in a JSON string and returns the re-serialized JSON.  The
+ * document is parsed and serialized natively, so no JS objects are created along the way.  Key
+ * order and number literals are preserved exactly.
+ *
+ * Unlike {@link convertKeys}, conversion is deep by default; pass `deep: false` or a `maxDepth`
+ * to limit it.  Set `pretty` to indent the output, and `mappings` to get a {@link KeyMappings}
+ * whose `result` is the JSON.  Throws an `Error` if `json` can't be parsed.
+ * ```
+ * assert('{"user_id":1,"tag_list":[{"tag_name":"a"}]}' === convertJsonKeys('{"userId": 1, "tagList": [{"tagName": "a"}]}', Case.Snake));
+ * ```
+ */
+function convertJsonKeys(json: string, caseType: Case, options: KeyOptions & {pretty?: boolean, mappings: true}): KeyMappings<string>;
+function convertJsonKeys(json: string, caseType: Case, options?: KeyOptions & {pretty?: boolean, mappings?: false}): string;
+function convertJsonKeys(json: string, caseType: Case, options: KeyOptions & {pretty?: boolean, mappings?: boolean} = {}): string | KeyMappings<string>
+{
+    assert(caseType in Case);
+    if (options.maxDepth !== undefined) assert(options.maxDepth >= 0);
+
+    return rsBind.convertJsonKeys(json, caseType, options);
+}
+
+/**
+ * Incrementally converts the keys of newline-delimited JSON (NDJSON) as chunks arrive.  Each
+ * record is parsed, converted, and serialized natively, following the same options as
+ * {@link convertJsonKeys}.  Records may be split across chunks; a record is emitted once its
+ * newline has been written.  Line endings and blank lines are passed through unchanged.
+ *
+ * `write` and `end` throw an `Error` naming the line number if a record isn't valid JSON.
+ * ```
+ * const converter = new NdjsonKeyConverter(Case.Snake);
+ * assert("" === converter.write('{"userId":'));
+ * assert('{"user_id":1}\n' === converter.write('1}\n{"userId"'));
+ * assert('{"user_id":2}' === converter.write(":2}") + converter.end());
+ * ```
+ *
+ * For piping Node streams, see {@link ndjsonKeyTransform}.
+ */
+class NdjsonKeyConverter
+{
+    private handle: unknown;
+
+    constructor(caseType: Case, options: KeyOptions = {})
+    {
+        assert(caseType in Case);
+        if (options.maxDepth !== undefined) assert(options.maxDepth >= 0);
+
+        this.handle = rsBind.ndjsonNew(caseType, options);
+    }
+
+    /** Buffers `chunk` and returns the converted form of every record it completes. */
+    write(chunk: string): string
+    {
+        return rsBind.ndjsonWrite(this.handle, chunk);
+    }
+
+    /** Converts whatever is left after the last newline. */
+    end(): string
+    {
+        return rsBind.ndjsonEnd(this.handle);
+    }
+}
+
+/**
+ * Returns a `Transform` stream that converts the keys of NDJSON piped through it using a
+ * {@link NdjsonKeyConverter}.  Multi-byte characters split across chunks are handled.
+ * ```
+ * fs.createReadStream("export.ndjson")
+ *     .pipe(ndjsonKeyTransform(Case.Camel))
+ *     .pipe(fs.createWriteStream("export-camel.ndjson"));
+ * ```
+ */
+function ndjsonKeyTransform(caseType: Case, options: KeyOptions = {}): Transform
+{
+    const converter = new NdjsonKeyConverter(caseType, options);
+    const decoder = new StringDecoder("utf8");
+
+    return new Transform({
+        transform(chunk: Buffer | string, _encoding: string, callback: (error?: Error | null, data?: string) => void)
+        {
+            try
+            {
+                callback(null, converter.write(typeof chunk === "string" ? chunk : decoder.write(chunk)));
+            }
+            catch (e)
+            {
+                callback(e as Error);
+            }
+        },
+        flush(callback: (error?: Error | null, data?: string) => void)
+        {
+            try
+            {
+                callback(null, converter.write(decoder.end()) + converter.end());
+            }
+            catch (e)
+            {
+                callback(e as Error);
+            }
+        },
+    });
+}
+
+/**
+ * Converts the mapping keys of a YAML document.  Only the text of each scalar key is rewritten,
+ * so comments, anchors, aliases, tags, quoting, indentation, and block scalar contents are all
+ * preserved.  Keys inside flow mappings (`{a: 1}`) are converted as well.  Quoted keys containing
+ * escape sequences and keys that are themselves collections are left as they are.
+ *
+ * Throws an `Error` if `yaml` can't be tokenized.
+ * ```
+ * assert("api_version: v1 # comment\nmeta_data: &base {}\n" === convertYamlKeys("apiVersion: v1 # comment\nmetaData: &base {}\n", Case.Snake));
+ * ```
+ */
+function convertYamlKeys(yaml: string, caseType: Case): string
+{
+    assert(caseType in Case);
+    return rsBind.convertYamlKeys(yaml, caseType);
+}
+
+/**
+ * Converts every table and key name in a TOML document, including dotted keys, arrays of tables,
+ * and inline tables.  The document is edited in place natively, so comments, whitespace, and the
+ * order of keys and tables are preserved.  Renamed keys are written bare when possible and quoted
+ * otherwise.
+ *
+ * Throws an `Error` if `toml` can't be parsed, or if two keys of one table convert to the same
+ * name, since TOML doesn't allow a key twice.
+ * ```
+ * assert("[package_info] # comment\ncrate_name = \"x\"\n" === convertTomlKeys("[packageInfo] # comment\ncrateName = \"x\"\n", Case.Snake));
+ * ```
+ */
+function convertTomlKeys(toml: string, caseType: Case): string
+{
+    assert(caseType in Case);
+    return rsBind.convertTomlKeys(toml, caseType);
+}
+
+/**
+ * Converts the field names in the header (first record) of a CSV string.  The header is parsed
+ * and rewritten with correct quoting, and everything after it is returned byte for byte.  With
+ * `headerOnly` set, only the new header row is returned, without a line terminator.
+ *
+ * `delimiter` must be a single ASCII character and defaults to `,`.
+ * ```
+ * assert('user_id,"full_name,alias"\n1,Ann\n' === convertCsvHeader('userId,"fullName,alias"\n1,Ann\n', Case.Snake));
+ * assert("user_id;last_name" === convertCsvHeader("userId;lastName\n1;2\n", Case.Snake, {delimiter: ";", headerOnly: true}));
+ * ```
+ */
+function convertCsvHeader(csv: string, caseType: Case, options: {delimiter?: string, headerOnly?: boolean} = {}): string
+{
+    assert(caseType in Case);
+    return rsBind.convertCsvHeader(csv, caseType, options);
+}
+
+/**
+ * Converts the parameter names of a query string, leaving the values and any leading `?` as they
+ * are.  Names are percent-decoded before conversion and re-encoded afterwards, so encoded
+ * characters are handled correctly.  A `URLSearchParams` can be passed instead of a string, in
+ * which case a new `URLSearchParams` is returned.
+ * ```
+ * assert("?user_id=1&first_name=Jo%20Ann" === convertQueryKeys("?userId=1&firstName=Jo%20Ann", Case.Snake));
+ * assert("userId=1" === convertQueryKeys(new URLSearchParams("user_id=1"), Case.Camel).toString());
+ * ```
+ */
+function convertQueryKeys(query: string, caseType: Case): string;
+function convertQueryKeys(query: URLSearchParams, caseType: Case): URLSearchParams;
+function convertQueryKeys(query: string | URLSearchParams, caseType: Case): string | URLSearchParams
+{
+    assert(caseType in Case);
+
+    if (query instanceof URLSearchParams)
+    {
+        return new URLSearchParams(rsBind.convertQueryKeys(query.toString(), caseType));
+    }
+    return rsBind.convertQueryKeys(query, caseType);
+}
+
+/**
+ * Renames the keys of every row in an array of flat records, such as the rows of a query result.
+ * Each row is copied shallowly, with its values kept by reference, and rows that aren't objects
+ * or are arrays are kept as they are.
+ *
+ * `columns` is either a case to convert every key to, or a mapping from old to new names, in
+ * which case keys that aren't in the mapping keep their name.  Every distinct key is converted or
+ * looked up only once, however many rows share it, which makes this much cheaper than
+ * {@link convertKeys} for large result sets.
+ * ```
+ * // [{user_id: 1, created_at: "..."}, {user_id: 2, created_at: "..."}]
+ * renameColumns([{userId: 1, createdAt: "..."}, {userId: 2, createdAt: "..."}], Case.Snake);
+ *
+ * // [{id: 1, createdAt: "..."}]
+ * renameColumns([{userId: 1, createdAt: "..."}], {userId: "id"});
+ * ```
+ */
+function renameColumns(rows: object[], columns: Case | Record<string, string> | Map<string, string>): any[]
+{
+    if (typeof columns === "number")
+    {
+        assert(columns in Case);
+        return rsBind.renameColumns(rows, columns);
+    }
+
+    const pairs = columns instanceof Map ? [...columns] : Object.entries(columns);
+    return rsBind.renameColumns(rows, pairs);
+}
+
+/**
+ * Converts an HTTP header name to its canonical Train-Case spelling.  Unlike `Case.Train`, this
+ * knows the headers that aren't simply capitalized, such as `ETag`, `WWW-Authenticate`,
+ * `Content-MD5`, `TE`, `DNT`, and `Sec-WebSocket-Key`.  The name may be in any case.
+ * ```
+ * assert("Content-Type" === canonicalizeHeader("content-type"));
+ * assert("X-Request-Id" === canonicalizeHeader("xRequestId"));
+ * assert("WWW-Authenticate" === canonicalizeHeader("WWW_AUTHENTICATE"));
+ * ```
+ */
+function canonicalizeHeader(name: string): string
+{
+    return rsBind.canonicalizeHeader(name);
+}
+
+/**
+ * Returns whether an HTTP header name is already spelled the way {@link canonicalizeHeader}
+ * would spell it.
+ * ```
+ * assert(isCanonicalHeader("ETag"));
+ * assert(!isCanonicalHeader("Etag"));
+ * ```
+ */
+function isCanonicalHeader(name: string): boolean
+{
+    return canonicalizeHeader(name) === name;
+}
+
+/**
+ * Converts a CSS property name to the camelCase name used by `element.style` and style objects.
+ * Vendor prefixes become a capitalized first word, except `-ms-`, which stays lowercase as
+ * browsers spell it.  Custom properties are returned unchanged, since they are only reachable
+ * through `setProperty` under their CSS name.
+ * ```
+ * assert("backgroundColor" === cssPropertyToCamel("background-color"));
+ * assert("WebkitTransition" === cssPropertyToCamel("-webkit-transition"));
+ * assert("msTransform" === cssPropertyToCamel("-ms-transform"));
+ * assert("--main-color" === cssPropertyToCamel("--main-color"));
+ * ```
+ */
+function cssPropertyToCamel(name: string): string
+{
+    return rsBind.cssPropertyToCamel(name);
+}
+
+/**
+ * Converts a camelCase style property name back to its CSS spelling, restoring the leading hyphen
+ * of vendor prefixes.  Names that already start with a hyphen, including custom properties, are
+ * returned unchanged.
+ * ```
+ * assert("background-color" === camelToCssProperty("backgroundColor"));
+ * assert("-webkit-transition" === camelToCssProperty("WebkitTransition"));
+ * assert("-ms-transform" === camelToCssProperty("msTransform"));
+ * ```
+ */
+function camelToCssProperty(name: string): string
+{
+    return rsBind.camelToCssProperty(name);
+}
+
+/**
+ * Converts an HTML or SVG attribute name to the prop name JSX uses for it.  Known HTML attributes
+ * get React's spelling, event handlers and hyphenated or namespaced SVG attributes are camelized,
+ * and `data-*` and `aria-*` attributes are kept verbatim.  Other names, including SVG's own
+ * camelCase attributes such as `viewBox`, are unchanged.
+ * ```
+ * assert("className" === htmlToJsxProp("class"));
+ * assert("htmlFor" === htmlToJsxProp("for"));
+ * assert("onMouseOver" === htmlToJsxProp("onmouseover"));
+ * assert("strokeWidth" === htmlToJsxProp("stroke-width"));
+ * assert("xlinkHref" === htmlToJsxProp("xlink:href"));
+ * assert("data-user-id" === htmlToJsxProp("data-user-id"));
+ * ```
+ */
+function htmlToJsxProp(name: string): string
+{
+    return rsBind.htmlToJsxProp(name);
+}
+
+/**
+ * Converts a JSX prop name back to the HTML or SVG attribute it stands for, the inverse of
+ * {@link htmlToJsxProp}.  Props that aren't HTML attributes or event handlers are hyphenated as SVG
+ * presentation attributes, unless SVG spells them in camelCase.
+ * ```
+ * assert("class" === jsxToHtmlAttribute("className"));
+ * assert("tabindex" === jsxToHtmlAttribute("tabIndex"));
+ * assert("stroke-width" === jsxToHtmlAttribute("strokeWidth"));
+ * assert("viewBox" === jsxToHtmlAttribute("viewBox"));
+ * ```
+ */
+function jsxToHtmlAttribute(name: string): string
+{
+    return rsBind.jsxToHtmlAttribute(name);
+}
+
+/**
+ * Renames the attributes of every tag in an HTML string to their JSX props, as
+ * {@link htmlToJsxProp} does.  Everything else is left untouched, as with
+ * {@link convertHtmlAttributes}; attribute values, including `style` strings, are not converted.
+ * ```
+ * assert('<label htmlFor="name" className="x">' === htmlAttributesToJsx('<label for="name" class="x">'));
+ * ```
+ */
+function htmlAttributesToJsx(html: string): string
+{
+    return rsBind.htmlAttributesToJsx(html);
+}
+
+/**
+ * Converts the names of the fields and field arguments of every object, interface, and input type
+ * in a GraphQL SDL document, including `extend type` blocks.  Type names, enum values, directives,
+ * default values, descriptions, and comments are left untouched.  Throws an `Error` if the
+ * document can't be tokenized.
+ *
+ * Set `fieldMap` to also get the new name of every field, grouped by type, for wiring up
+ * resolvers written against the old names.
+ * ```
+ * // type User { first_name(format_as: String): String }
+ * convertGraphqlFields("type User { firstName(formatAs: String): String }", Case.Snake);
+ *
+ * // {sdl: "type User { userId: ID }", fieldMap: {User: {user_id: "userId"}}}
+ * convertGraphqlFields("type User { user_id: ID }", Case.Camel, {fieldMap: true});
+ * ```
+ */
+function convertGraphqlFields(sdl: string, caseType: Case, options: {fieldMap: true}): {sdl: string, fieldMap: Record<string, Record<string, string>>};
+function convertGraphqlFields(sdl: string, caseType: Case, options?: {fieldMap?: false}): string;
+function convertGraphqlFields(sdl: string, caseType: Case, options: {fieldMap?: boolean} = {}): string | {sdl: string, fieldMap: Record<string, Record<string, string>>}
+{
+    assert(caseType in Case);
+    return rsBind.convertGraphqlFields(sdl, caseType, options);
+}
+
+/**
+ * Converts the column and constraint names in the `CREATE TABLE` and `ALTER TABLE` statements of a
+ * SQL script.  Quoted identifiers are kept exactly as written, and so are table names, types,
+ * keywords, string literals, comments, and every other statement.  Columns referenced by `CHECK`,
+ * `DEFAULT`, and generated column expressions are converted along with their definitions, and a
+ * converted name that would be a reserved word, such as `order`, is double-quoted.
+ *
+ * Throws an `Error` if a string, quoted identifier, or comment is never closed.
+ * ```
+ * // CREATE TABLE users (user_id INT PRIMARY KEY, "displayName" TEXT, CONSTRAINT users_user_id_check CHECK (user_id > 0));
+ * convertDdlIdentifiers('CREATE TABLE users (userId INT PRIMARY KEY, "displayName" TEXT, CONSTRAINT usersUserIdCheck CHECK (userId > 0));', Case.Snake);
+ * ```
+ */
+function convertDdlIdentifiers(sql: string, caseType: Case): string
+{
+    assert(caseType in Case);
+    return rsBind.convertDdlIdentifiers(sql, caseType);
+}
+
+/**
+ * Derives the JSON name protoc gives a protobuf field, byte for byte.  This is not camel case:
+ * protoc only drops each underscore and uppercases the letter after it, so capitals are kept and
+ * a digit after an underscore doesn't start a new word.  Use {@link Case.Camel} for ordinary
+ * camelization and this when the result has to match generated code or `json_name` defaults.
+ * ```
+ * assert("fooBar" === protoJsonName("foo_bar"));
+ * assert("field1Name" === protoJsonName("field_1_name"));
+ * assert("foo1bar" === protoJsonName("foo_1bar"));
+ * assert("FOOBar" === protoJsonName("FOO_bar"));
+ * ```
+ */
+function protoJsonName(field: string): string
+{
+    return rsBind.protoJsonName(field);
+}
+
+/**
+ * Recovers the snake_case field name from a protobuf JSON name, the inverse protobuf itself uses
+ * for field masks: each capital becomes an underscore and its lowercase letter.  This round-trips
+ * {@link protoJsonName} for any field made of lowercase words that each start with a letter.
+ *
+ * Throws an `Error` if `jsonName` contains an underscore, since no JSON name can.
+ * ```
+ * assert("foo_bar" === protoFieldName("fooBar"));
+ * assert("field1_name" === protoFieldName("field1Name"));
+ * ```
+ */
+function protoFieldName(jsonName: string): string
+{
+    return rsBind.protoFieldName(jsonName);
+}
+
+/**
+ * A Kubernetes resource name produced by {@link toK8sName}, along with whether words were dropped
+ * or cut to fit the 63 character limit.
+ */
+type K8sName = {name: string, truncated: boolean};
+
+/**
+ * Converts any string to a name Kubernetes accepts for resources and label values: lowercase
+ * kebab case made of ASCII letters and digits, starting and ending with one, and at most 63
+ * characters long.  Punctuation and whitespace separate words, and other characters, such as
+ * accented letters, are dropped.  Names that are too long lose whole words from the end, so they
+ * never end in a partial word unless the first word alone is over the limit.
+ *
+ * Throws an `Error` if `str` has no ASCII letters or digits to build a name from.
+ * ```
+ * // {name: "payment-api-v2-worker", truncated: false}
+ * toK8sName("PaymentAPI v2Worker");
+ * // {name: "my-app-staging", truncated: false}
+ * toK8sName("my_app.Staging!");
+ * ```
+ */
+function toK8sName(str: string): K8sName
+{
+    return rsBind.toK8sName(str);
+}
+
+/**
+ * Options for {@link toResourceName}.
+ *
+ * * `case`: the case of the name, {@link Case.Kebab} by default.
+ * * `maxLength`: the longest name allowed, in characters.  Longer names lose whole words from the
+ * end, and only a first word that is too long on its own is cut.
+ * * `charset`: the characters a name may contain, written like a regular expression character
+ * class without the brackets, such as `"a-z0-9-"`.  Other characters are dropped.  By default
+ * names may contain ASCII letters, digits, and the delimiter of `case`.
+ * * `hashSuffixOnTruncate`: append a six digit hexadecimal hash of the full name, joined by the
+ * delimiter of `case`, to names that had to be truncated, so that long names sharing a prefix
+ * stay distinct.  The hash is counted against `maxLength`, and is the same on every platform and
+ * release.
+ */
+type ResourceNameOptions = {
+    case?: Case,
+    maxLength?: number,
+    charset?: string,
+    hashSuffixOnTruncate?: boolean,
+};
+
+/**
+ * Converts any string to a name that follows a cloud provider's naming rules, such as the 63
+ * lowercase letters, digits, and hyphens of a GCP resource or the 24 lowercase letters and digits
+ * of an Azure storage account.  See {@link ResourceNameOptions}.
+ *
+ * Throws a `RangeError` if the options can't be satisfied, such as a `charset` that lacks the
+ * delimiter of `case` or a `maxLength` with no room for the hash, and an `Error` if none of the
+ * characters of `str` are allowed.
+ * ```
+ * assert("orders-service-prod" === toResourceName("OrdersService (prod)"));
+ * assert("ordersserviceprod" === toResourceName("OrdersService (prod)", {case: Case.Flat, charset: "a-z0-9"}));
+ * // "orders-service-production-" followed by a hash, 32 characters in all
+ * toResourceName("Orders Service Production East US", {maxLength: 32, hashSuffixOnTruncate: true});
+ * ```
+ */
+function toResourceName(str: string, options: ResourceNameOptions = {}): string
+{
+    assert(options.case === undefined || options.case in Case);
+    return rsBind.toResourceName(str, options);
+}
+
+/**
+ * Renames the schema properties and the query, cookie, and form parameters of an OpenAPI or
+ * Swagger document given as JSON.  Property names change wherever a schema declares or lists
+ * them: `properties`, `required`, `discriminator.propertyName`, and the `encoding` of a media
+ * type, including inline schemas of parameters, request bodies, and responses.
+ *
+ * Nothing else is touched, so the document stays consistent: `$ref` targets and component names,
+ * path templates and their path parameters, header names, enum values, defaults, examples, and
+ * `x-` extensions keep their spelling.  Like {@link convertJsonKeys}, it throws an `Error` if
+ * `json` isn't valid JSON, and `pretty` pretty-prints the result.
+ * ```
+ * // {"components":{"schemas":{"User":{"required":["user_id"],"properties":{"user_id":{"type":"string"}}}}}}
+ * convertOpenApiProperties('{"components":{"schemas":{"User":{"required":["userId"],"properties":{"userId":{"type":"string"}}}}}}', Case.Snake);
+ * ```
+ */
+function convertOpenApiProperties(json: string, caseType: Case, options: {pretty?: boolean} = {}): string
+{
+    assert(caseType in Case);
+    return rsBind.convertOpenApiProperties(json, caseType, options);
+}
+
+/**
+ * A plan to rename files, as returned by {@link planRenames}.  Carrying out `steps` in order never
+ * renames a file onto one that hasn't moved out of the way yet, even on file systems that ignore
+ * case.  `collisions` lists files whose new names would clash, ignoring case, with each other or
+ * with a file that keeps its name; none of them appear in `steps`.
+ */
+type RenamePlan = {
+    steps: {from: string, to: string}[],
+    collisions: {target: string, sources: string[]}[],
+};
+
+/**
+ * Plans how to rename files so that their names are in the given case.  Directories are kept as
+ * they are, with either `/` or `\` as separators, and only the stem of each file name is
+ * converted, so extensions and leading dots stay.
+ *
+ * Paths are compared the way macOS and Windows compare them, ignoring case.  Files that would end
+ * up with the same name are left alone and reported as {@link RenamePlan} collisions instead.
+ * Renames that have to wait for another file to move out of the way come after it, and cycles of
+ * renames, as well as renames that only change case, go through a temporary `.renaming` name.
+ * ```
+ * // {steps: [{from: "docs/userGuide.md", to: "docs/user_guide.md"}],
+ * //  collisions: [{target: "a_b.txt", sources: ["aB.txt", "a_b.txt"]}]}
+ * planRenames(["docs/userGuide.md", "aB.txt", "a_b.txt"], Case.Snake);
+ * ```
+ */
+function planRenames(filenames: string[], caseType: Case): RenamePlan
+{
+    assert(caseType in Case);
+    return rsBind.planRenames(filenames, caseType);
+}
+
+/**
+ * A package registry whose naming rules {@link toPackageName} follows.
+ */
+type PackageRegistry = "npm" | "crates" | "pypi";
+
+/**
+ * Derives a valid package name from a human-entered title, following the rules of `registry`
+ * (`"npm"` by default):
+ *
+ * * `"npm"`: lowercase kebab case, at most 214 characters.  A title of the form `@scope/name`
+ * keeps its scope, converted the same way, and the scope counts towards the limit.
+ * * `"crates"`: snake case, at most 64 characters, starting with a letter and not a Rust keyword
+ * or standard crate such as `std`.
+ * * `"pypi"`: lowercase kebab case, the normalized form PyPI compares names by.
+ *
+ * Words are split at punctuation, whitespace, and changes of case, but never before a digit, so
+ * `Web3` stays one word.  Characters other than ASCII letters and digits are dropped, and names
+ * that are too long lose whole words from the end.  Throws an `Error` if no letters or digits are
+ * left or the result breaks a rule that dropping characters can't fix, such as a crate name
+ * starting with a digit, and a `RangeError` for an unknown registry.
+ * ```
+ * assert("my-cool-project" === toPackageName("My Cool Project!"));
+ * assert("@acme-corp/web3-utils" === toPackageName("@Acme Corp/Web3 Utils"));
+ * assert("my_cool_project" === toPackageName("My Cool Project!", {registry: "crates"}));
+ * ```
+ */
+function toPackageName(str: string, options: {registry?: PackageRegistry} = {}): string
+{
+    return rsBind.toPackageName(str, options);
+}
+
+/**
+ * Converts any string to a SCREAMING_SNAKE_CASE name for a C preprocessor macro.  Every character
+ * other than an ASCII letter or digit separates words, except non-ASCII characters, which are
+ * dropped, and runs of separators become a single underscore.  Words also split at changes of
+ * case, but never before a digit.  The result never starts or ends with an underscore, since
+ * such names are reserved, and gets an `X_` prefix if it would start with a digit.
+ *
+ * Throws an `Error` if `str` has no ASCII letters or digits.
+ * ```
+ * assert("MAX_BUFFER_SIZE" === toMacroName("maxBufferSize"));
+ * assert("SHA256_SUM" === toMacroName("sha256Sum"));
+ * assert("X_3D_ENABLED" === toMacroName("3d-enabled"));
+ * ```
+ */
+function toMacroName(str: string): string
+{
+    return rsBind.toMacroName(str);
+}
+
+/**
+ * Derives the include guard for a header from its path, as {@link toMacroName} would convert
+ * the whole path.  Drive letters and `.` and `..` segments are dropped first, so the guard
+ * doesn't depend on where the path is relative to.
+ *
+ * Throws an `Error` if `path` has no ASCII letters or digits.
+ * ```
+ * assert("INCLUDE_MY_LIB_HTTP_CLIENT_H" === toIncludeGuard("include/my-lib/httpClient.h"));
+ * assert("SRC_UTIL_H" === toIncludeGuard("../src//util.h"));
+ * ```
+ */
+function toIncludeGuard(path: string): string
+{
+    return rsBind.toIncludeGuard(path);
+}
+
+/**
+ * A conversion configured with the boundaries words are split on, the pattern the words are
+ * written in, and the delimiter they are joined with, like `convert_case`'s `Converter`.  Calling
+ * `convert` is the same as calling {@link CaseString.mutate} with these options.
+ */
+class Converter
+{
+    readonly boundaries: Boundary[];
+    readonly pattern: Pattern;
+    readonly delim: string;
+
+    constructor(options: {boundaries: Boundary[], pattern: Pattern, delim: string})
+    {
+        for (const boundary of options.boundaries)
+        {
+            assert(boundary in Boundary);
+        }
+        assert(options.pattern in Pattern);
+
+        this.boundaries = options.boundaries;
+        this.pattern = options.pattern;
+        this.delim = options.delim;
+    }
+
+    convert(str: string): string
+    {
+        return rsBind.mutate(str, this);
+    }
+}
+
+/**
+ * The identifier conventions {@link preset} knows, as `language.kind`.
+ */
+type PresetName =
+    | "rust.struct" | "rust.enum" | "rust.variant" | "rust.trait" | "rust.type" | "rust.function" | "rust.method"
+    | "rust.variable" | "rust.field" | "rust.module" | "rust.constant" | "rust.static"
+    | "java.class" | "java.interface" | "java.method" | "java.variable" | "java.field" | "java.constant" | "java.package"
+    | "python.class" | "python.function" | "python.method" | "python.variable" | "python.constant" | "python.module"
+    | "python.package"
+    | "javascript.class" | "javascript.function" | "javascript.variable" | "javascript.constant";
+
+/**
+ * Returns a {@link Converter} that writes identifiers the way a language's style guide asks for
+ * that kind of identifier: the Rust API guidelines, the Google Java style guide, PEP 8, and the
+ * Google JavaScript style guide.  Acronyms are written as ordinary words, as all of these guides
+ * ask, and digits stay with the word before them instead of becoming words of their own.
+ *
+ * Throws a `RangeError` for an unknown preset.
+ * ```
+ * assert("HttpServer" === preset("rust.struct").convert("HTTP server"));
+ * assert("MAX_RETRY_COUNT" === preset("java.constant").convert("maxRetryCount"));
+ * assert("utf8_decoder" === preset("python.module").convert("Utf8Decoder"));
+ * assert("mypackage" === preset("java.package").convert("my-package"));
+ * ```
+ */
+function preset(name: PresetName): Converter
+{
+    return new Converter(rsBind.preset(name));
+}
+
+/**
+ * A language whose accessor naming convention {@link toGetterName}, {@link toSetterName}, and
+ * {@link fieldFromAccessor} follow.
+ */
+type AccessorLang = "java" | "csharp" | "javascript";
+
+/**
+ * Derives the getter name of a field under the conventions of `lang` (`"java"` by default).  A
+ * leading `_`, `m_`, or `#` marking a private member is dropped first.
+ *
+ * * `"java"`: JavaBeans `getFoo`, or `isFoo` with `boolean` set.  A field whose second letter is
+ * a capital keeps its first letter as it is, so `xCoordinate` gets `getxCoordinate`, since
+ * `java.beans.Introspector` would read `getXCoordinate` as the property `XCoordinate`.
+ * * `"csharp"`: the PascalCase property name, `Foo`, which serves as getter and setter alike.
+ * * `"javascript"`: accessor methods `getFoo` and `isFoo`.
+ *
+ * A boolean field that already starts with `is` followed by a capital is its own getter.
+ * ```
+ * assert("getFirstName" === toGetterName("firstName"));
+ * assert("isActive" === toGetterName("active", {boolean: true}));
+ * assert("isActive" === toGetterName("isActive", {boolean: true}));
+ * assert("getURL" === toGetterName("URL"));
+ * assert("FirstName" === toGetterName("_firstName", {lang: "csharp"}));
+ * ```
+ */
+function toGetterName(field: string, options: {lang?: AccessorLang, boolean?: boolean} = {}): string
+{
+    return rsBind.toGetterName(field, options);
+}
+
+/**
+ * Derives the setter name of a field, the counterpart of {@link toGetterName}.  The setter of a
+ * boolean field named like `isActive` is `setActive`, matching its getter `isActive`.
+ * ```
+ * assert("setFirstName" === toSetterName("firstName"));
+ * assert("setActive" === toSetterName("isActive", {boolean: true}));
+ * assert("FirstName" === toSetterName("firstName", {lang: "csharp"}));
+ * ```
+ */
+function toSetterName(field: string, options: {lang?: AccessorLang, boolean?: boolean} = {}): string
+{
+    return rsBind.toSetterName(field, options);
+}
+
+/**
+ * Recovers the field name from a getter or setter name, or returns `null` if `name` isn't one.
+ *
+ * * `"java"`: strips `get`, `set`, or `is` and decapitalizes the rest the way
+ * `java.beans.Introspector` does, so `getURLValue` gives `URLValue`, since a property starting
+ * with two capitals keeps both.
+ * * `"csharp"`: strips an optional `get_` or `set_` and camelizes the property name the way .NET
+ * JSON serializers do, lowercasing a leading acronym: `URLValue` gives `urlValue`.
+ * * `"javascript"`: strips `get`, `set`, or `is` and camelizes like `"csharp"`.
+ * ```
+ * assert("firstName" === fieldFromAccessor("getFirstName"));
+ * assert("URLValue" === fieldFromAccessor("getURLValue"));
+ * assert("urlValue" === fieldFromAccessor("getURLValue", {lang: "javascript"}));
+ * assert(null === fieldFromAccessor("isolate"));
+ * ```
+ */
+function fieldFromAccessor(name: string, options: {lang?: AccessorLang} = {}): string | null
+{
+    return rsBind.fieldFromAccessor(name, options);
+}
+
+/**
+ * What {@link setTraceCallback} reports about a conversion: how a string was split into words,
+ * with every option that changes the words applied, that the result came from the cache
+ * {@link setCacheSize} turns on, or that a function had to do something other than a plain
+ * conversion, such as truncating.
+ */
+type TraceEvent =
+    | {kind: "split", input: string, words: string[]}
+    | {kind: "cacheHit", input: string, result: string}
+    | {kind: "fallback", function: string, input: string, reason: string};
+
+/**
+ * Calls `callback` with each {@link TraceEvent} of every later conversion, for debugging output
+ * that isn't what you expected.  The events of a call are reported just before it returns.
+ * Tracing costs nothing until a callback is set; pass `null` to turn it off again.
+ * ```
+ * setTraceCallback(event => console.log(event));
+ * // {kind: "split", input: "XMLHttpRequest", words: ["XML", "Http", "Request"]}
+ * CS("XMLHttpRequest").toCase(Case.Snake);
+ * setTraceCallback(null);
+ * ```
+ */
+function setTraceCallback(callback: ((event: TraceEvent) => void) | null): void
+{
+    rsBind.setTraceCallback(callback ?? undefined);
+}
+
+/**
+ * A range of the numeric ids of an enum, both ends included.
+ */
+type IdRange = {min: number, max: number};
+
+/**
+ * What {@link nativeInfo} reports about the native library.
+ */
+type NativeInfo = {
+    /** The version of the native library. */
+    version: string,
+    /** The version of the `convert_case` crate it is built on. */
+    convertCaseVersion: string,
+    /** The cargo features it was built with; `"random"` means the random cases and patterns work. */
+    features: string[],
+    /** The version of the Unicode data letters are classified and cased with. */
+    unicodeVersion: string,
+    caseIds: IdRange,
+    patternIds: IdRange,
+    boundaryIds: IdRange,
+};
+
+/**
+ * Describes the native library this module is running on, so callers can check for a feature
+ * instead of calling a function to see whether it throws.
+ * ```
+ * if (nativeInfo().features.includes("yaml"))
+ * {
+ *     convertYamlKeys(text, Case.Snake);
+ * }
+ * ```
+ */
+function nativeInfo(): NativeInfo
+{
+    return rsBind.nativeInfo();
+}
+
+/**
+ * An identifier that doesn't follow the convention {@link caseConsistencyReport} inferred.
+ * `cases` lists the conventions it does follow, which is none if it mixes several, and
+ * `suggestion` is the identifier converted to the inferred convention.  `mixed` says how the
+ * identifier mixes conventions within itself, as {@link findMixedConventions} would, or is `null`
+ * if it is consistently in some other case, which usually calls for a different fix.
+ */
+type CaseOutlier = {identifier: string, cases: Case[], mixed: string | null, suggestion: string};
+
+/**
+ * How consistently a set of identifiers follows one convention, as returned by
+ * {@link caseConsistencyReport}.  `counts` says how many identifiers follow each convention, most
+ * followed first, and `convention` is the one they were most likely meant to follow, or `null` if
+ * none of them follow any.
+ */
+type CaseConsistencyReport = {
+    counts: {case: Case, count: number}[],
+    convention: Case | null,
+    outliers: CaseOutlier[],
+};
+
+/**
+ * Infers the naming convention a set of identifiers was meant to follow and lists the
+ * identifiers that don't follow it.  The conventions counted are camel, Pascal, snake, upper
+ * snake, kebab, cobol, train, flat, and upper flat case.
+ *
+ * Single words are in several of those at once, so each counts towards all of them.  Ties between
+ * conventions go to the one more identifiers of several words follow, and after that to the
+ * first in the list above.  Which conventions an identifier follows is checked with `options`, as
+ * {@link CaseString.isCase} checks them, and suggestions keep delimiters at the identifier's
+ * edges only if `options.edgeDelimiters` is `"preserve"`.
+ * ```
+ * // {counts: [{case: Case.Camel, count: 3}, ...], convention: Case.Camel,
+ * //  outliers: [{identifier: "created_at", cases: [Case.Snake], mixed: null, suggestion: "createdAt"}]}
+ * caseConsistencyReport(["userId", "name", "createdAt", "created_at"]);
+ * ```
+ */
+function caseConsistencyReport(identifiers: string[], options: CaseOptions = {}): CaseConsistencyReport
+{
+    return rsBind.caseConsistencyReport(identifiers, options);
+}
+
+/**
+ * One way an identifier reported by {@link lintIdentifiers} departs from the expected case:
+ * a boundary between words the case doesn't have, described by `message`, or, with a `null`
+ * `boundary`, letters cased the wrong way.
+ */
+type LintProblem = {boundary: Boundary | null, message: string};
+
+/**
+ * An identifier that isn't in the case {@link lintIdentifiers} expected.  `index` is its position
+ * in the list that was linted and `fix` is the identifier converted to the expected case.  The
+ * `severity` is `"error"` if the identifier splits into words differently than the expected case
+ * would, and `"warning"` if only the casing of its letters is wrong.
+ */
+type LintViolation = {
+    index: number,
+    identifier: string,
+    severity: "error" | "warning",
+    problems: LintProblem[],
+    fix: string,
+};
+
+/**
+ * Checks that every identifier is in `expectedCase`, reporting each one that isn't with the
+ * boundaries that make it wrong and the name it should have, so that a lint rule can show the
+ * problem and offer the fix.
+ *
+ * Identifiers in `options.allow` are never reported, and with `options.allowLeadingUnderscore`,
+ * underscores at the start of an identifier, as in `_private`, are ignored and kept by the fix.
+ * ```
+ * // [{index: 1, identifier: "userName", severity: "error",
+ * //   problems: [{boundary: Boundary.LowerUpper, message: "contains uppercase after lowercase"}],
+ * //   fix: "user_name"}]
+ * lintIdentifiers(["user_id", "userName"], Case.Snake);
+ * ```
+ */
+function lintIdentifiers(identifiers: string[], expectedCase: Case, options: {allow?: string[], allowLeadingUnderscore?: boolean} = {}): LintViolation[]
+{
+    assert(expectedCase in Case);
+    return rsBind.lintIdentifiers(identifiers, expectedCase, options);
+}
+
+/**
+ * An identifier {@link findMixedConventions} found, with its position in the list that was
+ * searched and how it mixes conventions.
+ */
+type MixedIdentifier = {index: number, identifier: string, reason: string};
+
+/**
+ * Finds the identifiers that mix conventions within themselves, like `user_Id` or
+ * `HTTP_server_Count`, leaving out those that consistently follow some other convention.  The two
+ * need different fixes: an identifier in another case can be converted as a whole, while a mixed
+ * one usually needs someone to decide what its words are.
+ *
+ * An identifier mixes conventions if it separates words with more than one of `_`, `-`, and
+ * space, if it separates some words with a delimiter and others with capital letters, as
+ * `user_firstName` does, or if its delimited words aren't all cased the same way.  Identifiers
+ * without delimiters can't mix conventions, and acronyms in them, as in `HTTPServer`, are taken
+ * to be deliberate.
+ * ```
+ * // [{index: 1, identifier: "user_Id", reason: "mixes lowercase and capitalized words"}]
+ * findMixedConventions(["userId", "user_Id", "USER_ID"]);
+ * ```
+ */
+function findMixedConventions(identifiers: string[]): MixedIdentifier[]
+{
+    return rsBind.findMixedConventions(identifiers);
+}
+
+/**
+ * Converts a whole set of identifiers to the given case at once, returning the new name of each
+ * distinct identifier, so that a codemod renaming them all never merges two of them.
+ *
+ * The map depends only on which identifiers are in the set, not on their order or on duplicates,
+ * so repeated runs produce the same renames, and its entries are sorted by the old name.
+ * Identifiers that convert to the same name are settled by sorting them by code point: one that
+ * is already spelled like the name keeps it, or else the one that sorts first does, and the
+ * others, in sorted order, get the first free name out of the name followed by the case's
+ * delimiter and `2`, `3`, ...
+ * ```
+ * // Map {"USER_ID" => "user_id_2", "user-id" => "user_id_3", "userName" => "user_name", "user_id" => "user_id"}
+ * buildRenameMap(["userName", "user-id", "user_id", "USER_ID"], Case.Snake);
+ * ```
+ */
+function buildRenameMap(identifiers: string[], caseType: Case): Map<string, string>
+{
+    assert(caseType in Case);
+    return new Map(rsBind.buildRenameMap(identifiers, caseType));
+}
+
+/**
+ * Where {@link findIdentifier} found an identifier: `text.slice(start, end)` is `text`, and
+ * `cases` lists the conventions it is written in there, which is several for a single word and
+ * none where it is written in a mix of cases.
+ */
+type IdentifierOccurrence = {start: number, end: number, text: string, cases: Case[]};
+
+/**
+ * Finds every occurrence of an identifier in text, in any case it could be written in, so that
+ * renaming it can reach every file that spells it differently.
+ *
+ * An occurrence is the words of `name`, in the same order and next to each other, within a run of
+ * letters, digits, `_`, and `-`.  Words are split the same way converting splits them and are
+ * compared ignoring case, so `userId` finds `user_id`, `USER_ID`, `user-id`, and also the `UserId`
+ * in `getUserId`.  Occurrences don't overlap and come in the order they appear.
+ * ```
+ * // [{start: 0, end: 7, text: "user_id", cases: [Case.Snake]},
+ * //  {start: 11, end: 17, text: "UserId", cases: [Case.Pascal]}]
+ * findIdentifier("user_id = getUserId();", "userId");
+ * ```
+ */
+function findIdentifier(text: string, name: string): IdentifierOccurrence[]
+{
+    return rsBind.findIdentifier(text, name);
+}
+
+/**
+ * A candidate found by {@link matchIdentifiers}, with its position in the list of candidates and
+ * how well it matched, from 0 to 1.
+ */
+type IdentifierMatch = {candidate: string, index: number, score: number};
+
+/**
+ * Scores candidates against a query by their words instead of their spelling, so that matches
+ * surface whatever case either is written in.  Both are split into words the way converting splits
+ * them, and a query word matches a candidate word it equals, starts, or abbreviates, so `usrId`
+ * finds `user_id` and `UserID`.
+ *
+ * A candidate scores 1 for the same words in the same order, less for abbreviated words, words in
+ * a different order, or extra words on either side, and 0 for no words in common.  Candidates
+ * that score 0 or under `options.minScore` are left out, and the rest come best first, tied ones in
+ * their original order, up to `options.limit` of them.
+ * ```
+ * // [{candidate: "user_id", index: 1, score: 0.8125}, {candidate: "UserID", index: 2, score: 0.8125},
+ * //  {candidate: "groupId", index: 3, score: 0.5}, {candidate: "userName", index: 0, score: 0.3125}]
+ * matchIdentifiers("usrId", ["userName", "user_id", "UserID", "groupId"]);
+ * ```
+ */
+function matchIdentifiers(query: string, candidates: string[], options: {limit?: number, minScore?: number} = {}): IdentifierMatch[]
+{
+    if (options.limit !== undefined) assert(options.limit >= 0);
+    return rsBind.matchIdentifiers(query, candidates, options);
+}
+
+/**
+ * The kinds of characters {@link segmentationStats} reports.  `"delimiter"` is `_`, `-`, and
+ * space, `"uncased"` is letters without case, as in most scripts other than Latin, Greek, and
+ * Cyrillic, and `"other"` is everything else, such as punctuation.
+ */
+type CharacterClass = "lowercase" | "uppercase" | "digit" | "delimiter" | "uncased" | "other";
+
+/**
+ * How {@link segmentationStats} split one string: its words, the kinds of characters in it, and
+ * how many times each boundary fired, for those that did, in the order of `Boundary`.
+ */
+type SegmentationStats = {
+    input: string,
+    wordCount: number,
+    words: string[],
+    characterClasses: CharacterClass[],
+    boundaries: {boundary: Boundary, count: number}[],
+};
+
+/**
+ * Splits every string into words and reports what the split found, for characterizing how
+ * complex the names of a codebase are or tuning a custom set of boundaries.  The strings are
+ * split on `options.boundaries`, or on the boundaries converting uses by default.
+ *
+ * A boundary fires wherever splitting would split on it, so one that only fires where another
+ * already did, as `Boundary.UpperDigit` and `Boundary.Acronym` can, is still counted there.
+ * ```
+ * // [{input: "parseHTTPResponse2", wordCount: 4, words: ["parse", "HTTP", "Response", "2"],
+ * //   characterClasses: ["lowercase", "uppercase", "digit"],
+ * //   boundaries: [{boundary: Boundary.LowerUpper, count: 1}, {boundary: Boundary.LowerDigit, count: 1},
+ * //                {boundary: Boundary.Acronym, count: 1}]}]
+ * segmentationStats(["parseHTTPResponse2"]);
+ * ```
+ */
+function segmentationStats(inputs: string[], options: {boundaries?: Boundary[]} = {}): SegmentationStats[]
+{
+    if (options.boundaries !== undefined)
+    {
+        for (let i = 0; i < options.boundaries.length; i++)
+        {
+            assert(options.boundaries[i] in Boundary);
+        }
+    }
+
+    return rsBind.segmentationStats(inputs, options);
+}
+
+/**
+ * A way {@link isReversible} found a round trip to lose information.  The `kind` is
+ * `"delimiters"` for `_`, `-`, or spaces that don't separate words, as in `_private`, which
+ * converting drops; `"acronym"` for an all-uppercase word that comes back with only its first
+ * letter capitalized; `"casing"` for any other word whose letters come back cased differently;
+ * `"digits"` for words with digits that come back split or merged differently; and
+ * `"boundaries"` for other words that come back split or merged differently, as converting to
+ * `Case.Flat` merges all of them.
+ */
+type ReversibilityLoss = {
+    kind: "delimiters" | "acronym" | "casing" | "digits" | "boundaries",
+    message: string,
+};
+
+/**
+ * What {@link isReversible} found converting a string and back: `forward` is the string in the
+ * target case and `back` is `forward` converted back to the original case.
+ */
+type Reversibility = {
+    reversible: boolean,
+    forward: string,
+    back: string,
+    losses: ReversibilityLoss[],
+};
+
+/**
+ * Converts `str` from `fromCase` to `toCase` and back, reporting whether `str` is recovered and,
+ * if it isn't, every way it was lost, so that a rename that can't be undone can be refused.
+ * ```
+ * // {reversible: false, forward: "get_http_response", back: "getHttpResponse",
+ * //  losses: [{kind: "acronym", message: "`HTTP` comes back as `Http`"}]}
+ * isReversible("getHTTPResponse", Case.Camel, Case.Snake);
+ * ```
+ */
+function isReversible(str: string, fromCase: Case, toCase: Case): Reversibility
+{
+    assert(fromCase in Case);
+    assert(toCase in Case);
+    return rsBind.isReversible(str, fromCase, toCase);
+}
+
+/**
+ * Identifiers {@link groupBySignature} found to have the same words, which are given in
+ * lowercase.  The identifiers are distinct and in the order they first appear.
+ */
+type SignatureGroup = {words: string[], identifiers: string[]};
+
+/**
+ * Groups identifiers that have the same words in the same order, whatever case they are written
+ * in, so that the same concept spelled in different conventions can be found.  Groups come in
+ * the order their first identifier appears and include identifiers that are alone in theirs;
+ * identifiers without any words are left out.
+ * ```
+ * // [{words: ["user", "id"], identifiers: ["userId", "user_id", "USER_ID"]},
+ * //  {words: ["group", "id"], identifiers: ["groupId"]}]
+ * groupBySignature(["userId", "user_id", "groupId", "USER_ID"]);
+ * ```
+ */
+function groupBySignature(identifiers: string[]): SignatureGroup[]
+{
+    return rsBind.groupBySignature(identifiers);
+}
+
+/**
+ * An acronym {@link discoverAcronyms} found, with how many times it is written in uppercase and
+ * the fraction of the places its casing shows that it is.
+ */
+type DiscoveredAcronym = {acronym: string, uses: number, consistency: number};
+
+/**
+ * Finds words that the identifiers consistently write in uppercase, as `HTTP` is in `HTTPServer`
+ * and `getHTTPResponse`, to suggest which acronyms a codebase preserves.
+ *
+ * Only words whose casing is a choice count: identifiers without both lowercase and uppercase
+ * letters are skipped, as is a lowercase first word, which camel case lowercases anyway.  A word
+ * of two or more letters is suggested if it is written in uppercase at least `options.minUses`
+ * times, 2 by default, and in at least `options.minConsistency` of the places its casing shows,
+ * 0.75 by default, so an occasional `ID` doesn't outweigh `Id` everywhere else.  Acronyms come
+ * most used first, and in alphabetical order when tied.
+ * ```
+ * // [{acronym: "HTTP", uses: 2, consistency: 1}]
+ * discoverAcronyms(["HTTPServer", "getHTTPResponse", "userId", "groupId", "parseID"]);
+ * ```
+ */
+function discoverAcronyms(identifiers: string[], options: {minUses?: number, minConsistency?: number} = {}): DiscoveredAcronym[]
+{
+    return rsBind.discoverAcronyms(identifiers, options);
+}
+
+/**
+ * One step of a {@link diffIdentifiers} diff.  `from` is the word as the first identifier spells
+ * it and `to` as the second does, and either is `null` if the word was added or removed.
+ */
+type WordDiff = {
+    change: "same" | "added" | "removed" | "changed",
+    from: string | null,
+    to: string | null,
+};
+
+/**
+ * Diffs the words of two identifiers, ignoring case, for showing what a rename changed rather
+ * than which characters it did.
+ *
+ * The words both have are the longest sequence common to them.  Between two of those, words of
+ * `a` and `b` are paired up in order as changed, and any left over on one side are removed or
+ * added.
+ * ```
+ * // [{change: "same", from: "get", to: "get"}, {change: "same", from: "User", to: "user"},
+ * //  {change: "added", from: null, to: "full"}, {change: "same", from: "Name", to: "name"}]
+ * diffIdentifiers("getUserName", "get_user_full_name");
+ * ```
+ */
+function diffIdentifiers(a: string, b: string): WordDiff[]
+{
+    return rsBind.diffIdentifiers(a, b);
+}
+
+/**
+ * Picks which of the cases `str` is in it is meant to be in, by which of them the `context`
+ * identifiers follow most, as {@link caseConsistencyReport} ranks them.  A single word like
+ * `"name"` is in camel, snake, kebab, and flat case at once, so the context decides between
+ * them.  A string in only one case gets that one whatever the context, and one in cases the
+ * context doesn't follow gets the first of its own.  Returns `null` if `str` follows none of the
+ * conventions.
+ * ```
+ * // Case.Snake
+ * suggestCase("name", ["user_id", "created_at", "id"]);
+ * ```
+ */
+function suggestCase(str: string, context: string[]): Case | null
+{
+    return rsBind.suggestCase(str, context);
+}
+
+/**
+ * What {@link toCaseDetailed} found converting a string: the converted `result`, the `words` the
+ * string was split into as it writes them, the `boundaries` that split it, in the order of
+ * {@link Boundary.all}, and the first of the cases {@link caseConsistencyReport} counts that the
+ * string was in before converting, or `null` if it was in none.
+ */
+type CaseDetails = {
+    result: string,
+    words: string[],
+    boundaries: Boundary[],
+    fromCaseDetected: Case | null,
+};
+
+/**
+ * Converts `str` to `caseType` as {@link CaseString.toCase} does, also returning what splitting
+ * it found along the way, so callers that need the words or the boundaries don't split it again.
+ * A single word is in several cases at once, so `fromCaseDetected` is the first of them, camel
+ * case for `"name"`.
+ * ```
+ * // {result: "user_id_list", words: ["user", "Id", "List"],
+ * //  boundaries: [Boundary.LowerUpper], fromCaseDetected: Case.Camel}
+ * toCaseDetailed("userIdList", Case.Snake);
+ * ```
+ */
+function toCaseDetailed(str: string, caseType: Case, options: CaseOptions & {fromCase?: Case | "auto", pattern?: Pattern} = {}): CaseDetails
+{
+    assert(caseType in Case);
+    if (options.fromCase !== undefined && options.fromCase !== "auto") assert(options.fromCase in Case);
+    if (options.pattern !== undefined) assert(options.pattern in Pattern);
+
+    return rsBind.toCaseDetailed(str, caseType, options.fromCase, options);
+}
+
+/**
+ * Cases only the word at `index` of `str` with `pattern`, counting from the end if `index` is
+ * negative, so `-1` is the last word.  The rest of the string, delimiters and acronyms included,
+ * is left as it is, and the string is returned unchanged if it has no word at `index`.  The
+ * pattern is applied to the word on its own, so {@link Pattern.Camel} always lowercases it.
+ * ```
+ * // "getHTTPResponse"
+ * applyToWord("GetHTTPResponse", 0, Pattern.Lowercase);
+ * // "user_id_LIST"
+ * applyToWord("user_id_list", -1, Pattern.Uppercase);
+ * ```
+ */
+function applyToWord(str: string, index: number, pattern: Pattern): string
+{
+    assert(Number.isInteger(index));
+    assert(pattern in Pattern);
+    return rsBind.applyToWord(str, index, pattern);
+}
+
+/**
+ * Lowercases the first word of `str`, turning pascal case into camel case without touching the
+ * other words.  See {@link applyToWord}.
+ * ```
+ * // "httpServerURL"
+ * lowerFirstWord("HTTPServerURL");
+ * ```
+ */
+function lowerFirstWord(str: string): string
+{
+    return applyToWord(str, 0, Pattern.Lowercase);
+}
+
+/**
+ * Capitalizes the first word of `str`, turning camel case into pascal case without touching the
+ * other words.  See {@link applyToWord}.
+ * ```
+ * // "UserHTTPClient"
+ * capitalizeFirstWord("userHTTPClient");
+ * ```
+ */
+function capitalizeFirstWord(str: string): string
+{
+    return applyToWord(str, 0, Pattern.Capital);
+}
+
+/**
+ * Lists the reserved words of `language`, those that can't be used as identifiers in it.
+ * ```
+ * // true
+ * reservedWords("python").includes("lambda");
+ * ```
+ */
+function reservedWords(language: ReservedLanguage): string[]
+{
+    return rsBind.reservedWords(language);
+}
+
+/**
+ * Checks whether `word` is a reserved word of `language`.  Words are compared exactly, except in
+ * SQL, where case doesn't matter.
+ * ```
+ * // true
+ * isReservedWord("Order", "sql");
+ * ```
+ */
+function isReservedWord(word: string, language: ReservedLanguage): boolean
+{
+    return rsBind.isReservedWord(word, language);
+}
+
+/**
+ * Converts `str` to `options.case`, if it is given, and keeps as many of its words as fit in
+ * `maxLength`, with `options.ellipsis` after them if any were dropped, for names with a length
+ * limit like database columns.  Lengths are counted as `length` counts them, and words are never
+ * cut, so a string whose first word doesn't fit becomes `""`.  Without a case, the string is kept
+ * as it is up to the end of the last word that fits.
+ * ```
+ * // "customer_billing"
+ * truncateWords("customerBillingAddressLine", 20, {case: Case.Snake});
+ * // "customer_billing…"
+ * truncateWords("customerBillingAddressLine", 20, {case: Case.Snake, ellipsis: "…"});
+ * ```
+ */
+function truncateWords(str: string, maxLength: number, options: {case?: Case, ellipsis?: string} = {}): string
+{
+    assert(maxLength >= 0);
+    if (options.case !== undefined) assert(options.case in Case);
+    return rsBind.truncateWords(str, maxLength, options);
+}
+
+/**
+ * What {@link cacheStats} reports: how many `entries` the cache has and the most it keeps,
+ * `size`, how many lookups were `hits` and `misses`, the `hitRate` between them, and a rough
+ * `memoryEstimate` of the bytes the entries take.
+ */
+type CacheStats = {
+    entries: number,
+    size: number,
+    hits: number,
+    misses: number,
+    hitRate: number,
+    memoryEstimate: number,
+};
+
+/**
+ * Sets how many conversions {@link CaseString.toCase} memoizes, dropping the oldest ones if
+ * there are more, or with `0`, the default, turns the cache off and empties it.  Only calls
+ * without options and without a `fromCase` of `"auto"` are cached, none to {@link Case.Random}
+ * or {@link Case.PseudoRandom}; {@link setTraceCallback} reports each hit as a `cacheHit`
+ * event.  The cache belongs to this instance of the module, so each worker thread and each
+ * Electron context has its own.
+ * ```
+ * setCacheSize(10_000);
+ * ```
+ */
+function setCacheSize(size: number): void
+{
+    assert(Number.isInteger(size) && size >= 0);
+    rsBind.setCacheSize(size);
+}
+
+/**
+ * Empties the cache {@link setCacheSize} turned on and resets its counts of hits and misses,
+ * for when the keys a server converts change.
+ */
+function clearCache(): void
+{
+    rsBind.clearCache();
+}
+
+/**
+ * Reports on the cache {@link setCacheSize} turned on.
+ * ```
+ * // {entries: 1, size: 10000, hits: 1, misses: 1, hitRate: 0.5, memoryEstimate: ...}
+ * CS("userId").toCase(Case.Snake);
+ * CS("userId").toCase(Case.Snake);
+ * cacheStats();
+ * ```
+ */
+function cacheStats(): CacheStats
+{
+    return rsBind.cacheStats();
+}
+
 export {
     Case,
     Boundary,
     Pattern,
+    WordPattern,
+    EdgeDelimiters,
+    EmptyInput,
+    Apostrophes,
+    LeadingDigit,
+    KeepCase,
+    DroppedCharacter,
+    DroppedCallback,
+    droppedCharacters,
+    ReservedLanguage,
+    ReservedWords,
+    CaseOptions,
+    setMaxInputLength,
     CaseString,
+    NullInput,
+    setNullInput,
     CS,
+    convertLineKeys,
+    convertHtmlAttributes,
+    CollisionPolicy,
+    KeyCollision,
+    KeyOptions,
+    KeyMappings,
+    convertKeys,
+    convertJsonKeys,
+    NdjsonKeyConverter,
+    ndjsonKeyTransform,
+    convertYamlKeys,
+    convertTomlKeys,
+    convertCsvHeader,
+    convertQueryKeys,
+    renameColumns,
+    canonicalizeHeader,
+    isCanonicalHeader,
+    cssPropertyToCamel,
+    camelToCssProperty,
+    htmlToJsxProp,
+    jsxToHtmlAttribute,
+    htmlAttributesToJsx,
+    convertGraphqlFields,
+    convertDdlIdentifiers,
+    protoJsonName,
+    protoFieldName,
+    K8sName,
+    toK8sName,
+    ResourceNameOptions,
+    toResourceName,
+    convertOpenApiProperties,
+    RenamePlan,
+    planRenames,
+    PackageRegistry,
+    toPackageName,
+    toMacroName,
+    toIncludeGuard,
+    Converter,
+    PresetName,
+    preset,
+    AccessorLang,
+    toGetterName,
+    toSetterName,
+    fieldFromAccessor,
+    TraceEvent,
+    setTraceCallback,
+    IdRange,
+    NativeInfo,
+    nativeInfo,
+    CaseOutlier,
+    CaseConsistencyReport,
+    caseConsistencyReport,
+    LintProblem,
+    LintViolation,
+    lintIdentifiers,
+    MixedIdentifier,
+    findMixedConventions,
+    buildRenameMap,
+    IdentifierOccurrence,
+    findIdentifier,
+    IdentifierMatch,
+    matchIdentifiers,
+    CharacterClass,
+    SegmentationStats,
+    segmentationStats,
+    ReversibilityLoss,
+    Reversibility,
+    isReversible,
+    SignatureGroup,
+    groupBySignature,
+    DiscoveredAcronym,
+    discoverAcronyms,
+    WordDiff,
+    diffIdentifiers,
+    suggestCase,
+    CaseDetails,
+    toCaseDetailed,
+    applyToWord,
+    lowerFirstWord,
+    capitalizeFirstWord,
+    reservedWords,
+    isReservedWord,
+    truncateWords,
+    CacheStats,
+    setCacheSize,
+    clearCache,
+    cacheStats,
 };
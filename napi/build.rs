@@ -0,0 +1,4 @@
+fn main()
+{
+    napi_build::setup();
+}
@@ -0,0 +1,475 @@
+//! The JS values of the build's functions: the [`Js`] of `convert-case-core` for napi-rs, which is
+//! all the build needs to export the functions of its `exports`.
+
+use std::convert::TryFrom;
+use convert_case_core::js::{Js, State, Thrown, Type};
+use convert_case_core::key_walker::{Engine, Entries, Kind, WalkError};
+use napi::{sys, Env, Error, JsBoolean, JsFunction, JsNumber, JsObject, JsString, JsUnknown, NapiRaw, NapiValue, Result, ValueType};
+
+/// A JS value of one call.  `JsUnknown` can't be cloned, so this keeps the raw value, which stays
+/// valid until the call that made it returns.
+#[derive(Clone, Copy)]
+pub struct Value(sys::napi_value);
+
+impl Value
+{
+    pub fn new<T: NapiRaw>(value: &T) -> Self
+    {
+        Value(unsafe { value.raw() })
+    }
+}
+
+/// The JS globals the functions need, looked up the first time one call needs them.
+#[derive(Clone, Copy)]
+struct Globals
+{
+    object_keys: Value,
+    object_create: Value,
+    define_property: Value,
+    get_own_property_symbols: Value,
+    property_is_enumerable: Value,
+    array_from: Value,
+    object_prototype: Value,
+    map: Value,
+    map_prototype: Value,
+    set: Value,
+    set_prototype: Value,
+    string: Value,
+}
+
+/// The [`Js`] of one call, which works on its values.
+pub struct Napi
+{
+    env: Env,
+    globals: Option<Globals>,
+}
+
+/// Makes an error of the JS class `class`.  Errors napi-rs makes itself have its status as their
+/// `code`, which those of the neon addon don't.
+fn new_error(env: Env, class: &str, message: &str) -> Result<JsObject>
+{
+    let constructor: JsFunction = env.get_global()?.get_named_property(class)?;
+    constructor.new_instance(&[env.create_string(message)?])
+}
+
+impl Napi
+{
+    pub fn new(env: Env) -> Self
+    {
+        Napi { env, globals: None }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn env(&self) -> Env
+    {
+        self.env
+    }
+
+    pub fn unknown(&self, value: &Value) -> JsUnknown
+    {
+        unsafe { JsUnknown::from_raw_unchecked(self.env.raw(), value.0) }
+    }
+
+    /// The value as an object, which arrays and functions are too.
+    pub fn object(&self, value: &Value) -> Result<JsObject>
+    {
+        match self.unknown(value).get_type()?
+        {
+            ValueType::Object | ValueType::Function => Ok(unsafe { JsObject::from_raw_unchecked(self.env.raw(), value.0) }),
+            _ => Err(self.error_of("TypeError", "failed to downcast any to object")),
+        }
+    }
+
+    fn function(&self, function: &Value) -> JsFunction
+    {
+        unsafe { JsFunction::from_raw_unchecked(self.env.raw(), function.0) }
+    }
+
+    fn globals(&mut self) -> Result<Globals>
+    {
+        if let Some(globals) = self.globals
+        {
+            return Ok(globals);
+        }
+
+        let global = self.env.get_global()?;
+        let object = global.get_named_property::<JsFunction>("Object")?.coerce_to_object()?;
+        let array: JsObject = global.get_named_property::<JsFunction>("Array")?.coerce_to_object()?;
+        let map: JsFunction = global.get_named_property("Map")?;
+        let set: JsFunction = global.get_named_property("Set")?;
+        let string: JsFunction = global.get_named_property("String")?;
+        let object_prototype: JsObject = self.env.create_object()?.get_prototype()?;
+
+        let globals = Globals {
+            object_keys: Value::new(&object.get_named_property::<JsFunction>("keys")?),
+            object_create: Value::new(&object.get_named_property::<JsFunction>("create")?),
+            define_property: Value::new(&object.get_named_property::<JsFunction>("defineProperty")?),
+            get_own_property_symbols: Value::new(&object.get_named_property::<JsFunction>("getOwnPropertySymbols")?),
+            property_is_enumerable: Value::new(&object_prototype.get_named_property::<JsFunction>("propertyIsEnumerable")?),
+            array_from: Value::new(&array.get_named_property::<JsFunction>("from")?),
+            map_prototype: Value::new(&map.new_instance::<JsUnknown>(&[])?.get_prototype::<JsObject>()?),
+            set_prototype: Value::new(&set.new_instance::<JsUnknown>(&[])?.get_prototype::<JsObject>()?),
+            object_prototype: Value::new(&object_prototype),
+            map: Value::new(&map),
+            set: Value::new(&set),
+            string: Value::new(&string),
+        };
+        self.globals = Some(globals);
+        Ok(globals)
+    }
+
+    /// Calls `function` with `args`, and `undefined` as `this`.
+    fn call_function(&self, function: &Value, args: &[Value]) -> Result<Value>
+    {
+        let args: Vec<JsUnknown> = args.iter().map(|arg| self.unknown(arg)).collect();
+        Ok(Value::new(&self.function(function).call(None, &args)?))
+    }
+
+    /// Calls `function` with `args` and the array it returns as a vector.
+    fn call_for_array(&self, function: &Value, args: &[Value]) -> Result<Vec<Value>>
+    {
+        let array = self.call_function(function, args)?;
+        self.elements_of(&self.object(&array)?)
+    }
+
+    fn elements_of(&self, array: &JsObject) -> Result<Vec<Value>>
+    {
+        (0..array.get_array_length()?)
+            .map(|i| Ok(Value::new(&array.get_element::<JsUnknown>(i)?)))
+            .collect()
+    }
+
+    fn array(&self, elements: &[Value]) -> Result<JsObject>
+    {
+        let mut array = self.env.create_array_with_length(elements.len())?;
+        for (i, element) in elements.iter().enumerate()
+        {
+            array.set_element(i as u32, self.unknown(element))?;
+        }
+
+        Ok(array)
+    }
+
+    /// An error of the JS class `class`, which napi-rs throws as it is.
+    fn error_of(&self, class: &str, message: &str) -> Error
+    {
+        match new_error(self.env, class, message)
+        {
+            Ok(error) => Error::from(error.into_unknown()),
+            Err(error) => error,
+        }
+    }
+
+    /// The `RangeError` for an input over the length limit, which `message` describes.
+    fn input_too_long(&self, message: &str) -> Error
+    {
+        let error = new_error(self.env, "RangeError", message).and_then(|mut error| {
+            error.set_named_property("code", "ERR_INPUT_TOO_LONG")?;
+            Ok(error)
+        });
+        match error
+        {
+            Ok(error) => Error::from(error.into_unknown()),
+            Err(error) => error,
+        }
+    }
+}
+
+impl Engine for Napi
+{
+    type Value = Value;
+    type Error = Error;
+
+    fn kind(&mut self, value: &Value) -> Result<Kind>
+    {
+        let unknown = self.unknown(value);
+        if unknown.is_array()?
+        {
+            return Ok(Kind::Array);
+        }
+        if unknown.get_type()? != ValueType::Object
+        {
+            return Ok(Kind::Other);
+        }
+
+        let globals = self.globals()?;
+        let prototype: JsUnknown = self.object(value)?.get_prototype()?;
+        Ok(if prototype.get_type()? == ValueType::Null
+        {
+            Kind::NullObject
+        }
+        else if self.env.strict_equals(&prototype, self.unknown(&globals.object_prototype))?
+        {
+            Kind::Object
+        }
+        else if self.env.strict_equals(&prototype, self.unknown(&globals.map_prototype))?
+        {
+            Kind::Map
+        }
+        else if self.env.strict_equals(&prototype, self.unknown(&globals.set_prototype))?
+        {
+            Kind::Set
+        }
+        else
+        {
+            Kind::Instance
+        })
+    }
+
+    fn string(&mut self, value: &Value) -> Result<Option<String>>
+    {
+        let unknown = self.unknown(value);
+        match unknown.get_type()?
+        {
+            ValueType::String => Ok(Some(JsString::try_from(unknown)?.into_utf8()?.into_owned()?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn new_string(&mut self, string: &str) -> Result<Value>
+    {
+        Ok(Value::new(&self.env.create_string(string)?))
+    }
+
+    fn same(&mut self, a: &Value, b: &Value) -> Result<bool>
+    {
+        self.env.strict_equals(self.unknown(a), self.unknown(b))
+    }
+
+    fn elements(&mut self, array: &Value) -> Result<Vec<Value>>
+    {
+        self.elements_of(&self.object(array)?)
+    }
+
+    fn new_array(&mut self, elements: Vec<Value>) -> Result<Value>
+    {
+        Ok(Value::new(&self.array(&elements)?))
+    }
+
+    fn keys(&mut self, object: &Value) -> Result<Vec<String>>
+    {
+        let globals = self.globals()?;
+        self.call_for_array(&globals.object_keys, &[*object])?
+            .iter()
+            .map(|key| JsString::try_from(self.unknown(key))?.into_utf8()?.into_owned())
+            .collect()
+    }
+
+    fn get(&mut self, object: &Value, key: &str) -> Result<Value>
+    {
+        let value: JsUnknown = self.object(object)?.get_property(self.env.create_string(key)?)?;
+        Ok(Value::new(&value))
+    }
+
+    fn new_object(&mut self, null_prototype: bool) -> Result<Value>
+    {
+        match null_prototype
+        {
+            true =>
+            {
+                let null = Value::new(&self.env.get_null()?);
+                let globals = self.globals()?;
+                self.call_function(&globals.object_create, &[null])
+            }
+            false => Ok(Value::new(&self.env.create_object()?)),
+        }
+    }
+
+    fn define(&mut self, object: &Value, name: &str, value: Value) -> Result<()>
+    {
+        let mut js_object = self.object(object)?;
+        let js_name = self.env.create_string(name)?;
+        if name != "__proto__"
+        {
+            return js_object.set_property(js_name, self.unknown(&value));
+        }
+
+        let mut descriptor = self.env.create_object()?;
+        descriptor.set_named_property("value", self.unknown(&value))?;
+        for attribute in ["writable", "enumerable", "configurable"]
+        {
+            descriptor.set_named_property(attribute, true)?;
+        }
+
+        let globals = self.globals()?;
+        self.call_function(&globals.define_property, &[Value::new(&js_object), Value::new(&js_name), Value::new(&descriptor)])?;
+        Ok(())
+    }
+
+    fn copy_symbols(&mut self, from: &Value, to: &Value) -> Result<()>
+    {
+        let globals = self.globals()?;
+        let (from_object, mut to_object) = (self.object(from)?, self.object(to)?);
+        for symbol in self.call_for_array(&globals.get_own_property_symbols, &[*from])?
+        {
+            let property_is_enumerable = self.function(&globals.property_is_enumerable);
+            let enumerable = property_is_enumerable.call(Some(&from_object), &[self.unknown(&symbol)])?;
+            if enumerable.coerce_to_bool()?.get_value()?
+            {
+                let value: JsUnknown = from_object.get_property(self.unknown(&symbol))?;
+                to_object.set_property(self.unknown(&symbol), value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn entries(&mut self, map: &Value) -> Result<Entries<Value>>
+    {
+        let globals = self.globals()?;
+        self.call_for_array(&globals.array_from, &[*map])?
+            .iter()
+            .map(|entry| {
+                let entry = self.object(entry)?;
+                Ok((Value::new(&entry.get_element::<JsUnknown>(0)?), Value::new(&entry.get_element::<JsUnknown>(1)?)))
+            })
+            .collect()
+    }
+
+    fn new_map(&mut self, entries: Entries<Value>) -> Result<Value>
+    {
+        let mut pairs = Vec::with_capacity(entries.len());
+        for (key, value) in entries
+        {
+            pairs.push(Value::new(&self.array(&[key, value])?));
+        }
+        let pairs = self.array(&pairs)?;
+
+        let globals = self.globals()?;
+        Ok(Value::new(&self.function(&globals.map).new_instance(&[pairs])?))
+    }
+
+    fn members(&mut self, set: &Value) -> Result<Vec<Value>>
+    {
+        let globals = self.globals()?;
+        self.call_for_array(&globals.array_from, &[*set])
+    }
+
+    fn new_set(&mut self, members: Vec<Value>) -> Result<Value>
+    {
+        let members = self.array(&members)?;
+        let globals = self.globals()?;
+        Ok(Value::new(&self.function(&globals.set).new_instance(&[members])?))
+    }
+
+    fn error(&mut self, error: WalkError) -> Error
+    {
+        self.throw(error.into())
+    }
+}
+
+impl Js for Napi
+{
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    fn type_of(&mut self, value: &Value) -> Result<Type>
+    {
+        let unknown = self.unknown(value);
+        Ok(match unknown.get_type()?
+        {
+            ValueType::Undefined => Type::Undefined,
+            ValueType::Null => Type::Null,
+            ValueType::Boolean => Type::Boolean,
+            ValueType::Number => Type::Number,
+            ValueType::String => Type::String,
+            ValueType::Function => Type::Function,
+            ValueType::Object if unknown.is_array()? => Type::Array,
+            ValueType::Object | ValueType::External => Type::Object,
+            _ => Type::Other,
+        })
+    }
+
+    fn boolean(&mut self, value: &Value) -> Result<Option<bool>>
+    {
+        let unknown = self.unknown(value);
+        match unknown.get_type()?
+        {
+            ValueType::Boolean => Ok(Some(JsBoolean::try_from(unknown)?.get_value()?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn number(&mut self, value: &Value) -> Result<Option<f64>>
+    {
+        let unknown = self.unknown(value);
+        match unknown.get_type()?
+        {
+            ValueType::Number => Ok(Some(JsNumber::try_from(unknown)?.get_double()?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn to_string(&mut self, value: &Value) -> Result<String>
+    {
+        let globals = self.globals()?;
+        let string = self.call_function(&globals.string, &[*value])?;
+        JsString::try_from(self.unknown(&string))?.into_utf8()?.into_owned()
+    }
+
+    fn undefined(&mut self) -> Result<Value>
+    {
+        Ok(Value::new(&self.env.get_undefined()?))
+    }
+
+    fn null(&mut self) -> Result<Value>
+    {
+        Ok(Value::new(&self.env.get_null()?))
+    }
+
+    fn new_boolean(&mut self, boolean: bool) -> Result<Value>
+    {
+        Ok(Value::new(&self.env.get_boolean(boolean)?))
+    }
+
+    fn new_number(&mut self, number: f64) -> Result<Value>
+    {
+        Ok(Value::new(&self.env.create_double(number)?))
+    }
+
+    fn call(&mut self, function: &Value, args: &[Value]) -> Result<Value>
+    {
+        self.call_function(function, args)
+    }
+
+    fn throw(&mut self, error: Thrown) -> Error
+    {
+        match error
+        {
+            Thrown::Error(message) => self.error_of("Error", &message),
+            Thrown::TypeError(message) => self.error_of("TypeError", &message),
+            Thrown::RangeError(message) => self.error_of("RangeError", &message),
+            Thrown::InputTooLong(message) => self.input_too_long(&message),
+        }
+    }
+
+    fn with_state<R>(&mut self, f: impl FnOnce(&State) -> R) -> Result<Option<R>>
+    {
+        Ok(Some(f(&crate::instance(self.env)?.state)))
+    }
+
+    fn trace_callback(&mut self) -> Result<Option<Value>>
+    {
+        let instance = crate::instance(self.env)?;
+        let callback = instance.trace_callback.borrow();
+        match &*callback
+        {
+            Some(callback) => Ok(Some(Value::new(&self.env.get_reference_value::<JsFunction>(callback)?))),
+            None => Ok(None),
+        }
+    }
+
+    fn set_trace_callback(&mut self, callback: Option<Value>) -> Result<()>
+    {
+        let callback = match callback
+        {
+            Some(callback) => Some(self.env.create_reference(self.function(&callback))?),
+            None => None,
+        };
+        if let Some(mut previous) = crate::instance(self.env)?.trace_callback.replace(callback)
+        {
+            previous.unref(self.env)?;
+        }
+
+        Ok(())
+    }
+}
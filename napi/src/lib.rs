@@ -0,0 +1,160 @@
+//! Node-API bindings for `convert-case-core`, built on napi-rs.
+//!
+//! Every function is one of `exports` in `convert-case-core`, exported under the same name as by
+//! the neon addon, so this builds a drop-in replacement for `index.node` (`npm run build-napi`)
+//! that reads the same arguments and throws the same `Error`s, `RangeError`s, and `TypeError`s
+//! with the same messages: all this crate does is convert napi-rs's values.  It only uses
+//! Node-API 6, whose ABI is stable, so one binary loads in every Node version and Electron release
+//! that has it, without being rebuilt.
+//!
+//! Build it with `cargo build -p convert-case-napi`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use napi::{CallContext, Env, JsObject, JsUnknown, Ref, Result};
+use napi_derive::module_exports;
+
+use convert_case_core::js::State;
+
+mod js;
+
+use js::{Napi, Value};
+
+/// The state of one instance of the addon, which each thread or context that loads it has its
+/// own of, like the one the neon addon keeps on its exports: the [`State`] of
+/// `convert-case-core` and the callback `setTraceCallback` set.
+#[derive(Default)]
+struct Instance
+{
+    state: State,
+    trace_callback: RefCell<Option<Ref<()>>>,
+}
+
+/// Returns the state of the instance the function was called through, creating it the first
+/// time.  It is handed out as an `Rc` so that nothing borrows it across calls back into JS, which
+/// can call the addon again.
+fn instance(env: Env) -> Result<Rc<Instance>>
+{
+    if let Some(instance) = env.get_instance_data::<Rc<Instance>>()?
+    {
+        return Ok(instance.clone());
+    }
+
+    let instance = Rc::new(Instance::default());
+    env.set_instance_data(instance.clone(), (), |context| {
+        if let Some(mut callback) = context.value.trace_callback.take()
+        {
+            let _ = callback.unref(context.env);
+        }
+    })?;
+    Ok(instance)
+}
+
+/// A function the addon exports, which is called with the arguments of each call.
+type Function = fn(&mut Napi, &[Value]) -> Result<Value>;
+
+/// Calls `function` with the arguments of one call.
+fn call(cx: CallContext, function: Function) -> Result<JsUnknown>
+{
+    let args = (0..cx.length)
+        .map(|i| Ok(Value::new(&cx.get::<JsUnknown>(i)?)))
+        .collect::<Result<Vec<_>>>()?;
+    let mut js = Napi::new(*cx.env);
+    let result = function(&mut js, &args)?;
+    Ok(js.unknown(&result))
+}
+
+/// The NDJSON functions, whose streams are wrapped in the objects `ndjsonNew` returns, with
+/// their `onCollision` callback kept as a property, like the `JsBox` the neon addon returns.
+#[cfg(feature = "json")]
+mod ndjson
+{
+    use std::cell::RefCell;
+    use convert_case_core::exports;
+    use convert_case_core::js::{Js, Type};
+    use convert_case_core::json;
+    use convert_case_core::key_walker::Engine;
+    use napi::{JsObject, Result};
+
+    use crate::js::{Napi, Value};
+
+    type NdjsonStream = RefCell<json::NdjsonStream>;
+
+    /// The handle each call is given first, which wraps the stream, and its `onCollision`
+    /// callback.
+    fn handle(js: &mut Napi, args: &[Value]) -> Result<(JsObject, Option<Value>)>
+    {
+        let handle = match args.first()
+        {
+            Some(handle) => *handle,
+            None => js.undefined()?,
+        };
+        let object = js.object(&handle)?;
+        let on_collision = js.get(&handle, "onCollision")?;
+        let on_collision = match js.type_of(&on_collision)?
+        {
+            Type::Function => Some(on_collision),
+            _ => None,
+        };
+        Ok((object, on_collision))
+    }
+
+    pub fn ndjson_new(js: &mut Napi, args: &[Value]) -> Result<Value>
+    {
+        let (stream, on_collision) = exports::ndjson_new(js, args)?;
+        let mut handle = js.env().create_object()?;
+        js.env().wrap::<NdjsonStream>(&mut handle, RefCell::new(stream))?;
+        if let Some(on_collision) = on_collision
+        {
+            handle.set_named_property("onCollision", js.unknown(&on_collision))?;
+        }
+
+        Ok(Value::new(&handle))
+    }
+
+    pub fn ndjson_write(js: &mut Napi, args: &[Value]) -> Result<Value>
+    {
+        let (handle, on_collision) = handle(js, args)?;
+        let env = js.env();
+        let stream: &NdjsonStream = env.unwrap(&handle)?;
+        exports::ndjson_write(js, stream, on_collision, args)
+    }
+
+    pub fn ndjson_end(js: &mut Napi, args: &[Value]) -> Result<Value>
+    {
+        let (handle, on_collision) = handle(js, args)?;
+        let env = js.env();
+        let stream: &NdjsonStream = env.unwrap(&handle)?;
+        exports::ndjson_end(js, stream, on_collision)
+    }
+}
+
+/// The functions the addon exports: those of `exports` in `convert-case-core`, but for the
+/// NDJSON ones, whose handles are wrapped here.
+mod functions
+{
+    pub use convert_case_core::exports::*;
+    #[cfg(feature = "json")]
+    pub(crate) use crate::ndjson::{ndjson_end, ndjson_new, ndjson_write};
+}
+
+macro_rules! export
+{
+    ($($(#[$attr:meta])* $name:literal => $function:ident $signature:literal;)*) =>
+    {
+        /// Registers the exports for one Node instance, which runs again in every worker thread
+        /// and every Electron context that loads the addon; their state is the instance data of
+        /// each.
+        #[module_exports]
+        fn init(mut exports: JsObject, env: Env) -> Result<()>
+        {
+            $(
+                $(#[$attr])*
+                exports.set_named_property($name, env.create_function_from_closure($name, |cx| call(cx, functions::$function))?)?;
+            )*
+            Ok(())
+        }
+    };
+}
+
+convert_case_core::for_each_export!(export);
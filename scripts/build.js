@@ -0,0 +1,47 @@
+// Builds the addon into ./index.node, passing any arguments on to cargo, then copies the
+// declarations build.rs generated for that build into ./index.node.d.ts.  build.rs writes them
+// into its OUT_DIR, which depends on the profile and features, so that a build with other
+// features, such as a `--no-default-features` clippy run, never overwrites them.
+const { spawnSync } = require("child_process");
+const fs = require("fs");
+const path = require("path");
+
+const args = process.argv.slice(2);
+const root = path.join(__dirname, "..");
+const shell = process.platform === "win32";
+
+function run(command, commandArgs, options = {})
+{
+    const result = spawnSync(command, commandArgs, { cwd: root, shell, ...options });
+    if (result.status !== 0)
+    {
+        process.exit(result.status ?? 1);
+    }
+    return result;
+}
+
+run("cargo-cp-artifact", ["-nc", "index.node", "--", "cargo", "build", "--message-format=json-render-diagnostics", ...args], { stdio: "inherit" });
+
+// Asking cargo again is a no-op build, but it reports where the build script of this package wrote
+// its output for these arguments
+const build = run("cargo", ["build", "--message-format=json", ...args], { encoding: "utf8", stdio: ["ignore", "pipe", "inherit"] });
+const outDir = build.stdout.split("\n")
+    .filter(line => line.startsWith("{"))
+    .map(line => JSON.parse(line))
+    .filter(message => message.reason === "build-script-executed" && /\bnode-convert-case\b/.test(message.package_id))
+    .map(message => message.out_dir)
+    .pop();
+if (outDir === undefined)
+{
+    console.error("cargo didn't report the output of the addon's build script");
+    process.exit(1);
+}
+
+const declarations = fs.readFileSync(path.join(outDir, "index.node.d.ts"), "utf8");
+const target = path.join(root, "index.node.d.ts");
+
+// Leave the file alone when nothing changed, so that watchers don't see a rebuild
+if (!fs.existsSync(target) || fs.readFileSync(target, "utf8") !== declarations)
+{
+    fs.writeFileSync(target, declarations);
+}
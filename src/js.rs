@@ -0,0 +1,431 @@
+//! The JS values of the addon's functions: the [`Js`] of `convert-case-core` for neon's handles,
+//! which is all the addon needs to export the functions of its `exports`.
+
+use convert_case_core::js::{Js, State, Thrown, Type};
+use convert_case_core::key_walker::{Engine, Entries, Kind, WalkError};
+use neon::prelude::*;
+use neon::result::Throw;
+
+/// The functions of the JS globals the walk needs, looked up the first time one call needs them.
+#[derive(Clone, Copy)]
+struct Globals<'a>
+{
+    object_keys: Handle<'a, JsFunction>,
+    object_create: Handle<'a, JsFunction>,
+    define_property: Handle<'a, JsFunction>,
+    get_own_property_symbols: Handle<'a, JsFunction>,
+    property_is_enumerable: Handle<'a, JsFunction>,
+    get_prototype_of: Handle<'a, JsFunction>,
+    array_from: Handle<'a, JsFunction>,
+    object_prototype: Handle<'a, JsValue>,
+    map: Handle<'a, JsFunction>,
+    map_prototype: Handle<'a, JsValue>,
+    set: Handle<'a, JsFunction>,
+    set_prototype: Handle<'a, JsValue>,
+}
+
+/// The [`Js`] of one call, which works on its handles.
+pub struct Neon<'a>
+{
+    cx: FunctionContext<'a>,
+    globals: Option<Globals<'a>>,
+}
+
+impl<'a> Neon<'a>
+{
+    pub fn new(cx: FunctionContext<'a>) -> Self
+    {
+        Neon { cx, globals: None }
+    }
+
+    /// The context of the call, for the NDJSON handles the addon boxes itself.
+    #[cfg(feature = "json")]
+    pub fn cx(&mut self) -> &mut FunctionContext<'a>
+    {
+        &mut self.cx
+    }
+
+    fn globals(&mut self) -> NeonResult<Globals<'a>>
+    {
+        if let Some(globals) = self.globals
+        {
+            return Ok(globals);
+        }
+
+        let cx = &mut self.cx;
+        let object: Handle<JsFunction> = cx.global().get(cx, "Object")?;
+        let array: Handle<JsFunction> = cx.global().get(cx, "Array")?;
+        let map: Handle<JsFunction> = cx.global().get(cx, "Map")?;
+        let set: Handle<JsFunction> = cx.global().get(cx, "Set")?;
+        let object_prototype: Handle<JsObject> = object.get(cx, "prototype")?;
+
+        let globals = Globals {
+            object_keys: object.get(cx, "keys")?,
+            object_create: object.get(cx, "create")?,
+            define_property: object.get(cx, "defineProperty")?,
+            get_own_property_symbols: object.get(cx, "getOwnPropertySymbols")?,
+            property_is_enumerable: object_prototype.get(cx, "propertyIsEnumerable")?,
+            get_prototype_of: object.get(cx, "getPrototypeOf")?,
+            array_from: array.get(cx, "from")?,
+            object_prototype: object_prototype.upcast(),
+            map,
+            map_prototype: map.get(cx, "prototype")?,
+            set,
+            set_prototype: set.get(cx, "prototype")?,
+        };
+        self.globals = Some(globals);
+        Ok(globals)
+    }
+
+    fn object(&mut self, value: &Handle<'a, JsValue>) -> JsResult<'a, JsObject>
+    {
+        value.downcast_or_throw::<JsObject, _>(&mut self.cx)
+    }
+
+    /// Calls `function` with `args` and the array it returns as a vector.
+    fn call_for_array<const N: usize>(&mut self, function: Handle<'a, JsFunction>, args: [Handle<'a, JsValue>; N]) -> NeonResult<Vec<Handle<'a, JsValue>>>
+    {
+        let undefined = self.cx.undefined();
+        function.call(&mut self.cx, undefined, args)?
+            .downcast_or_throw::<JsArray, _>(&mut self.cx)?
+            .to_vec(&mut self.cx)
+    }
+
+    fn array(&mut self, elements: &[Handle<'a, JsValue>]) -> JsResult<'a, JsArray>
+    {
+        let array = JsArray::new(&mut self.cx, elements.len() as u32);
+        for (i, element) in elements.iter().enumerate()
+        {
+            array.set(&mut self.cx, i as u32, *element)?;
+        }
+
+        Ok(array)
+    }
+
+    /// Throws the `RangeError` for an input over the length limit, which `message` describes.
+    fn throw_input_too_long(&mut self, message: String) -> NeonResult<()>
+    {
+        let error = self.cx.range_error(message)?;
+        let code = self.cx.string("ERR_INPUT_TOO_LONG");
+        error.set(&mut self.cx, "code", code)?;
+        self.cx.throw(error)
+    }
+}
+
+impl<'a> Engine for Neon<'a>
+{
+    type Value = Handle<'a, JsValue>;
+    type Error = Throw;
+
+    fn kind(&mut self, value: &Self::Value) -> NeonResult<Kind>
+    {
+        if value.is_a::<JsArray, _>(&mut self.cx)
+        {
+            return Ok(Kind::Array);
+        }
+        if !value.is_a::<JsObject, _>(&mut self.cx)
+        {
+            return Ok(Kind::Other);
+        }
+
+        let globals = self.globals()?;
+        let undefined = self.cx.undefined();
+        let prototype = globals.get_prototype_of.call(&mut self.cx, undefined, [*value])?;
+        Ok(if prototype.is_a::<JsNull, _>(&mut self.cx)
+        {
+            Kind::NullObject
+        }
+        else if prototype.strict_equals(&mut self.cx, globals.object_prototype)
+        {
+            Kind::Object
+        }
+        else if prototype.strict_equals(&mut self.cx, globals.map_prototype)
+        {
+            Kind::Map
+        }
+        else if prototype.strict_equals(&mut self.cx, globals.set_prototype)
+        {
+            Kind::Set
+        }
+        else
+        {
+            Kind::Instance
+        })
+    }
+
+    fn string(&mut self, value: &Self::Value) -> NeonResult<Option<String>>
+    {
+        Ok(value.downcast::<JsString, _>(&mut self.cx).ok().map(|string| string.value(&mut self.cx)))
+    }
+
+    fn new_string(&mut self, string: &str) -> NeonResult<Self::Value>
+    {
+        Ok(self.cx.string(string).upcast())
+    }
+
+    fn same(&mut self, a: &Self::Value, b: &Self::Value) -> NeonResult<bool>
+    {
+        Ok(a.strict_equals(&mut self.cx, *b))
+    }
+
+    fn elements(&mut self, array: &Self::Value) -> NeonResult<Vec<Self::Value>>
+    {
+        array.downcast_or_throw::<JsArray, _>(&mut self.cx)?.to_vec(&mut self.cx)
+    }
+
+    fn new_array(&mut self, elements: Vec<Self::Value>) -> NeonResult<Self::Value>
+    {
+        Ok(self.array(&elements)?.upcast())
+    }
+
+    fn keys(&mut self, object: &Self::Value) -> NeonResult<Vec<String>>
+    {
+        let globals = self.globals()?;
+        let keys = self.call_for_array(globals.object_keys, [*object])?;
+        keys.into_iter()
+            .map(|key| Ok(key.downcast_or_throw::<JsString, _>(&mut self.cx)?.value(&mut self.cx)))
+            .collect()
+    }
+
+    fn get(&mut self, object: &Self::Value, key: &str) -> NeonResult<Self::Value>
+    {
+        self.object(object)?.get(&mut self.cx, key)
+    }
+
+    fn new_object(&mut self, null_prototype: bool) -> NeonResult<Self::Value>
+    {
+        match null_prototype
+        {
+            true =>
+            {
+                let globals = self.globals()?;
+                let undefined = self.cx.undefined();
+                let null = self.cx.null();
+                globals.object_create.call(&mut self.cx, undefined, [null.upcast::<JsValue>()])
+            }
+            false => Ok(self.cx.empty_object().upcast()),
+        }
+    }
+
+    fn define(&mut self, object: &Self::Value, name: &str, value: Self::Value) -> NeonResult<()>
+    {
+        let object = self.object(object)?;
+        if name != "__proto__"
+        {
+            object.set(&mut self.cx, name, value)?;
+            return Ok(());
+        }
+
+        let descriptor = self.cx.empty_object();
+        descriptor.set(&mut self.cx, "value", value)?;
+        for attribute in ["writable", "enumerable", "configurable"]
+        {
+            let yes = self.cx.boolean(true);
+            descriptor.set(&mut self.cx, attribute, yes)?;
+        }
+
+        let globals = self.globals()?;
+        let undefined = self.cx.undefined();
+        let name = self.cx.string(name);
+        globals.define_property.call(&mut self.cx, undefined, [object.upcast::<JsValue>(), name.upcast(), descriptor.upcast()])?;
+        Ok(())
+    }
+
+    fn copy_symbols(&mut self, from: &Self::Value, to: &Self::Value) -> NeonResult<()>
+    {
+        let globals = self.globals()?;
+        let (from, to) = (self.object(from)?, self.object(to)?);
+        for symbol in self.call_for_array(globals.get_own_property_symbols, [from.upcast()])?
+        {
+            if globals.property_is_enumerable.call(&mut self.cx, from, [symbol])?.downcast_or_throw::<JsBoolean, _>(&mut self.cx)?.value(&mut self.cx)
+            {
+                let value: Handle<JsValue> = from.get(&mut self.cx, symbol)?;
+                to.set(&mut self.cx, symbol, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn entries(&mut self, map: &Self::Value) -> NeonResult<Entries<Self::Value>>
+    {
+        let globals = self.globals()?;
+        self.call_for_array(globals.array_from, [*map])?
+            .into_iter()
+            .map(|entry| {
+                let entry = entry.downcast_or_throw::<JsArray, _>(&mut self.cx)?;
+                Ok((entry.get(&mut self.cx, 0)?, entry.get(&mut self.cx, 1)?))
+            })
+            .collect()
+    }
+
+    fn new_map(&mut self, entries: Entries<Self::Value>) -> NeonResult<Self::Value>
+    {
+        let mut pairs = Vec::with_capacity(entries.len());
+        for (key, value) in entries
+        {
+            pairs.push(self.array(&[key, value])?.upcast());
+        }
+        let pairs = self.array(&pairs)?;
+        let globals = self.globals()?;
+
+        Ok(globals.map.construct(&mut self.cx, [pairs.upcast::<JsValue>()])?.upcast())
+    }
+
+    fn members(&mut self, set: &Self::Value) -> NeonResult<Vec<Self::Value>>
+    {
+        let globals = self.globals()?;
+        self.call_for_array(globals.array_from, [*set])
+    }
+
+    fn new_set(&mut self, members: Vec<Self::Value>) -> NeonResult<Self::Value>
+    {
+        let members = self.array(&members)?;
+        let globals = self.globals()?;
+        Ok(globals.set.construct(&mut self.cx, [members.upcast::<JsValue>()])?.upcast())
+    }
+
+    fn error(&mut self, error: WalkError) -> Throw
+    {
+        self.throw(error.into())
+    }
+}
+
+impl<'a> Js for Neon<'a>
+{
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    fn type_of(&mut self, value: &Self::Value) -> NeonResult<Type>
+    {
+        let cx = &mut self.cx;
+        Ok(if value.is_a::<JsUndefined, _>(cx)
+        {
+            Type::Undefined
+        }
+        else if value.is_a::<JsNull, _>(cx)
+        {
+            Type::Null
+        }
+        else if value.is_a::<JsBoolean, _>(cx)
+        {
+            Type::Boolean
+        }
+        else if value.is_a::<JsNumber, _>(cx)
+        {
+            Type::Number
+        }
+        else if value.is_a::<JsString, _>(cx)
+        {
+            Type::String
+        }
+        else if value.is_a::<JsFunction, _>(cx)
+        {
+            Type::Function
+        }
+        else if value.is_a::<JsArray, _>(cx)
+        {
+            Type::Array
+        }
+        else if value.is_a::<JsObject, _>(cx)
+        {
+            Type::Object
+        }
+        else
+        {
+            Type::Other
+        })
+    }
+
+    fn boolean(&mut self, value: &Self::Value) -> NeonResult<Option<bool>>
+    {
+        Ok(value.downcast::<JsBoolean, _>(&mut self.cx).ok().map(|boolean| boolean.value(&mut self.cx)))
+    }
+
+    fn number(&mut self, value: &Self::Value) -> NeonResult<Option<f64>>
+    {
+        Ok(value.downcast::<JsNumber, _>(&mut self.cx).ok().map(|number| number.value(&mut self.cx)))
+    }
+
+    fn to_string(&mut self, value: &Self::Value) -> NeonResult<String>
+    {
+        // neon has no bigint, so its decimal digits come from `String`
+        let string: Handle<JsFunction> = self.cx.global().get(&mut self.cx, "String")?;
+        let undefined = self.cx.undefined();
+        Ok(string.call(&mut self.cx, undefined, [*value])?.downcast_or_throw::<JsString, _>(&mut self.cx)?.value(&mut self.cx))
+    }
+
+    fn undefined(&mut self) -> NeonResult<Self::Value>
+    {
+        Ok(self.cx.undefined().upcast())
+    }
+
+    fn null(&mut self) -> NeonResult<Self::Value>
+    {
+        Ok(self.cx.null().upcast())
+    }
+
+    fn new_boolean(&mut self, boolean: bool) -> NeonResult<Self::Value>
+    {
+        Ok(self.cx.boolean(boolean).upcast())
+    }
+
+    fn new_number(&mut self, number: f64) -> NeonResult<Self::Value>
+    {
+        Ok(self.cx.number(number).upcast())
+    }
+
+    fn call(&mut self, function: &Self::Value, args: &[Self::Value]) -> NeonResult<Self::Value>
+    {
+        let function = function.downcast_or_throw::<JsFunction, _>(&mut self.cx)?;
+        let undefined = self.cx.undefined();
+        function.call(&mut self.cx, undefined, args)
+    }
+
+    fn throw(&mut self, error: Thrown) -> Throw
+    {
+        let thrown: NeonResult<()> = match error
+        {
+            Thrown::Error(message) => self.cx.throw_error(message),
+            Thrown::TypeError(message) => self.cx.throw_type_error(message),
+            Thrown::RangeError(message) => self.cx.throw_range_error(message),
+            Thrown::InputTooLong(message) => self.throw_input_too_long(message),
+        };
+        thrown.unwrap_err()
+    }
+
+    fn with_state<R>(&mut self, f: impl FnOnce(&State) -> R) -> NeonResult<Option<R>>
+    {
+        Ok(crate::instance(&mut self.cx)?.map(|instance| f(&instance.state)))
+    }
+
+    fn trace_callback(&mut self) -> NeonResult<Option<Self::Value>>
+    {
+        let instance = match crate::instance(&mut self.cx)?
+        {
+            Some(instance) => instance,
+            None => return Ok(None),
+        };
+        let callback = instance.trace_callback.borrow();
+        Ok(callback.as_ref().map(|callback| callback.to_inner(&mut self.cx).upcast()))
+    }
+
+    fn set_trace_callback(&mut self, callback: Option<Self::Value>) -> NeonResult<()>
+    {
+        let instance = match crate::instance(&mut self.cx)?
+        {
+            Some(instance) => instance,
+            None => return Ok(()),
+        };
+        let callback = match callback
+        {
+            Some(callback) => Some(callback.downcast_or_throw::<JsFunction, _>(&mut self.cx)?.root(&mut self.cx)),
+            None => None,
+        };
+        if let Some(previous) = instance.trace_callback.replace(callback)
+        {
+            previous.drop(&mut self.cx);
+        }
+
+        Ok(())
+    }
+}
@@ -1,95 +1,162 @@
 use neon::prelude::*;
-use std::mem::transmute;
-use convert_case::{Casing, Pattern, Case, Converter, Boundary};
+use std::cell::RefCell;
 
-fn js_case_convert(mut cx: FunctionContext) -> JsResult<JsString>
-{
-    let str: String = cx.argument::<JsString>(0)?.value(&mut cx);
-    let case_type: Case = unsafe { transmute(cx.argument::<JsNumber>(1)?.value(&mut cx) as u8) };
-    let mut conv = Converter::new();
+use convert_case_core::js::State;
+#[cfg(feature = "json")]
+use convert_case_core::{exports, json};
 
-    // Alternative way of declaring an optional argument
-    let js_from_case: Handle<JsValue> = cx.argument(2)?;
-    if js_from_case.is_a::<JsNumber, _>(&mut cx)
-    {
-        let from_case: Case = unsafe { transmute(js_from_case.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx) as u8) };
-        conv = conv.from_case(from_case);
-    }
+mod js;
 
-    Ok(cx.string(conv.to_case(case_type).convert(str)))
+use js::Neon;
+
+/// The state of one instance of the addon, which each thread or context that loads it has its
+/// own of: the [`State`] of `convert-case-core` and the callback `setTraceCallback` set.
+#[derive(Default)]
+struct Instance
+{
+    state: State,
+    trace_callback: RefCell<Option<Root<JsFunction>>>,
 }
 
-fn js_is_case(mut cx: FunctionContext) -> JsResult<JsBoolean>
+// The trace callback isn't released with `Root::drop` here, since an addon whose worker is
+// terminated finalizes its exports after neon's own instance data is gone; dropping the `Root`
+// queues its release instead, which neon skips once the instance is closing.
+impl Finalize for Instance {}
+
+/// The property of the exports the [`Instance`] is kept under.
+const INSTANCE: &str = "instance";
+
+/// Defines the [`Instance`] of the addon on its exports with `Object.defineProperty`, so that
+/// it is neither enumerable nor writable, and JS can neither list it nor replace it.
+fn define_instance(cx: &mut ModuleContext) -> NeonResult<()>
 {
-    let str: String = cx.argument::<JsString>(0)?.value(&mut cx);
-    let case_type = cx.argument::<JsNumber>(1)?.value(&mut cx) as u8;
+    let exports = cx.exports_object()?;
+    let object: Handle<JsFunction> = cx.global().get(cx, "Object")?;
+    let define_property: Handle<JsFunction> = object.get(cx, "defineProperty")?;
+
+    let descriptor = cx.empty_object();
+    let instance = cx.boxed(Instance::default());
+    descriptor.set(cx, "value", instance)?;
+    let name = cx.string(INSTANCE);
+    define_property.call(cx, object, [exports.upcast::<JsValue>(), name.upcast(), descriptor.upcast()])?;
+    Ok(())
+}
 
-    unsafe
+/// Returns the state of the exports the function was called through, or `None` if it wasn't
+/// called through them.
+fn instance<'a>(cx: &mut FunctionContext<'a>) -> NeonResult<Option<Handle<'a, JsBox<Instance>>>>
+{
+    match cx.this().upcast::<JsValue>().downcast::<JsObject, _>(cx)
     {
-        Ok(cx.boolean(str.is_case(transmute(case_type))))
+        Ok(exports) =>
+        {
+            let instance: Handle<JsValue> = exports.get(cx, INSTANCE)?;
+            Ok(instance.downcast::<JsBox<Instance>, _>(cx).ok())
+        }
+        Err(_) => Ok(None),
     }
 }
 
-fn js_mutate_str(mut cx: FunctionContext) -> JsResult<JsString>
+/// A function the addon exports, which is called with the arguments of each call.
+type Function<'a> = fn(&mut Neon<'a>, &[Handle<'a, JsValue>]) -> NeonResult<Handle<'a, JsValue>>;
+
+/// Calls `function` with the context and the arguments of one call.
+fn call<'a>(mut cx: FunctionContext<'a>, function: Function<'a>) -> JsResult<'a, JsValue>
 {
-    let str: String = cx.argument::<JsString>(0)?.value(&mut cx);
-    let options = cx.argument::<JsObject>(1)?;
-    let mut conv = Converter::new();
+    let args = (0..cx.len())
+        .map(|i| cx.argument::<JsValue>(i))
+        .collect::<NeonResult<Vec<_>>>()?;
+    function(&mut Neon::new(cx), &args)
+}
 
-    let js_delim: Handle<JsValue> = options.get(&mut cx, "delim")?;
-    if js_delim.is_a::<JsString, _>(&mut cx)
+#[cfg(feature = "json")]
+struct NdjsonHandle
+{
+    stream: RefCell<json::NdjsonStream>,
+    on_collision: Option<Root<JsFunction>>,
+}
+
+#[cfg(feature = "json")]
+impl Finalize for NdjsonHandle
+{
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C)
     {
-        let delim: String = js_delim.downcast_or_throw::<JsString, _>(&mut cx)?.value(&mut cx);
-        conv = conv.set_delim(delim);
+        if let Some(on_collision) = self.on_collision
+        {
+            on_collision.drop(cx);
+        }
     }
+}
 
-    let js_pattern: Handle<JsValue> = options.get(&mut cx, "pattern")?;
-    if js_pattern.is_a::<JsNumber, _>(&mut cx)
+#[cfg(feature = "json")]
+impl NdjsonHandle
+{
+    /// The `onCollision` callback the stream reports its collisions to, if one was given.
+    fn on_collision<'a>(&self, cx: &mut FunctionContext<'a>) -> Option<Handle<'a, JsValue>>
     {
-        let pattern: Pattern = unsafe { transmute(js_pattern.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx) as u8) };
-        conv = conv.set_pattern(pattern);
+        self.on_collision.as_ref().map(|on_collision| on_collision.to_inner(cx).upcast())
     }
+}
 
-    let js_boundaries: Handle<JsValue> = options.get(&mut cx, "boundaries")?;
-    if js_boundaries.is_a::<JsArray, _>(&mut cx)
+#[cfg(feature = "json")]
+fn ndjson_new<'a>(js: &mut Neon<'a>, args: &[Handle<'a, JsValue>]) -> JsResult<'a, JsValue>
+{
+    let (stream, on_collision) = exports::ndjson_new(js, args)?;
+    let cx = js.cx();
+    let on_collision = match on_collision
     {
-        conv = conv.remove_boundaries(&Boundary::all());
-        let boundaries: Vec<Handle<JsValue>> = js_boundaries.downcast_or_throw::<JsArray, _>(&mut cx)?.to_vec(&mut cx)?;
-        for boundary in boundaries
-        {
-            conv = conv.add_boundary(unsafe { transmute(boundary.downcast::<JsNumber, _>(&mut cx).unwrap().value(&mut cx) as u8) });
-        }
-    }
+        Some(on_collision) => Some(on_collision.downcast_or_throw::<JsFunction, _>(cx)?.root(cx)),
+        None => None,
+    };
 
-    Ok(cx.string(conv.convert(str)))
+    Ok(cx.boxed(NdjsonHandle { stream: RefCell::new(stream), on_collision }).upcast())
 }
 
-fn boundary_vec_to_array<'a, C: Context<'a>>(vec: Vec<Boundary>, cx: &mut C) -> JsResult<'a, JsArray>
+#[cfg(feature = "json")]
+fn ndjson_write<'a>(js: &mut Neon<'a>, args: &[Handle<'a, JsValue>]) -> JsResult<'a, JsValue>
 {
-    let a = JsArray::new(cx, vec.len() as u32);
-
-    for (i, s) in vec.iter().enumerate()
-    {
-        let v = cx.number(*s as u8);
-        a.set(cx, i as u32, v)?;
-    }
+    let handle = js.cx().argument::<JsBox<NdjsonHandle>>(0)?;
+    let on_collision = handle.on_collision(js.cx());
+    exports::ndjson_write(js, &handle.stream, on_collision, args)
+}
 
-    Ok(a)
+#[cfg(feature = "json")]
+fn ndjson_end<'a>(js: &mut Neon<'a>, _args: &[Handle<'a, JsValue>]) -> JsResult<'a, JsValue>
+{
+    let handle = js.cx().argument::<JsBox<NdjsonHandle>>(0)?;
+    let on_collision = handle.on_collision(js.cx());
+    exports::ndjson_end(js, &handle.stream, on_collision)
 }
 
-fn js_list_from(mut cx: FunctionContext) -> JsResult<JsArray>
+/// The functions the addon exports: those of `exports` in `convert-case-core`, but for the
+/// NDJSON ones, whose handles are boxed here.
+mod functions
 {
-    let from_str: String = cx.argument::<JsString>(0)?.value(&mut cx);
-    
-    Ok(boundary_vec_to_array(Boundary::list_from(from_str.as_str()), &mut cx).unwrap())
+    pub use convert_case_core::exports::*;
+    #[cfg(feature = "json")]
+    pub(crate) use crate::{ndjson_end, ndjson_new, ndjson_write};
 }
 
-#[neon::main]
-fn main(mut cx: ModuleContext) -> NeonResult<()>
+macro_rules! export
 {
-    cx.export_function("toCase", js_case_convert)?;
-    cx.export_function("isCase", js_is_case)?;
-    cx.export_function("mutate", js_mutate_str)?;
-    cx.export_function("listFrom", js_list_from)?;
-    Ok(())
+    ($($(#[$attr:meta])* $name:literal => $function:ident $signature:literal;)*) =>
+    {
+        /// Registers the exports for one Node instance.  This runs again in every worker thread and
+        /// every Electron context that loads the addon, including each reload of a renderer, so no
+        /// state may be kept outside the `JsBox`es and handles of each instance, which is also
+        /// where the trace callback and the cache live: nothing in this crate or in
+        /// `convert-case-core` is static or process-wide, apart from the `tracing` subscriber
+        /// that collects trace events while one call runs, and initializing twice is harmless.
+        #[neon::main]
+        fn main(mut cx: ModuleContext) -> NeonResult<()>
+        {
+            $(
+                $(#[$attr])*
+                cx.export_function($name, |cx| call(cx, functions::$function))?;
+            )*
+            define_instance(&mut cx)
+        }
+    };
 }
+
+convert_case_core::for_each_export!(export);
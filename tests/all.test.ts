@@ -1,5 +1,8 @@
 import { AssertionError } from "assert";
-import { CS, Case, Boundary, Pattern } from "../main";
+import * as fs from "fs";
+import * as path from "path";
+import { Worker } from "worker_threads";
+import { CS, Case, Boundary, Pattern, convertLineKeys, convertHtmlAttributes, convertKeys, convertJsonKeys, NdjsonKeyConverter, ndjsonKeyTransform, convertYamlKeys, convertTomlKeys, convertCsvHeader, convertQueryKeys, KeyCollision, renameColumns, canonicalizeHeader, isCanonicalHeader, cssPropertyToCamel, camelToCssProperty, htmlToJsxProp, jsxToHtmlAttribute, htmlAttributesToJsx, convertGraphqlFields, convertDdlIdentifiers, protoJsonName, protoFieldName, toK8sName, toResourceName, convertOpenApiProperties, planRenames, toPackageName, toMacroName, toIncludeGuard, Converter, preset, toGetterName, toSetterName, fieldFromAccessor, TraceEvent, setTraceCallback, nativeInfo, caseConsistencyReport, lintIdentifiers, findMixedConventions, buildRenameMap, findIdentifier, matchIdentifiers, segmentationStats, isReversible, groupBySignature, discoverAcronyms, diffIdentifiers, suggestCase, setNullInput, setMaxInputLength, toCaseDetailed, applyToWord, lowerFirstWord, capitalizeFirstWord, Apostrophes, reservedWords, isReservedWord, ReservedLanguage, truncateWords, KeepCase, droppedCharacters, DroppedCharacter, setCacheSize, clearCache, cacheStats } from "../main";
 
 //#region toCase
 test("'toBe_or not-to-BE' in camelCase to be 'toBeOrNotToBe'", () => {
@@ -69,4 +72,1481 @@ test("Pattern of Case.ScreamingSnake to be Pattern.Uppercase", () => {
 test("Delim of Case.ScreamingSnake to be '_'", () => {
     expect(Case.delim(Case.ScreamingSnake)).toBe("_");
 });
-//#endregion
\ No newline at end of file
+//#endregion
+
+//#region convertLineKeys
+test("convertLineKeys converts dotenv keys to UPPER_SNAKE and leaves values and comments alone", () => {
+    expect(convertLineKeys("# database\nexport dbHost=localhost\napiKey = fooBar\n", Case.UpperSnake)).toBe("# database\nexport DB_HOST=localhost\nAPI_KEY = fooBar\n");
+});
+
+test("convertLineKeys with separator ':' converts simple YAML keys", () => {
+    expect(convertLineKeys("server:\n  listenPort: 8080\n  - hostName: a_b\n", Case.Snake, {separator: ":"})).toBe("server:\n  listen_port: 8080\n  - host_name: a_b\n");
+});
+//#endregion
+
+//#region convertHtmlAttributes
+test("convertHtmlAttributes converts attribute names only", () => {
+    expect(convertHtmlAttributes('<input maxLength=10 onClick="doThing()" disabled>Some Text</input>', Case.Kebab)).toBe('<input max-length=10 on-click="doThing()" disabled>Some Text</input>');
+});
+
+test("convertHtmlAttributes with excludeData and excludeAria keeps data-* and aria-* verbatim", () => {
+    expect(convertHtmlAttributes('<div data-userId="a" aria-labelledBy="b" tabIndex="0"></div>', Case.Kebab, {excludeData: true, excludeAria: true})).toBe('<div data-userId="a" aria-labelledBy="b" tab-index="0"></div>');
+});
+//#endregion
+
+//#region convertKeys
+test("convertKeys converts top-level keys only and keeps values by reference", () => {
+    const lastLogin = {timeZone: "UTC"};
+    const converted = convertKeys({userId: 1, lastLogin}, Case.Snake);
+    expect(converted).toEqual({user_id: 1, last_login: {timeZone: "UTC"}});
+    expect(converted.last_login).toBe(lastLogin);
+});
+
+test("convertKeys with deep and maxDepth converts nested objects and arrays of records", () => {
+    const input = {pageInfo: {hasNext: true, inner: {keepMe: 1}}, itemList: [{itemId: 1}, {itemId: 2}]};
+    expect(convertKeys(input, Case.Kebab, {deep: true, maxDepth: 2})).toEqual({"page-info": {"has-next": true, inner: {keepMe: 1}}, "item-list": [{"item-id": 1}, {"item-id": 2}]});
+});
+
+test("convertKeys leaves non-plain objects untouched", () => {
+    const date = new Date(0);
+    expect(convertKeys({createdAt: date}, Case.Snake, {deep: true}).created_at).toBe(date);
+});
+
+test("convertKeys converts Map string keys and Set string members into new collections", () => {
+    const headers = new Map<any, any>([["contentType", "text/html"], [42, "answer"], ["acceptList", {qValue: 1}]]);
+    const converted = convertKeys(headers, Case.Kebab, {deep: true});
+    expect(converted).not.toBe(headers);
+    expect([...converted.entries()]).toEqual([["content-type", "text/html"], [42, "answer"], ["accept-list", {"q-value": 1}]]);
+    expect([...convertKeys(new Set(["fooBar", 7]), Case.Snake)]).toEqual(["foo_bar", 7]);
+});
+//#endregion
+
+//#region convertJsonKeys
+test("convertJsonKeys converts nested keys and preserves order and number literals", () => {
+    expect(convertJsonKeys('{"zIndex": 1, "aValue": {"bigNumber": 12345678901234567890, "x": [{"innerKey": null}]}}', Case.Snake)).toBe('{"z_index":1,"a_value":{"big_number":12345678901234567890,"x":[{"inner_key":null}]}}');
+});
+
+test("convertJsonKeys respects maxDepth and throws on invalid JSON", () => {
+    expect(convertJsonKeys('{"outerKey": {"innerKey": 1}}', Case.Kebab, {maxDepth: 1})).toBe('{"outer-key":{"innerKey":1}}');
+    expect(() => convertJsonKeys("{not json", Case.Kebab)).toThrow(Error);
+});
+//#endregion
+
+//#region NdjsonKeyConverter
+test("NdjsonKeyConverter converts records split across chunks", () => {
+    const converter = new NdjsonKeyConverter(Case.Snake);
+    expect(converter.write('{"userId":')).toBe("");
+    expect(converter.write('1}\r\n\n{"userName"')).toBe('{"user_id":1}\r\n\n');
+    expect(converter.write(':"a"}')).toBe("");
+    expect(converter.end()).toBe('{"user_name":"a"}');
+});
+
+test("NdjsonKeyConverter throws with the line number of an invalid record", () => {
+    const converter = new NdjsonKeyConverter(Case.Snake);
+    expect(() => converter.write('{"a":1}\n{oops}\n')).toThrow("line 2");
+});
+
+test("ndjsonKeyTransform converts piped NDJSON", async () => {
+    const transform = ndjsonKeyTransform(Case.Kebab);
+    const output: string[] = [];
+    transform.on("data", (data: Buffer) => output.push(data.toString()));
+    const finished = new Promise(resolve => transform.on("end", resolve));
+    transform.write(Buffer.from('{"fooBar":"é'));
+    transform.end(Buffer.from('"}\n'));
+    await finished;
+    expect(output.join("")).toBe('{"foo-bar":"é"}\n');
+});
+//#endregion
+
+//#region convertYamlKeys
+test("convertYamlKeys converts mapping keys and preserves comments, anchors, and block scalars", () => {
+    const yaml = "# config\nimagePullPolicy: Always  # note\nbaseSpec: &base\n  restartPolicy: \"Never\"\nscript: |\n  notAKey: value\nother:\n  <<: *base\n  \"quotedKey\": {flowKey: 1}\n";
+    expect(convertYamlKeys(yaml, Case.Snake)).toBe("# config\nimage_pull_policy: Always  # note\nbase_spec: &base\n  restart_policy: \"Never\"\nscript: |\n  notAKey: value\nother:\n  <<: *base\n  \"quoted_key\": {flow_key: 1}\n");
+});
+
+test("convertYamlKeys throws on invalid YAML", () => {
+    expect(() => convertYamlKeys("key: \"unterminated", Case.Snake)).toThrow(Error);
+});
+//#endregion
+
+//#region convertTomlKeys
+test("convertTomlKeys renames tables and keys while preserving comments and order", () => {
+    const toml = "# manifest\n[packageInfo]\nzetaKey = 1 # keep\nalphaKey = { innerKey = 2 }\ndotted.keyName = 3\n\n[[binTarget]]\ntargetName = \"a\"\n";
+    expect(convertTomlKeys(toml, Case.Kebab)).toBe("# manifest\n[package-info]\nzeta-key = 1 # keep\nalpha-key = { inner-key = 2 }\ndotted.key-name = 3\n\n[[bin-target]]\ntarget-name = \"a\"\n");
+});
+
+test("convertTomlKeys throws on invalid TOML", () => {
+    expect(() => convertTomlKeys("[unclosed", Case.Kebab)).toThrow(Error);
+});
+
+test("convertTomlKeys throws instead of dropping keys that convert to the same name", () => {
+    expect(() => convertTomlKeys("fooBar = 1\nfoo_bar = 2\n", Case.Snake)).toThrow('Keys "fooBar", "foo_bar" all convert to "foo_bar"');
+    expect(() => convertTomlKeys("[outer]\ninner = { aB = 1, a_b = 2 }\n", Case.Snake)).toThrow('at "outer.inner"');
+});
+//#endregion
+
+//#region convertCsvHeader
+test("convertCsvHeader converts quoted header fields and leaves the rows untouched", () => {
+    expect(convertCsvHeader('userId,"lastName,first"\r\nfooBar,"a,b"\r\n', Case.Snake)).toBe('user_id,"last_name,first"\r\nfooBar,"a,b"\r\n');
+});
+
+test("convertCsvHeader with a custom delimiter and headerOnly returns just the header row", () => {
+    expect(convertCsvHeader("userId\tcreatedAt\n1\t2\n", Case.Kebab, {delimiter: "\t", headerOnly: true})).toBe("user-id\tcreated-at");
+});
+//#endregion
+
+//#region convertQueryKeys
+test("convertQueryKeys converts parameter names and keeps values and the leading '?'", () => {
+    expect(convertQueryKeys("?userId=1&firstName=fooBar&flag&sortBy=createdAt", Case.Snake)).toBe("?user_id=1&first_name=fooBar&flag&sort_by=createdAt");
+});
+
+test("convertQueryKeys decodes and re-encodes percent-encoded names", () => {
+    expect(convertQueryKeys("first%5FName=a&na%C3%AFveKey=b", Case.Camel)).toBe("firstName=a&na%C3%AFveKey=b");
+});
+
+test("convertQueryKeys accepts and returns URLSearchParams", () => {
+    const params = convertQueryKeys(new URLSearchParams({user_id: "1", page_size: "a b"}), Case.Camel);
+    expect(params.get("userId")).toBe("1");
+    expect(params.get("pageSize")).toBe("a b");
+});
+//#endregion
+
+//#region KeyOptions include/exclude
+test("convertKeys exclude patterns keep matching keys and their subtrees verbatim", () => {
+    const input = {userId: 1, metadata: {rawBlob: {keepThis: 1}}, httpInfo: {rawHeaders: {xRequestId: 1}, statusCode: 200}};
+    expect(convertKeys(input, Case.Snake, {deep: true, exclude: ["metadata.*", "**.rawHeaders"]})).toEqual({user_id: 1, metadata: {rawBlob: {keepThis: 1}}, http_info: {rawHeaders: {xRequestId: 1}, status_code: 200}});
+});
+
+test("convertJsonKeys include patterns rename only matching keys", () => {
+    expect(convertJsonKeys('{"pageInfo": {"hasNext": true}, "itemList": [{"itemId": 1}]}', Case.Snake, {include: ["itemList", "itemList.*"]})).toBe('{"pageInfo":{"hasNext":true},"item_list":[{"item_id":1}]}');
+});
+//#endregion
+
+//#region KeyOptions collisions
+test("convertKeys suffix policy keeps every colliding key and reports the collision", () => {
+    const reports: KeyCollision[] = [];
+    const converted = convertKeys({fooBar: 1, foo_bar: 2, nested: {aB: 3, a_b: 4}}, Case.Snake, {deep: true, collisions: "suffix", onCollision: (collision) => reports.push(collision)});
+    expect(converted).toEqual({foo_bar: 1, foo_bar_2: 2, nested: {a_b: 3, a_b_2: 4}});
+    expect(reports).toEqual([{path: "", keys: ["fooBar", "foo_bar"], name: "foo_bar"}, {path: "nested", keys: ["aB", "a_b"], name: "a_b"}]);
+});
+
+test("convertJsonKeys firstWins and error policies", () => {
+    expect(convertJsonKeys('{"fooBar": 1, "foo_bar": 2}', Case.Snake, {collisions: "firstWins"})).toBe('{"foo_bar":1}');
+    expect(() => convertJsonKeys('{"a": {"fooBar": 1, "foo_bar": 2}}', Case.Snake, {collisions: "error"})).toThrow('Keys "fooBar", "foo_bar" all convert to "foo_bar" at "a"');
+});
+//#endregion
+
+//#region KeyMappings
+test("convertKeys mappings round-trip keys a suffix policy renamed", () => {
+    const {result, forwardMap, reverseMap} = convertKeys({userID: 1, user_id: 2, tagSet: new Set(["fooBar"])}, Case.Snake, {collisions: "suffix", mappings: true});
+    expect(result).toEqual({user_id: 1, user_id_2: 2, tag_set: new Set(["fooBar"])});
+    expect([...forwardMap]).toEqual([["userID", "user_id"], ["user_id", "user_id_2"], ["tagSet", "tag_set"]]);
+    expect([...reverseMap]).toEqual([["user_id", "userID"], ["user_id_2", "user_id"], ["tag_set", "tagSet"]]);
+});
+
+test("convertJsonKeys mappings cover nested keys", () => {
+    const {result, reverseMap} = convertJsonKeys('{"pageInfo": {"hasNext": true}}', Case.Kebab, {mappings: true});
+    expect(result).toBe('{"page-info":{"has-next":true}}');
+    expect(reverseMap.get("has-next")).toBe("hasNext");
+});
+//#endregion
+
+//#region renameColumns
+test("renameColumns converts the keys of every row to a case", () => {
+    const rows = [{userId: 1, createdAt: "a"}, {userId: 2, createdAt: "b"}, null];
+    expect(renameColumns(rows, Case.Snake)).toEqual([{user_id: 1, created_at: "a"}, {user_id: 2, created_at: "b"}, null]);
+});
+
+test("renameColumns applies a mapping and keeps unmapped columns", () => {
+    expect(renameColumns([{userId: 1, createdAt: "a"}], {userId: "id"})).toEqual([{id: 1, createdAt: "a"}]);
+    expect(renameColumns([{userId: 1}], new Map([["userId", "uid"]]))).toEqual([{uid: 1}]);
+});
+//#endregion
+
+//#region KeyOptions values
+test("convertKeys converts string values at matching paths", () => {
+    const query = {sortBy: "createdAt", orderBy: ["userId", "lastName"], filter: {fieldName: "firstName", value: "keepMe"}};
+    expect(convertKeys(query, Case.Snake, {deep: true, values: ["sortBy", "orderBy", "**.fieldName"]})).toEqual({sort_by: "created_at", order_by: ["user_id", "last_name"], filter: {field_name: "first_name", value: "keepMe"}});
+});
+
+test("convertJsonKeys converts string values at matching paths", () => {
+    expect(convertJsonKeys('{"query": {"sortBy": "createdAt", "limit": 10}}', Case.Kebab, {values: ["*.sortBy"]})).toBe('{"query":{"sort-by":"created-at","limit":10}}');
+});
+//#endregion
+
+//#region KeyOptions strict
+test("convertKeys keeps null prototypes, symbol and index keys, and own __proto__ keys", () => {
+    const symbol = Symbol("tag");
+    const input = Object.assign(Object.create(null), {fooBar: 1, 10: 2, [symbol]: 3});
+    const converted = convertKeys(input, Case.Snake);
+    expect(Object.getPrototypeOf(converted)).toBe(null);
+    expect(Object.keys(converted)).toEqual(["10", "foo_bar"]);
+    expect(converted[symbol]).toBe(3);
+
+    const proto = convertKeys(JSON.parse('{"__proto__": {"polluted": true}}'), Case.Camel, {exclude: ["__proto__"]});
+    expect(Object.getPrototypeOf(proto)).toBe(Object.prototype);
+    expect(Object.keys(proto)).toEqual(["__proto__"]);
+});
+
+test("strict mode throws on ambiguous input", () => {
+    expect(() => convertKeys({createdAt: new Date()}, Case.Snake, {deep: true, strict: true})).toThrow(TypeError);
+    expect(() => convertKeys({fooBar: 1, foo_bar: 2}, Case.Snake, {strict: true})).toThrow('Keys "fooBar", "foo_bar" all convert to "foo_bar"');
+    expect(() => convertJsonKeys('{"a": {"__proto__": 1}}', Case.Snake, {strict: true})).toThrow('Strict key conversion found a "__proto__" key at "a.__proto__"');
+});
+//#endregion
+
+//#region canonicalizeHeader
+test("canonicalizeHeader applies the standard exceptions", () => {
+    expect(canonicalizeHeader("content-type")).toBe("Content-Type");
+    expect(canonicalizeHeader("etag")).toBe("ETag");
+    expect(canonicalizeHeader("www-authenticate")).toBe("WWW-Authenticate");
+    expect(canonicalizeHeader("CONTENT-MD5")).toBe("Content-MD5");
+    expect(canonicalizeHeader("te")).toBe("TE");
+    expect(canonicalizeHeader("sec-websocket-key")).toBe("Sec-WebSocket-Key");
+    expect(canonicalizeHeader("xRequestId")).toBe("X-Request-Id");
+});
+
+test("isCanonicalHeader detects canonical spellings", () => {
+    expect(isCanonicalHeader("ETag")).toBe(true);
+    expect(isCanonicalHeader("Etag")).toBe(false);
+    expect(isCanonicalHeader("content-type")).toBe(false);
+});
+//#endregion
+
+//#region CSS properties
+test("cssPropertyToCamel handles vendor prefixes and custom properties", () => {
+    expect(cssPropertyToCamel("border-top-left-radius")).toBe("borderTopLeftRadius");
+    expect(cssPropertyToCamel("-webkit-transition")).toBe("WebkitTransition");
+    expect(cssPropertyToCamel("-ms-transform")).toBe("msTransform");
+    expect(cssPropertyToCamel("--custom-props")).toBe("--custom-props");
+});
+
+test("camelToCssProperty restores vendor prefixes", () => {
+    expect(camelToCssProperty("borderTopLeftRadius")).toBe("border-top-left-radius");
+    expect(camelToCssProperty("WebkitTransition")).toBe("-webkit-transition");
+    expect(camelToCssProperty("MozOSXFontSmoothing")).toBe("-moz-osx-font-smoothing");
+    expect(camelToCssProperty("msTransform")).toBe("-ms-transform");
+    expect(camelToCssProperty("--custom-props")).toBe("--custom-props");
+});
+//#endregion
+
+//#region JSX props
+test("htmlToJsxProp and jsxToHtmlAttribute round-trip", () => {
+    const pairs = [["class", "className"], ["for", "htmlFor"], ["tabindex", "tabIndex"], ["ondblclick", "onDoubleClick"], ["onkeydown", "onKeyDown"], ["stroke-width", "strokeWidth"], ["xlink:href", "xlinkHref"], ["viewBox", "viewBox"], ["aria-label", "aria-label"], ["data-user-id", "data-user-id"], ["href", "href"]];
+    for (const [html, jsx] of pairs)
+    {
+        expect(htmlToJsxProp(html)).toBe(jsx);
+        expect(jsxToHtmlAttribute(jsx)).toBe(html);
+    }
+});
+
+test("htmlToJsxProp capitalizes an unknown event name that starts with a non-ASCII letter", () => {
+    expect(htmlToJsxProp("onéclick")).toBe("onÉclick");
+    expect(htmlToJsxProp("on日本")).toBe("on日本");
+});
+
+test("htmlAttributesToJsx renames the attributes of HTML markup", () => {
+    expect(htmlAttributesToJsx('<svg viewBox="0 0 1 1"><path stroke-width="2" class="p" data-x="1"/></svg>')).toBe('<svg viewBox="0 0 1 1"><path strokeWidth="2" className="p" data-x="1"/></svg>');
+});
+//#endregion
+
+//#region convertGraphqlFields
+test("convertGraphqlFields renames fields and arguments only", () => {
+    const sdl = `
+"""A user"""
+type User implements Node @key(fields: "user_id") {
+  user_id: ID!
+  "The display name"
+  display_name(max_length: Int = 10 @deprecated(reason: "x")): String @deprecated(reason: "use full_name")
+  # full_name: String
+}
+
+enum SORT_ORDER { CREATED_AT updated_at }
+
+input UserFilter { created_after: DateTime, tags: [String!] = ["a_b"] }
+
+extend type Query { find_user(user_id: ID!, filter: UserFilter): User }
+`;
+    const expected = `
+"""A user"""
+type User implements Node @key(fields: "user_id") {
+  userId: ID!
+  "The display name"
+  displayName(maxLength: Int = 10 @deprecated(reason: "x")): String @deprecated(reason: "use full_name")
+  # full_name: String
+}
+
+enum SORT_ORDER { CREATED_AT updated_at }
+
+input UserFilter { createdAfter: DateTime, tags: [String!] = ["a_b"] }
+
+extend type Query { findUser(userId: ID!, filter: UserFilter): User }
+`;
+    expect(convertGraphqlFields(sdl, Case.Camel)).toBe(expected);
+});
+
+test("convertGraphqlFields can return a field map for resolvers", () => {
+    expect(convertGraphqlFields("type A { a_b: Int }\ninterface B { c_d: Int }", Case.Camel, {fieldMap: true})).toEqual({sdl: "type A { aB: Int }\ninterface B { cD: Int }", fieldMap: {A: {a_b: "aB"}, B: {c_d: "cD"}}});
+    expect(() => convertGraphqlFields('type A { a: "unterminated }', Case.Camel)).toThrow("Invalid GraphQL SDL");
+});
+//#endregion
+
+//#region convertDdlIdentifiers
+test("convertDdlIdentifiers converts CREATE TABLE columns and constraints", () => {
+    const sql = `CREATE TABLE IF NOT EXISTS public.userAccounts (
+    userId BIGINT NOT NULL,
+    "keepMe" TEXT DEFAULT 'fooBar', -- createdAt stays in comments
+    sortOrder INT CHECK (sortOrder >= 0),
+    teamId INT CONSTRAINT fkTeam REFERENCES teams (teamId),
+    CONSTRAINT pkUserAccounts PRIMARY KEY (userId, teamId),
+    UNIQUE KEY uqSort (sortOrder)
+);
+SELECT userId FROM userAccounts;`;
+    const expected = `CREATE TABLE IF NOT EXISTS public.userAccounts (
+    user_id BIGINT NOT NULL,
+    "keepMe" TEXT DEFAULT 'fooBar', -- createdAt stays in comments
+    sort_order INT CHECK (sort_order >= 0),
+    team_id INT CONSTRAINT fk_team REFERENCES teams (team_id),
+    CONSTRAINT pk_user_accounts PRIMARY KEY (user_id, team_id),
+    UNIQUE KEY uq_sort (sort_order)
+);
+SELECT userId FROM userAccounts;`;
+    expect(convertDdlIdentifiers(sql, Case.Snake)).toBe(expected);
+});
+
+test("convertDdlIdentifiers converts ALTER TABLE actions and quotes reserved words", () => {
+    expect(convertDdlIdentifiers("ALTER TABLE t ADD COLUMN Order INT, RENAME COLUMN oldName TO newName, DROP CONSTRAINT IF EXISTS fkOld", Case.Snake)).toBe('ALTER TABLE t ADD COLUMN "order" INT, RENAME COLUMN old_name TO new_name, DROP CONSTRAINT IF EXISTS fk_old');
+    expect(() => convertDdlIdentifiers("CREATE TABLE t (a TEXT DEFAULT 'x)", Case.Snake)).toThrow("Invalid SQL");
+});
+//#endregion
+
+//#region protobuf JSON names
+test("protoJsonName matches protoc", () => {
+    const names = [["foo_bar", "fooBar"], ["field_1_name", "field1Name"], ["foo_1bar", "foo1bar"], ["FOO_bar", "FOOBar"], ["_foo", "Foo"], ["foo__bar", "fooBar"], ["foo_", "foo"], ["http2_port", "http2Port"]];
+    for (const [field, json] of names)
+    {
+        expect(protoJsonName(field)).toBe(json);
+    }
+});
+
+test("protoFieldName inverts protoJsonName", () => {
+    for (const field of ["foo_bar", "user_id2", "a_b_c"])
+    {
+        expect(protoFieldName(protoJsonName(field))).toBe(field);
+    }
+    expect(protoFieldName("field1Name")).toBe("field1_name");
+    expect(() => protoFieldName("foo_bar")).toThrow("Invalid protobuf JSON name");
+});
+//#endregion
+
+//#region toK8sName
+test("toK8sName produces RFC 1123 labels", () => {
+    expect(toK8sName("PaymentAPI v2Worker")).toEqual({name: "payment-api-v2-worker", truncated: false});
+    expect(toK8sName("--my_app.Staging!--")).toEqual({name: "my-app-staging", truncated: false});
+    expect(toK8sName("Café Über 2")).toEqual({name: "caf-ber-2", truncated: false});
+    expect(() => toK8sName("__")).toThrow(Error);
+});
+
+test("toK8sName truncates at word boundaries", () => {
+    const long = toK8sName("the quick brown fox jumps over the lazy dog and keeps on running far away");
+    expect(long).toEqual({name: "the-quick-brown-fox-jumps-over-the-lazy-dog-and-keeps-on", truncated: true});
+    expect(toK8sName("a".repeat(70) + " b")).toEqual({name: "a".repeat(63), truncated: true});
+});
+//#endregion
+
+//#region toResourceName
+test("toResourceName converts and filters to a charset", () => {
+    expect(toResourceName("OrdersService (prod)")).toBe("orders-service-prod");
+    expect(toResourceName("Orders Service #2", {case: Case.Flat, charset: "a-z0-9"})).toBe("ordersservice2");
+    expect(() => toResourceName("x", {charset: "a-z"})).toThrow(RangeError);
+    expect(() => toResourceName("!!!")).toThrow(Error);
+});
+
+test("toResourceName truncates at word boundaries and appends a hash", () => {
+    const input = "Orders Service Production East US";
+    expect(toResourceName(input, {maxLength: 32})).toBe("orders-service-production-east");
+    const hashed = toResourceName(input, {maxLength: 32, hashSuffixOnTruncate: true});
+    expect(hashed).toMatch(/^orders-service-production-[0-9a-f]{6}$/);
+    expect(toResourceName(input + " 2", {maxLength: 32, hashSuffixOnTruncate: true})).not.toBe(hashed);
+    expect(toResourceName(input, {case: Case.UpperSnake, charset: "A-Z0-9_", maxLength: 24, hashSuffixOnTruncate: true})).toMatch(/^ORDERS_SERVICE_[0-9A-F]{6}$/);
+    expect(() => toResourceName(input, {maxLength: 7, hashSuffixOnTruncate: true})).toThrow(RangeError);
+});
+//#endregion
+
+//#region KeyOptions renameAllCompat
+test("renameAllCompat matches serde's variant renames", () => {
+    const variants = [
+        ["Outcome", "outcome", "OUTCOME", "outcome", "outcome", "OUTCOME", "outcome", "OUTCOME"],
+        ["VeryTasty", "verytasty", "VERYTASTY", "veryTasty", "very_tasty", "VERY_TASTY", "very-tasty", "VERY-TASTY"],
+        ["A", "a", "A", "a", "a", "A", "a", "A"],
+        ["Z42", "z42", "Z42", "z42", "z42", "Z42", "z42", "Z42"],
+        ["IOError", "ioerror", "IOERROR", "iOError", "i_o_error", "I_O_ERROR", "i-o-error", "I-O-ERROR"],
+        ["Http2Client", "http2client", "HTTP2CLIENT", "http2Client", "http2_client", "HTTP2_CLIENT", "http2-client", "HTTP2-CLIENT"],
+    ];
+    const cases = [Case.Flat, Case.UpperFlat, Case.Camel, Case.Snake, Case.UpperSnake, Case.Kebab, Case.Cobol];
+    for (const [variant, ...renamed] of variants)
+    {
+        cases.forEach((caseType, i) => {
+            expect(convertKeys({[variant]: 1}, caseType, {renameAllCompat: true})).toEqual({[renamed[i]]: 1});
+        });
+        expect(convertKeys({[variant]: 1}, Case.Pascal, {renameAllCompat: true})).toEqual({[variant]: 1});
+    }
+});
+
+test("renameAllCompat matches serde's field renames", () => {
+    const fields = [
+        ["outcome", "OUTCOME", "Outcome", "outcome", "OUTCOME", "outcome", "OUTCOME"],
+        ["very_tasty", "VERY_TASTY", "VeryTasty", "veryTasty", "VERY_TASTY", "very-tasty", "VERY-TASTY"],
+        ["a", "A", "A", "a", "A", "a", "A"],
+        ["z42", "Z42", "Z42", "z42", "Z42", "z42", "Z42"],
+        ["http_2_server", "HTTP_2_SERVER", "Http2Server", "http2Server", "HTTP_2_SERVER", "http-2-server", "HTTP-2-SERVER"],
+        ["version_2beta", "VERSION_2BETA", "Version2beta", "version2beta", "VERSION_2BETA", "version-2beta", "VERSION-2BETA"],
+        ["r#type", "TYPE", "Type", "type", "TYPE", "type", "TYPE"],
+    ];
+    const cases = [Case.UpperFlat, Case.Pascal, Case.Camel, Case.UpperSnake, Case.Kebab, Case.Cobol];
+    for (const [field, ...renamed] of fields)
+    {
+        cases.forEach((caseType, i) => {
+            expect(convertJsonKeys(JSON.stringify({[field]: 1}), caseType, {renameAllCompat: true})).toBe(JSON.stringify({[renamed[i]]: 1}));
+        });
+    }
+    expect(convertKeys({sort_by: "created_at"}, Case.Camel, {renameAllCompat: true, values: ["sort_by"]})).toEqual({sortBy: "createdAt"});
+    expect(() => convertKeys({a: 1}, Case.Train, {renameAllCompat: true})).toThrow(RangeError);
+});
+//#endregion
+
+//#region convertOpenApiProperties
+test("convertOpenApiProperties renames schema properties and parameters", () => {
+    const doc = {
+        openapi: "3.1.0",
+        paths: {
+            "/users/{userId}": {
+                parameters: [
+                    {name: "userId", in: "path", required: true, schema: {type: "string"}},
+                    {name: "pageSize", in: "query", schema: {type: "integer", default: 10}},
+                    {name: "X-Request-Id", in: "header", schema: {type: "string"}},
+                ],
+                get: {
+                    responses: {
+                        "200": {content: {"application/json": {schema: {$ref: "#/components/schemas/UserAccount"}}}},
+                    },
+                },
+            },
+        },
+        components: {
+            schemas: {
+                UserAccount: {
+                    type: "object",
+                    required: ["userId", "accountType"],
+                    discriminator: {propertyName: "accountType", mapping: {freeTier: "#/components/schemas/FreeTier"}},
+                    properties: {
+                        userId: {type: "string"},
+                        accountType: {type: "string", enum: ["freeTier", "paidTier"]},
+                        homeAddress: {type: "object", properties: {postCode: {type: "string"}}, example: {postCode: "1"}},
+                        tagList: {type: "array", items: {properties: {tagName: {type: "string"}}}},
+                    },
+                },
+            },
+        },
+    };
+    const converted = JSON.parse(convertOpenApiProperties(JSON.stringify(doc), Case.Snake));
+    const account = converted.components.schemas.UserAccount;
+    expect(Object.keys(account.properties)).toEqual(["user_id", "account_type", "home_address", "tag_list"]);
+    expect(account.required).toEqual(["user_id", "account_type"]);
+    expect(account.discriminator).toEqual({propertyName: "account_type", mapping: {freeTier: "#/components/schemas/FreeTier"}});
+    expect(account.properties.account_type.enum).toEqual(["freeTier", "paidTier"]);
+    expect(account.properties.home_address).toEqual({type: "object", properties: {post_code: {type: "string"}}, example: {postCode: "1"}});
+    expect(account.properties.tag_list.items.properties).toEqual({tag_name: {type: "string"}});
+
+    const path = converted.paths["/users/{userId}"];
+    expect(path.parameters.map((parameter: any) => parameter.name)).toEqual(["userId", "page_size", "X-Request-Id"]);
+    expect(path.get.responses["200"].content["application/json"].schema).toEqual({$ref: "#/components/schemas/UserAccount"});
+});
+
+test("convertOpenApiProperties rejects invalid JSON", () => {
+    expect(() => convertOpenApiProperties("{paths: 1}", Case.Snake)).toThrow("Invalid JSON");
+});
+//#endregion
+
+//#region planRenames
+test("planRenames keeps directories and extensions and reports collisions", () => {
+    const plan = planRenames(["src/myComponent.test.tsx", "C:\\Users\\Me\\toDoList.TXT", ".eslintRc", "aB.txt", "a_b.txt", "AB.txt", "fooBar.md"], Case.Snake);
+    expect(plan.steps).toEqual([
+        {from: "src/myComponent.test.tsx", to: "src/my_component.test.tsx"},
+        {from: "C:\\Users\\Me\\toDoList.TXT", to: "C:\\Users\\Me\\to_do_list.TXT"},
+        {from: ".eslintRc", to: ".eslint_rc"},
+        {from: "fooBar.md", to: "foo_bar.md"},
+    ]);
+    expect(plan.collisions).toEqual([{target: "a_b.txt", sources: ["aB.txt", "a_b.txt"]}, {target: "ab.txt", sources: ["aB.txt", "AB.txt"]}]);
+});
+
+test("planRenames compares paths case-insensitively", () => {
+    expect(planRenames(["x/FooBar", "x/foo-bar"], Case.Kebab).collisions).toEqual([{target: "x/foo-bar", sources: ["x/FooBar", "x/foo-bar"]}]);
+    expect(planRenames(["Readme.md"], Case.Lower).steps).toEqual([{from: "Readme.md", to: "Readme.md.renaming"}, {from: "Readme.md.renaming", to: "readme.md"}]);
+    expect(planRenames(["fooBar", "foo_bar_baz"], Case.Snake).steps).toEqual([{from: "fooBar", to: "foo_bar"}]);
+});
+//#endregion
+
+//#region toPackageName
+test("toPackageName follows each registry's rules", () => {
+    expect(toPackageName("  My Cool Project! (v2) ")).toBe("my-cool-project-v2");
+    expect(toPackageName("@Acme Corp/Web3 Utils")).toBe("@acme-corp/web3-utils");
+    expect(toPackageName("HTTPClient for Node.js", {registry: "crates"})).toBe("http_client_for_node_js");
+    expect(toPackageName("Django REST Tools", {registry: "pypi"})).toBe("django-rest-tools");
+    expect(toPackageName("@Acme Corp/Web3 Utils", {registry: "pypi"})).toBe("acme-corp-web3-utils");
+});
+
+test("toPackageName enforces length and leading characters", () => {
+    const long = toPackageName(Array(30).fill("component").join(" "), {registry: "crates"});
+    expect(long).toBe(Array(6).fill("component").join("_"));
+    expect(toPackageName("@scope/" + "x".repeat(300)).length).toBe(214);
+    expect(() => toPackageName("3D Engine", {registry: "crates"})).toThrow("can't start with a digit");
+    expect(() => toPackageName("Self", {registry: "crates"})).toThrow("reserved");
+    expect(() => toPackageName("???")).toThrow(Error);
+    expect(() => toPackageName("x", {registry: "maven" as any})).toThrow(RangeError);
+});
+//#endregion
+
+//#region C macros
+test("toMacroName produces valid macro names", () => {
+    expect(toMacroName("maxBufferSize")).toBe("MAX_BUFFER_SIZE");
+    expect(toMacroName("__HTTP--Parser v2__")).toBe("HTTP_PARSER_V2");
+    expect(toMacroName("sha256Sum")).toBe("SHA256_SUM");
+    expect(toMacroName("3d-enabled")).toBe("X_3D_ENABLED");
+    expect(toMacroName("naïve café")).toBe("NAVE_CAF");
+    expect(() => toMacroName("--")).toThrow(Error);
+});
+
+test("toIncludeGuard collapses path separators", () => {
+    expect(toIncludeGuard("include/my-lib/httpClient.h")).toBe("INCLUDE_MY_LIB_HTTP_CLIENT_H");
+    expect(toIncludeGuard("./src//util.h")).toBe(toIncludeGuard("../src/util.h"));
+    expect(toIncludeGuard("C:\\work\\proj\\_private\\config.hpp")).toBe("WORK_PROJ_PRIVATE_CONFIG_HPP");
+});
+//#endregion
+
+//#region Presets
+test("preset follows each language's style guide", () => {
+    expect(preset("rust.struct").convert("HTTP server")).toBe("HttpServer");
+    expect(preset("rust.function").convert("parseMP3Header")).toBe("parse_mp3_header");
+    expect(preset("rust.constant").convert("maxUtf8Size")).toBe("MAX_UTF8_SIZE");
+    expect(preset("java.method").convert("get_user_id")).toBe("getUserId");
+    expect(preset("java.package").convert("My Package")).toBe("mypackage");
+    expect(preset("python.module").convert("Utf8Decoder")).toBe("utf8_decoder");
+    expect(preset("javascript.class").convert("xml_http_request")).toBe("XmlHttpRequest");
+});
+
+test("preset returns a configured Converter", () => {
+    const converter = preset("python.constant");
+    expect(converter).toBeInstanceOf(Converter);
+    expect(converter.delim).toBe("_");
+    expect(converter.pattern).toBe(Pattern.Uppercase);
+    expect(new Converter({boundaries: converter.boundaries, pattern: Pattern.Lowercase, delim: "."}).convert("fooBar2Baz")).toBe("foo.bar2.baz");
+    expect(() => preset("cobol.paragraph" as any)).toThrow(RangeError);
+});
+//#endregion
+
+//#region Accessors
+test("toGetterName and toSetterName follow bean conventions", () => {
+    expect(toGetterName("firstName")).toBe("getFirstName");
+    expect(toSetterName("m_firstName")).toBe("setFirstName");
+    expect(toGetterName("xCoordinate")).toBe("getxCoordinate");
+    expect(toGetterName("active", {boolean: true})).toBe("isActive");
+    expect(toGetterName("isActive", {boolean: true})).toBe("isActive");
+    expect(toSetterName("isActive", {boolean: true})).toBe("setActive");
+    expect(toGetterName("#xCoordinate", {lang: "javascript"})).toBe("getXCoordinate");
+    expect(toGetterName("_firstName", {lang: "csharp"})).toBe("FirstName");
+    expect(toSetterName("firstName", {lang: "csharp", boolean: true})).toBe("FirstName");
+    expect(() => toGetterName("x", {lang: "go" as any})).toThrow(RangeError);
+});
+
+test("fieldFromAccessor inverts each language's rules", () => {
+    expect(fieldFromAccessor("getFirstName")).toBe("firstName");
+    expect(fieldFromAccessor("getURLValue")).toBe("URLValue");
+    expect(fieldFromAccessor("getUrl")).toBe("url");
+    expect(fieldFromAccessor("getxCoordinate")).toBe("xCoordinate");
+    expect(fieldFromAccessor("isActive")).toBe("active");
+    expect(fieldFromAccessor("isolate")).toBeNull();
+    expect(fieldFromAccessor("get")).toBeNull();
+    expect(fieldFromAccessor("getURLValue", {lang: "javascript"})).toBe("urlValue");
+    expect(fieldFromAccessor("getxCoordinate", {lang: "javascript"})).toBeNull();
+    expect(fieldFromAccessor("IOStream", {lang: "csharp"})).toBe("ioStream");
+    expect(fieldFromAccessor("set_FirstName", {lang: "csharp"})).toBe("firstName");
+});
+//#endregion
+
+//#region worker_threads
+// Each worker loads its own instance of the addon, using it the way main.ts does
+const workerSource = `
+const { parentPort, workerData } = require("worker_threads");
+const rsBind = require(workerData.addon);
+const names = [];
+for (let i = 0; i < 200; i++) {
+    names.push(rsBind.toCase("workerThread " + i, workerData.snake, undefined));
+}
+const stream = rsBind.ndjsonNew(workerData.snake, {});
+const ndjson = rsBind.ndjsonWrite(stream, '{"workerId":' + workerData.id + '}\\n') + rsBind.ndjsonEnd(stream);
+const keys = rsBind.convertKeys({workerId: workerData.id, nestedValue: [{innerKey: 1}]}, workerData.snake, {deep: true});
+parentPort.postMessage({names, ndjson, keys});
+`;
+
+function runWorkers(count: number): Promise<any[]>
+{
+    const addon = path.join(__dirname, "..", "index.node");
+    return Promise.all(Array.from({length: count}, (_, id) => new Promise((resolve, reject) => {
+        const worker = new Worker(workerSource, {eval: true, workerData: {addon, id, snake: Case.Snake}});
+        worker.once("message", resolve);
+        worker.once("error", reject);
+    })));
+}
+
+test("the addon converts in several worker threads at once", async () => {
+    const results = await runWorkers(4);
+    results.forEach((result, id) => {
+        expect(result.names).toHaveLength(200);
+        expect(result.names[199]).toBe("worker_thread_199");
+        expect(result.ndjson).toBe(`{"worker_id":${id}}\n`);
+        expect(result.keys).toEqual({worker_id: id, nested_value: [{inner_key: 1}]});
+    });
+});
+
+test("the addon loads again in new workers after others have exited", async () => {
+    await runWorkers(2);
+    const results = await runWorkers(2);
+    expect(results[1].names[0]).toBe("worker_thread_0");
+    expect(CS("workerThread").toCase(Case.Snake).toString()).toBe("worker_thread");
+});
+
+test("terminating a worker in the middle of conversions doesn't leave anything running", async () => {
+    // The worker holds an open NDJSON stream and a trace callback while it converts in a loop
+    const source = `
+const { parentPort, workerData } = require("worker_threads");
+const rsBind = require(workerData.addon);
+rsBind.setTraceCallback(() => {});
+const stream = rsBind.ndjsonNew(workerData.snake, {});
+parentPort.postMessage("converting");
+for (let i = 0; ; i++) {
+    rsBind.ndjsonWrite(stream, '{"recordId":' + i + '}\\n');
+    rsBind.toCase("workerThread " + i, workerData.snake, undefined);
+}
+`;
+    const addon = path.join(__dirname, "..", "index.node");
+    const worker = new Worker(source, {eval: true, workerData: {addon, snake: Case.Snake}});
+    await new Promise((resolve) => worker.once("message", resolve));
+
+    expect(await worker.terminate()).toBe(1);
+    expect(CS("workerThread").toCase(Case.Snake).toString()).toBe("worker_thread");
+});
+//#endregion
+
+//#region Electron contexts
+test("the addon can be initialized more than once in the same thread", () => {
+    // Electron does this for every renderer context and every reload of one
+    const addon = path.join(__dirname, "..", "index.node");
+    const first = {exports: {} as any};
+    const second = {exports: {} as any};
+    process.dlopen(first, addon);
+    process.dlopen(second, addon);
+
+    expect(first.exports).not.toBe(second.exports);
+    expect(first.exports.toCase("rendererProcess", Case.Kebab, undefined)).toBe("renderer-process");
+    expect(second.exports.toCase("mainProcess", Case.Kebab, undefined)).toBe("main-process");
+    const stream = second.exports.ndjsonNew(Case.Snake, {});
+    expect(second.exports.ndjsonWrite(stream, '{"reloadCount":2}\n')).toBe('{"reload_count":2}\n');
+});
+//#endregion
+
+//#region Pattern functions
+test("mutate calls a pattern function for each word", () => {
+    const seen: [string, number][] = [];
+    const upperSecond = (word: string, i: number) => {
+        seen.push([word, i]);
+        return i == 1 ? word.toUpperCase() : word.toLowerCase();
+    };
+    expect(CS("xmlHttpRequest").mutate({pattern: upperSecond, delim: "_"}).toString()).toBe("xml_HTTP_request");
+    expect(seen).toEqual([["xml", 0], ["Http", 1], ["Request", 2]]);
+    expect(CS("").mutate({pattern: upperSecond, delim: "_"}).toString()).toBe("");
+    expect(CS("a-b").mutate({pattern: (word) => `[${word}]`, boundaries: [Boundary.Hyphen]}).toString()).toBe("[a][b]");
+    expect(() => CS("a b").mutate({pattern: () => 1 as any})).toThrow(TypeError);
+});
+//#endregion
+
+//#region Tracing
+test("setTraceCallback reports how strings are split", () => {
+    const events: TraceEvent[] = [];
+    setTraceCallback(event => events.push(event));
+    try {
+        CS("XMLHttpRequest").toCase(Case.Snake);
+        convertKeys({userId: 1}, Case.Snake);
+    } finally {
+        setTraceCallback(null);
+    }
+    CS("untraced").toCase(Case.Snake);
+
+    expect(events).toEqual([
+        {kind: "split", input: "XMLHttpRequest", words: ["XML", "Http", "Request"]},
+        {kind: "split", input: "userId", words: ["user", "Id"]},
+    ]);
+});
+
+test("setTraceCallback reports the words the options split a string into", () => {
+    const events: TraceEvent[] = [];
+    setTraceCallback(event => events.push(event));
+    try {
+        expect(CS("MAX_1_000_RETRIES").toCase(Case.Camel, undefined, {keepNumericSeparators: true}).toString()).toBe("max1_000Retries");
+        expect(CS("don't stop").toCase(Case.Snake, undefined, {apostrophes: "strip"}).toString()).toBe("dont_stop");
+        expect(CS("foo__bar").toCase(Case.Kebab, undefined, {collapseDelims: false}).toString()).toBe("foo--bar");
+    } finally {
+        setTraceCallback(null);
+    }
+
+    expect(events).toEqual([
+        {kind: "split", input: "MAX_1_000_RETRIES", words: ["MAX", "1_000", "RETRIES"]},
+        {kind: "split", input: "don't stop", words: ["dont", "stop"]},
+        {kind: "split", input: "foo__bar", words: ["foo", "", "bar"]},
+    ]);
+});
+
+test("setTraceCallback reports cache hits", () => {
+    const events: TraceEvent[] = [];
+    setCacheSize(16);
+    setTraceCallback(event => events.push(event));
+    try {
+        CS("cachedKey").toCase(Case.Snake);
+        CS("cachedKey").toCase(Case.Snake);
+    } finally {
+        setTraceCallback(null);
+        setCacheSize(0);
+    }
+
+    expect(events).toEqual([
+        {kind: "split", input: "cachedKey", words: ["cached", "Key"]},
+        {kind: "cacheHit", input: "cachedKey", result: "cached_key"},
+    ]);
+});
+
+test("setTraceCallback reports fallbacks", () => {
+    const events: TraceEvent[] = [];
+    setTraceCallback(event => events.push(event));
+    try {
+        expect(toMacroName("3d-engine")).toBe("X_3D_ENGINE");
+    } finally {
+        setTraceCallback(null);
+    }
+
+    expect(events).toEqual([
+        {kind: "fallback", function: "toMacroName", input: "3d-engine", reason: 'prefixed "3D_ENGINE" with X_ since it starts with a digit'},
+    ]);
+});
+//#endregion
+
+//#region nativeInfo
+test("nativeInfo describes the native library", () => {
+    const info = nativeInfo();
+    expect(info.version).toMatch(/^\d+\.\d+\.\d+$/);
+    expect(info.convertCaseVersion).toBe("0.6.0");
+    expect(info.features).toEqual(["json", "yaml", "toml", "csv", "random"]);
+    expect(info.unicodeVersion).toMatch(/^\d+\.\d+\.\d+$/);
+    expect(info.caseIds).toEqual({min: Case.Upper, max: Case.PseudoRandom});
+    expect(info.patternIds).toEqual({min: Pattern.Lowercase, max: Pattern.PseudoRandom});
+    expect(info.boundaryIds).toEqual({min: Boundary.Hyphen, max: Boundary.Acronym});
+});
+//#endregion
+
+//#region Generated typings
+test("the enums in index.node.d.ts have the same ids as Case, Pattern, and Boundary", () => {
+    const dts = fs.readFileSync(path.join(__dirname, "..", "index.node.d.ts"), "utf8");
+    const generated = (name: string) => {
+        const body = dts.match(new RegExp(`export const enum ${name}\\s*{([^}]*)}`))![1];
+        return Object.fromEntries([...body.matchAll(/(\w+) = (\d+)/g)].map(([, member, id]) => [member, Number(id)]));
+    };
+    const members = (e: object) => Object.fromEntries(Object.entries(e).filter(([, id]) => typeof id === "number"));
+
+    expect(generated("Case")).toEqual(members(Case));
+    expect(generated("Pattern")).toEqual(members(Pattern));
+    expect(generated("Boundary")).toEqual(members(Boundary));
+});
+//#endregion
+
+//#region Ids
+test("the addon throws a RangeError for ids that aren't a Case, Pattern, or Boundary", () => {
+    // Called without main.ts, which asserts the ids before the addon ever sees them
+    const rsBind = require("../index.node");
+    for (const id of [99, -1, 1.5, 256, NaN])
+    {
+        expect(() => rsBind.toCase("fooBar", id, undefined)).toThrow(RangeError);
+        expect(() => rsBind.isCase("fooBar", id)).toThrow(RangeError);
+        expect(() => rsBind.applyToWord("fooBar", 0, id)).toThrow(RangeError);
+        expect(() => rsBind.mutate("fooBar", {boundaries: [id]})).toThrow(RangeError);
+    }
+    expect(() => rsBind.toCase("fooBar", Case.Snake, 99)).toThrow("99 isn't the id of a Case");
+    expect(() => rsBind.mutate("fooBar", {boundaries: ["hyphen"]})).toThrow(TypeError);
+});
+//#endregion
+
+//#region caseConsistencyReport
+test("caseConsistencyReport infers the convention and suggests fixes for outliers", () => {
+    const report = caseConsistencyReport(["userId", "name", "createdAt", "created_at", "HTTP_server"]);
+    expect(report.convention).toBe(Case.Camel);
+    expect(report.counts[0]).toEqual({case: Case.Camel, count: 3});
+    expect(report.counts.find((count) => count.case === Case.Snake)).toEqual({case: Case.Snake, count: 2});
+    expect(report.outliers).toEqual([
+        {identifier: "created_at", cases: [Case.Snake], mixed: null, suggestion: "createdAt"},
+        {identifier: "HTTP_server", cases: [], mixed: "mixes uppercase and lowercase words", suggestion: "httpServer"},
+    ]);
+});
+
+test("caseConsistencyReport breaks ties between single words by the first convention", () => {
+    expect(caseConsistencyReport(["id", "name"]).convention).toBe(Case.Camel);
+    expect(caseConsistencyReport(["id", "user_id"]).convention).toBe(Case.Snake);
+    const empty = caseConsistencyReport([]);
+    expect(empty.convention).toBeNull();
+    expect(empty.outliers).toEqual([]);
+});
+//#endregion
+
+//#region lintIdentifiers
+test("lintIdentifiers reports the offending boundaries and fixes", () => {
+    expect(lintIdentifiers(["user_id", "user2Name", "user-name", "USER_ID"], Case.Snake)).toEqual([
+        {index: 1, identifier: "user2Name", severity: "error", problems: [
+            {boundary: Boundary.DigitUpper, message: "contains uppercase after digit"},
+            {boundary: Boundary.LowerDigit, message: "contains digit after lowercase"},
+        ], fix: "user_2_name"},
+        {index: 2, identifier: "user-name", severity: "error", problems: [{boundary: Boundary.Hyphen, message: "contains a hyphen"}], fix: "user_name"},
+        {index: 3, identifier: "USER_ID", severity: "warning", problems: [{boundary: null, message: "letters aren't cased as snake case requires"}], fix: "user_id"},
+    ]);
+});
+
+test("lintIdentifiers skips allowed names and can ignore leading underscores", () => {
+    const options = {allow: ["XMLHttpRequest"], allowLeadingUnderscore: true};
+    expect(lintIdentifiers(["XMLHttpRequest", "_privateName", "__proto__"], Case.Camel, options)).toEqual([
+        {index: 2, identifier: "__proto__", severity: "error", problems: [{boundary: Boundary.Underscore, message: "contains an underscore"}], fix: "__proto"},
+    ]);
+});
+//#endregion
+
+//#region findMixedConventions
+test("findMixedConventions flags identifiers that mix conventions within themselves", () => {
+    const identifiers = ["userId", "USER_ID", "user_Id", "HTTP_server_Count", "user_firstName", "user-name_id", "HTTPServer", "get_A_value"];
+    expect(findMixedConventions(identifiers)).toEqual([
+        {index: 2, identifier: "user_Id", reason: "mixes lowercase and capitalized words"},
+        {index: 3, identifier: "HTTP_server_Count", reason: "mixes uppercase, lowercase, and capitalized words"},
+        {index: 4, identifier: "user_firstName", reason: "separates words with both `_` and capital letters"},
+        {index: 5, identifier: "user-name_id", reason: "separates words with both `-` and `_`"},
+        {index: 7, identifier: "get_A_value", reason: "mixes lowercase and capitalized words"},
+    ]);
+});
+//#endregion
+
+//#region buildRenameMap
+test("buildRenameMap settles collisions the same way whatever the order of the set", () => {
+    const expected = [["USER_ID", "user_id_2"], ["user-id", "user_id_3"], ["userName", "user_name"], ["user_id", "user_id"]];
+    expect([...buildRenameMap(["userName", "user-id", "user_id", "USER_ID"], Case.Snake)]).toEqual(expected);
+    expect([...buildRenameMap(["USER_ID", "user_id", "userName", "user-id", "userName"], Case.Snake)]).toEqual(expected);
+});
+
+test("buildRenameMap suffixes past names other identifiers already convert to", () => {
+    expect([...buildRenameMap(["fooBar", "FooBar", "foo_bar_2"], Case.Camel)]).toEqual([
+        ["FooBar", "fooBar3"], ["fooBar", "fooBar"], ["foo_bar_2", "fooBar2"],
+    ]);
+});
+//#endregion
+
+//#region findIdentifier
+test("findIdentifier finds an identifier in every case it is written in", () => {
+    const text = "const USER_ID = row.user_id ?? getUserId(); // user-id, userIdentity";
+    expect(findIdentifier(text, "userId")).toEqual([
+        {start: 6, end: 13, text: "USER_ID", cases: [Case.UpperSnake]},
+        {start: 20, end: 27, text: "user_id", cases: [Case.Snake]},
+        {start: 34, end: 40, text: "UserId", cases: [Case.Pascal]},
+        {start: 47, end: 54, text: "user-id", cases: [Case.Kebab]},
+    ]);
+});
+
+test("findIdentifier reports spans in UTF-16 indices", () => {
+    const text = "😀 naïveName, naïve_name";
+    const found = findIdentifier(text, "NaïveName");
+    expect(found.map((occurrence) => text.slice(occurrence.start, occurrence.end))).toEqual(["naïveName", "naïve_name"]);
+});
+//#endregion
+
+//#region matchIdentifiers
+test("matchIdentifiers finds candidates by their words in any case", () => {
+    expect(matchIdentifiers("usrId", ["userName", "user_id", "UserID", "groupId"])).toEqual([
+        {candidate: "user_id", index: 1, score: 0.8125},
+        {candidate: "UserID", index: 2, score: 0.8125},
+        {candidate: "groupId", index: 3, score: 0.5},
+        {candidate: "userName", index: 0, score: 0.3125},
+    ]);
+});
+
+test("matchIdentifiers ranks words in order above words out of order, and takes a limit", () => {
+    const found = matchIdentifiers("user-name", ["nameUser", "USER_NAME", "user_full_name"], {limit: 2});
+    expect(found.map((match) => match.candidate)).toEqual(["USER_NAME", "user_full_name"]);
+    expect(found[0].score).toBe(1);
+    expect(matchIdentifiers("user-name", ["nameUser"], {minScore: 0.9})).toEqual([]);
+});
+//#endregion
+
+//#region segmentationStats
+test("segmentationStats reports words, character classes, and the boundaries that fired", () => {
+    expect(segmentationStats(["parseHTTPResponse2", "user_id_list"])).toEqual([
+        {
+            input: "parseHTTPResponse2",
+            wordCount: 4,
+            words: ["parse", "HTTP", "Response", "2"],
+            characterClasses: ["lowercase", "uppercase", "digit"],
+            boundaries: [{boundary: Boundary.LowerUpper, count: 1}, {boundary: Boundary.LowerDigit, count: 1}, {boundary: Boundary.Acronym, count: 1}],
+        },
+        {
+            input: "user_id_list",
+            wordCount: 3,
+            words: ["user", "id", "list"],
+            characterClasses: ["lowercase", "delimiter"],
+            boundaries: [{boundary: Boundary.Underscore, count: 2}],
+        },
+    ]);
+});
+
+test("segmentationStats only counts the boundaries it is given", () => {
+    const [stats] = segmentationStats(["parseHTTPResponse2"], {boundaries: [Boundary.LowerUpper]});
+    expect(stats.words).toEqual(["parse", "HTTPResponse2"]);
+    expect(stats.boundaries).toEqual([{boundary: Boundary.LowerUpper, count: 1}]);
+});
+//#endregion
+
+//#region isReversible
+test("isReversible recovers identifiers that lose nothing", () => {
+    expect(isReversible("user_id", Case.Snake, Case.Camel)).toEqual({reversible: true, forward: "userId", back: "user_id", losses: []});
+});
+
+test("isReversible reports dropped delimiters, flattened acronyms, and split digits", () => {
+    expect(isReversible("getHTTPResponse", Case.Camel, Case.Snake)).toEqual({
+        reversible: false,
+        forward: "get_http_response",
+        back: "getHttpResponse",
+        losses: [{kind: "acronym", message: "`HTTP` comes back as `Http`"}],
+    });
+    expect(isReversible("_user_id", Case.Snake, Case.Camel).losses).toEqual([
+        {kind: "delimiters", message: "delimiters that don't separate words are dropped"},
+    ]);
+    expect(isReversible("v2_api", Case.Snake, Case.Camel).losses).toEqual([
+        {kind: "digits", message: "`v2` comes back as `v`, `2`"},
+    ]);
+    expect(isReversible("user_name", Case.Snake, Case.Flat).losses).toEqual([
+        {kind: "boundaries", message: "`user`, `name` comes back as `username`"},
+    ]);
+});
+//#endregion
+
+//#region groupBySignature
+test("groupBySignature groups identifiers with the same words in any case", () => {
+    expect(groupBySignature(["userId", "user_id", "groupId", "USER_ID", "userId", "__"])).toEqual([
+        {words: ["user", "id"], identifiers: ["userId", "user_id", "USER_ID"]},
+        {words: ["group", "id"], identifiers: ["groupId"]},
+    ]);
+});
+//#endregion
+
+//#region discoverAcronyms
+test("discoverAcronyms suggests words consistently written in uppercase", () => {
+    const identifiers = ["HTTPServer", "getHTTPResponse", "userId", "groupId", "parseID", "MAX_SIZE", "urlPath", "parseURL", "URLValue"];
+    expect(discoverAcronyms(identifiers)).toEqual([
+        {acronym: "HTTP", uses: 2, consistency: 1},
+        {acronym: "URL", uses: 2, consistency: 1},
+    ]);
+    expect(discoverAcronyms(identifiers, {minUses: 1, minConsistency: 0.3})).toEqual([
+        {acronym: "HTTP", uses: 2, consistency: 1},
+        {acronym: "URL", uses: 2, consistency: 1},
+        {acronym: "ID", uses: 1, consistency: 1 / 3},
+    ]);
+});
+//#endregion
+
+//#region diffIdentifiers
+test("diffIdentifiers reports added words whatever the case", () => {
+    expect(diffIdentifiers("getUserName", "get_user_full_name")).toEqual([
+        {change: "same", from: "get", to: "get"},
+        {change: "same", from: "User", to: "user"},
+        {change: "added", from: null, to: "full"},
+        {change: "same", from: "Name", to: "name"},
+    ]);
+});
+
+test("diffIdentifiers pairs removed and added words as changed", () => {
+    expect(diffIdentifiers("fetch_user_id_list", "loadUserIds")).toEqual([
+        {change: "changed", from: "fetch", to: "load"},
+        {change: "same", from: "user", to: "User"},
+        {change: "changed", from: "id", to: "Ids"},
+        {change: "removed", from: "list", to: null},
+    ]);
+});
+//#endregion
+
+//#region suggestCase
+test("suggestCase picks the context's convention for a single word", () => {
+    expect(suggestCase("name", ["user_id", "created_at", "id"])).toBe(Case.Snake);
+    expect(suggestCase("name", ["userId", "createdAt", "id"])).toBe(Case.Camel);
+    expect(suggestCase("name", [])).toBe(Case.Camel);
+});
+
+test("suggestCase keeps the only case of an unambiguous string", () => {
+    expect(suggestCase("userName", ["user_id", "created_at"])).toBe(Case.Camel);
+    expect(suggestCase("user_Name", ["user_id"])).toBeNull();
+});
+//#endregion
+
+//#region Edge delimiters
+test("edgeDelimiters strips, preserves, or rejects delimiters at the edges", () => {
+    expect(CS("_user_id_").toCase(Case.Camel).toString()).toBe("userId");
+    expect(CS("_user_id_").toCase(Case.Camel, undefined, {edgeDelimiters: "strip"}).toString()).toBe("userId");
+    expect(CS("__user_id").toCase(Case.Camel, Case.Snake, {edgeDelimiters: "preserve"}).toString()).toBe("__userId");
+    expect(() => CS("_user_id").toCase(Case.Camel, undefined, {edgeDelimiters: "reject"})).toThrow(RangeError);
+    expect(CS("user_id").toCase(Case.Camel, undefined, {edgeDelimiters: "reject"}).toString()).toBe("userId");
+});
+
+test("edgeDelimiters decides whether isCase and detection accept delimiters at the edges", () => {
+    expect(CS("_user_id").isCase(Case.Snake)).toBe(false);
+    expect(CS("_user_id").isCase(Case.Snake, {edgeDelimiters: "reject"})).toBe(false);
+    expect(CS("_user_id").isCase(Case.Snake, {edgeDelimiters: "strip"})).toBe(true);
+    expect(CS("_user_id").isCase(Case.Snake, {edgeDelimiters: "preserve"})).toBe(true);
+    expect(caseConsistencyReport(["_user_id", "userName", "created_at"], {edgeDelimiters: "preserve"}).outliers).toEqual([
+        {identifier: "userName", cases: [Case.Camel], mixed: null, suggestion: "user_name"},
+    ]);
+});
+//#endregion
+
+//#region collapseDelims
+test("collapseDelims: false keeps an empty word between consecutive delimiters", () => {
+    expect(CS("foo__bar").toCase(Case.Snake).toString()).toBe("foo_bar");
+    expect(CS("foo__bar").toCase(Case.Snake, undefined, {collapseDelims: false}).toString()).toBe("foo__bar");
+    expect(CS("foo__bar").toCase(Case.Kebab, undefined, {collapseDelims: false}).toString()).toBe("foo--bar");
+    expect(CS("foo__barBaz").toCase(Case.Camel, undefined, {collapseDelims: false}).toString()).toBe("fooBarBaz");
+    expect(CS("foo__bar").isCase(Case.Snake)).toBe(false);
+    expect(CS("foo__bar").isCase(Case.Snake, {collapseDelims: false})).toBe(true);
+});
+
+test("collapseDelims applies to the words mutate splits", () => {
+    const words: string[] = [];
+    const result = CS("foo__bar").mutate({pattern: (word) => { words.push(word); return word.toUpperCase(); }, delim: ".", collapseDelims: false});
+    expect(result.toString()).toBe("FOO..BAR");
+    expect(words).toEqual(["foo", "", "bar"]);
+});
+//#endregion
+
+//#region emptyInput
+test("emptyInput decides what strings without words convert to", () => {
+    for (const input of ["", "   ", "__-", " \t\n"])
+    {
+        expect(CS(input).toCase(Case.Snake, undefined, {emptyInput: "unchanged"}).toString()).toBe(input);
+        expect(CS(input).toCase(Case.Snake, undefined, {emptyInput: "empty"}).toString()).toBe("");
+        expect(() => CS(input).toCase(Case.Snake, undefined, {emptyInput: "throw"})).toThrow(RangeError);
+    }
+    expect(CS("   ").toCase(Case.Snake).toString()).toBe("");
+    expect(CS(" x ").toCase(Case.Snake, undefined, {emptyInput: "throw"}).toString()).toBe("x");
+});
+
+test("emptyInput decides whether strings without words are in a case", () => {
+    expect(CS("   ").isCase(Case.Snake, {emptyInput: "unchanged"})).toBe(true);
+    expect(CS("   ").isCase(Case.Snake, {emptyInput: "empty"})).toBe(false);
+    expect(CS("").isCase(Case.Snake, {emptyInput: "empty"})).toBe(true);
+    expect(CS("").isCase(Case.Snake, {emptyInput: "throw"})).toBe(false);
+});
+//#endregion
+
+//#region NullInput
+test("CS throws on null and undefined unless told to coerce them", () => {
+    expect(() => CS(null)).toThrow(TypeError);
+    expect(() => CS(undefined)).toThrow(TypeError);
+    expect(CS(null, {nullInput: "coerce"}).toCase(Case.Snake).toString()).toBe("");
+});
+
+test("setNullInput changes the default for calls that don't say", () => {
+    setNullInput("coerce");
+    try
+    {
+        expect(CS(undefined).toCase(Case.Camel).toString()).toBe("");
+        expect(() => CS(null, {nullInput: "throw"})).toThrow(TypeError);
+    }
+    finally
+    {
+        setNullInput("throw");
+    }
+    expect(() => CS(null)).toThrow(TypeError);
+});
+//#endregion
+
+//#region maxInputLength
+test("maxInputLength refuses longer inputs with a dedicated error", () => {
+    const long = "ab_".repeat(10);
+    expect(CS(long).toCase(Case.Camel, undefined, {maxInputLength: 30}).toString()).toBe("abAbAbAbAbAbAbAbAbAb");
+    try
+    {
+        CS(long).toCase(Case.Camel, undefined, {maxInputLength: 29});
+        throw new Error("didn't throw");
+    }
+    catch (error)
+    {
+        expect(error).toBeInstanceOf(RangeError);
+        expect((error as any).code).toBe("ERR_INPUT_TOO_LONG");
+    }
+    expect(() => CS("é".repeat(4)).isCase(Case.Flat, {maxInputLength: 4})).not.toThrow();
+    expect(() => CS(long).mutate({delim: "", maxInputLength: 5})).toThrow(RangeError);
+});
+
+test("setMaxInputLength sets the limit for calls that don't give one", () => {
+    setMaxInputLength(5);
+    try
+    {
+        expect(() => CS("user_name").toCase(Case.Camel)).toThrow(RangeError);
+        expect(() => caseConsistencyReport(["id", "user_name"])).toThrow(RangeError);
+        expect(CS("user_name").toCase(Case.Camel, undefined, {maxInputLength: Infinity}).toString()).toBe("userName");
+    }
+    finally
+    {
+        setMaxInputLength(null);
+    }
+    expect(CS("user_name").toCase(Case.Camel).toString()).toBe("userName");
+});
+
+test("setMaxInputLength also limits the functions without CaseOptions", () => {
+    setMaxInputLength(5);
+    try
+    {
+        const tooLong = (f: () => unknown) => {
+            try { f(); } catch (e: any) { return e instanceof RangeError && e.code === "ERR_INPUT_TOO_LONG"; }
+            return false;
+        };
+        expect(tooLong(() => convertJsonKeys('{"userId":1}', Case.Snake))).toBe(true);
+        expect(tooLong(() => convertKeys({userName: 1}, Case.Snake))).toBe(true);
+        expect(tooLong(() => convertKeys({id: "userName"}, Case.Snake, {values: ["id"]}))).toBe(true);
+        expect(tooLong(() => droppedCharacters("userName", "user_name"))).toBe(true);
+        expect(tooLong(() => truncateWords("user_name", 4))).toBe(true);
+        expect(tooLong(() => lintIdentifiers(["userName"], Case.Snake))).toBe(true);
+        expect(convertKeys({id: 1}, Case.Snake)).toEqual({id: 1});
+    }
+    finally
+    {
+        setMaxInputLength(null);
+    }
+});
+
+test("the addon keeps the limit out of reach of JS and checks it natively", () => {
+    const rsBind = require("../index.node");
+    setMaxInputLength(5);
+    try
+    {
+        const state = Object.getOwnPropertyNames(rsBind).filter(name => typeof rsBind[name] != "function");
+        expect(Object.keys(rsBind).filter(name => state.includes(name))).toEqual([]);
+        for (const name of state)
+        {
+            expect(Reflect.set(rsBind, name, {})).toBe(false);
+        }
+        expect(Reflect.set(rsBind, "maxInputLength", 100)).toBe(true);
+        expect(() => CS("user_name").toCase(Case.Camel)).toThrow(RangeError);
+    }
+    finally
+    {
+        setMaxInputLength(null);
+        delete rsBind.maxInputLength;
+    }
+    for (const limit of [NaN, -1, 1.5])
+    {
+        expect(() => rsBind.setMaxInputLength(limit)).toThrow(RangeError);
+        expect(() => rsBind.toCase("user_name", Case.Camel, undefined, {maxInputLength: limit})).toThrow(RangeError);
+    }
+    expect(CS("user_name").toCase(Case.Camel).toString()).toBe("userName");
+});
+//#endregion
+
+//#region defaultBoundaries
+test("defaultBoundaries: true adds boundaries to the defaults instead of replacing them", () => {
+    expect(CS("my_varName").mutate({boundaries: [Boundary.UpperLower], delim: "."}).toString()).toBe("my_varN.ame");
+    expect(CS("my_varName").mutate({boundaries: [Boundary.UpperLower], defaultBoundaries: false, delim: "."}).toString()).toBe("my_varN.ame");
+    expect(CS("my_varName").mutate({boundaries: [Boundary.UpperLower], defaultBoundaries: true, delim: "."}).toString()).toBe("my.var.N.ame");
+    expect(CS("my_varName").mutate({defaultBoundaries: false, delim: "."}).toString()).toBe("my_varName");
+    expect(CS("my_varName").mutate({delim: "."}).toString()).toBe("my.var.Name");
+});
+//#endregion
+
+//#region listFrom candidates
+test("listFrom only finds the candidate boundaries", () =>
+{
+    expect(Boundary.listFrom("userId_list", Boundary.delims())).toEqual([Boundary.Underscore]);
+    expect(Boundary.listFrom("userId_list", [Boundary.LowerUpper])).toEqual([Boundary.LowerUpper]);
+    expect(Boundary.listFrom("userId_list", [])).toEqual([]);
+});
+//#endregion
+
+//#region toCaseDetailed
+test("toCaseDetailed returns the words, boundaries, and detected case", () =>
+{
+    expect(toCaseDetailed("userIdList", Case.Snake)).toEqual({
+        result: "user_id_list",
+        words: ["user", "Id", "List"],
+        boundaries: [Boundary.LowerUpper],
+        fromCaseDetected: Case.Camel,
+    });
+});
+
+test("toCaseDetailed reports no detected case for mixed strings", () =>
+{
+    const details = toCaseDetailed("user_Id-list", Case.Kebab, {fromCase: Case.Kebab});
+    expect(details.result).toBe("user_id-list");
+    expect(details.boundaries).toEqual([Boundary.Hyphen]);
+    expect(details.fromCaseDetected).toBeNull();
+});
+//#endregion
+
+//#region keepNumericSeparators
+test("keepNumericSeparators keeps digit groups in one word", () =>
+{
+    expect(CS("MAX_1_000_RETRIES").toCase(Case.Camel, undefined, {keepNumericSeparators: true}).toString()).toBe("max1_000Retries");
+    expect(CS("MAX_1_000_RETRIES").toCase(Case.Camel).toString()).toBe("max1000Retries");
+    expect(CS("LIMIT_1_000_000").toCase(Case.Kebab, undefined, {keepNumericSeparators: true}).toString()).toBe("limit-1_000_000");
+});
+
+test("keepNumericSeparators only keeps groups of three", () =>
+{
+    expect(CS("v1_2_release").toCase(Case.Kebab, undefined, {keepNumericSeparators: true}).toString()).toBe("v-1-2-release");
+});
+//#endregion
+
+//#region applyToWord
+test("lowerFirstWord and capitalizeFirstWord leave acronyms alone", () =>
+{
+    expect(lowerFirstWord("HTTPServerURL")).toBe("httpServerURL");
+    expect(capitalizeFirstWord("userHTTPClient")).toBe("UserHTTPClient");
+    expect(lowerFirstWord("")).toBe("");
+});
+
+test("applyToWord counts negative indices from the end", () =>
+{
+    expect(applyToWord("_user_id_list", -1, Pattern.Uppercase)).toBe("_user_id_LIST");
+    expect(applyToWord("user_id", 5, Pattern.Uppercase)).toBe("user_id");
+});
+//#endregion
+
+//#region wordRange
+test("wordRange converts only a slice of the words", () =>
+{
+    expect(CS("legacy_HTTPClient_Factory").toCase(Case.Snake, undefined, {wordRange: [-2, Infinity]}).toString()).toBe("legacy_HTTP_client_factory");
+    expect(CS("legacy_HTTP_client_factory").toCase(Case.Camel, undefined, {wordRange: [2, 4]}).toString()).toBe("legacy_HTTPClientFactory");
+    expect(CS("keep_THIS-as-is").toCase(Case.Camel, undefined, {wordRange: [1, 1]}).toString()).toBe("keep_THIS-as-is");
+});
+
+test("isCase with a wordRange only checks that range", () =>
+{
+    expect(CS("legacy_HTTP_client_factory").isCase(Case.Snake, {wordRange: [2, 4]})).toBe(true);
+    expect(CS("legacy_HTTP_ClientFactory").isCase(Case.Snake, {wordRange: [2, 4]})).toBe(false);
+});
+//#endregion
+
+//#region apostrophes
+test("apostrophes are kept, stripped, or split on", () =>
+{
+    expect(CS("Don't Stop").toCase(Case.Snake).toString()).toBe("don't_stop");
+    expect(CS("Don't Stop").toCase(Case.Snake, undefined, {apostrophes: "strip"}).toString()).toBe("dont_stop");
+    expect(CS("Don’t Stop").toCase(Case.Kebab, undefined, {apostrophes: "split"}).toString()).toBe("don-t-stop");
+    expect(CS("the users' guide").toCase(Case.Kebab, undefined, {apostrophes: "strip"}).toString()).toBe("the-users-guide");
+});
+
+test("unknown apostrophe policies throw a RangeError", () =>
+{
+    expect(() => CS("it's").toCase(Case.Snake, undefined, {apostrophes: "drop" as Apostrophes})).toThrow(RangeError);
+});
+//#endregion
+
+//#region capitalizeAfterDigit
+test("capitalizeAfterDigit decides the case of a word after a digit", () =>
+{
+    expect(CS("base64encode").toCase(Case.Camel).toString()).toBe("base64Encode");
+    expect(CS("base64_encode").toCase(Case.Camel, undefined, {capitalizeAfterDigit: false}).toString()).toBe("base64encode");
+    expect(CS("UTF8 string").toCase(Case.Pascal, undefined, {capitalizeAfterDigit: false}).toString()).toBe("Utf8string");
+    expect(CS("utf8 string").toCase(Case.Train, undefined, {capitalizeAfterDigit: false}).toString()).toBe("Utf-8-String");
+});
+//#endregion
+
+//#region leadingDigit
+test("leadingDigit prefixes, spells out, or rejects a leading digit", () =>
+{
+    expect(CS("3d model").toCase(Case.Snake).toString()).toBe("3_d_model");
+    expect(CS("3d model").toCase(Case.Snake, undefined, {leadingDigit: "prefixUnderscore"}).toString()).toBe("_3_d_model");
+    expect(CS("3dModel").toCase(Case.Camel, undefined, {leadingDigit: "spellOut"}).toString()).toBe("threeDModel");
+    expect(CS("42 answers").toCase(Case.Kebab, undefined, {leadingDigit: "spellOut"}).toString()).toBe("four-two-answers");
+    expect(() => CS("3dModel").toCase(Case.Snake, undefined, {leadingDigit: "error"})).toThrow(RangeError);
+    expect(CS("model3d").toCase(Case.Snake, undefined, {leadingDigit: "error"}).toString()).toBe("model_3_d");
+});
+//#endregion
+
+//#region reservedWords
+test("reservedWords renames converted reserved words", () =>
+{
+    expect(CS("Type").toCase(Case.Snake, undefined, {reservedWords: {language: "rust"}}).toString()).toBe("type_");
+    expect(CS("Class").toCase(Case.Camel, undefined, {reservedWords: {language: "javascript", replacements: {class: "klass"}}}).toString()).toBe("klass");
+    expect(CS("type").toCase(Case.Snake, undefined, {reservedWords: {language: "rust", prefix: "r#"}}).toString()).toBe("r#type");
+    expect(CS("type name").toCase(Case.Snake, undefined, {reservedWords: {language: "rust"}}).toString()).toBe("type_name");
+});
+
+test("isReservedWord checks each language's reserved words", () =>
+{
+    expect(isReservedWord("Order", "sql")).toBe(true);
+    expect(isReservedWord("lambda", "python")).toBe(true);
+    expect(isReservedWord("lambda", "rust")).toBe(false);
+    expect(reservedWords("javascript")).toContain("yield");
+    expect(() => isReservedWord("fn", "go" as ReservedLanguage)).toThrow(RangeError);
+});
+//#endregion
+
+//#region truncateWords
+test("truncateWords keeps whole words within the limit", () =>
+{
+    expect(truncateWords("customerBillingAddressLine", 20, {case: Case.Snake})).toBe("customer_billing");
+    expect(truncateWords("customerBillingAddressLine", 20, {case: Case.Camel, ellipsis: "…"})).toBe("customerBilling…");
+    expect(truncateWords("customerBillingAddressLine", 40, {case: Case.Kebab})).toBe("customer-billing-address-line");
+    expect(truncateWords("supercalifragilistic", 5, {case: Case.Snake})).toBe("");
+});
+
+test("truncateWords without a case keeps the string as written", () =>
+{
+    expect(truncateWords("user_HTTPRequest_id", 16)).toBe("user_HTTPRequest");
+    expect(truncateWords("naïve_café_menu", 11, {ellipsis: "…"})).toBe("naïve_café…");
+});
+//#endregion
+
+//#region seed
+test("a seed makes the random cases reproducible", () =>
+{
+    const random = CS("sponge case fixture").toCase(Case.Random, undefined, {seed: 42}).toString();
+    expect(CS("sponge case fixture").toCase(Case.Random, undefined, {seed: 42}).toString()).toBe(random);
+    expect(random.toLowerCase()).toBe("sponge case fixture");
+
+    const pseudo = CS("sponge case fixture").toCase(Case.PseudoRandom, undefined, {seed: BigInt("18446744073709551615")}).toString();
+    expect(CS("sponge case fixture").toCase(Case.PseudoRandom, undefined, {seed: BigInt("18446744073709551615")}).toString()).toBe(pseudo);
+    expect(pseudo).not.toBe(CS("sponge case fixture").toCase(Case.PseudoRandom, undefined, {seed: 7}).toString());
+});
+
+test("seeds outside 64 bits throw a RangeError", () =>
+{
+    expect(() => CS("a").toCase(Case.Random, undefined, {seed: -1})).toThrow(RangeError);
+    expect(() => CS("a").toCase(Case.Random, undefined, {seed: BigInt("18446744073709551616")})).toThrow(RangeError);
+});
+//#endregion
+
+//#region keepCase
+test("keepCase leaves the case of some characters alone", () =>
+{
+    expect(CS("delta ΔT [Hz]").toCase(Case.UpperSnake, undefined, {keepCase: ["greek", "bracketed"]}).toString()).toBe("DELTA_ΔT_[Hz]");
+    expect(CS("Ωmega value").toCase(Case.Snake, undefined, {keepCase: ["nonLatin"]}).toString()).toBe("Ωmega_value");
+    expect(CS("get (HTTP Status) Code").toCase(Case.Kebab, undefined, {keepCase: ["bracketed"]}).toString()).toBe("get-(HTTP Status)-code");
+    expect(CS("get [fooBar] value").toCase(Case.Snake, undefined, {keepCase: ["bracketed"]}).toString()).toBe("get_[fooBar]_value");
+    expect(CS("getValue(fooBar(x)) [unclosed").toCase(Case.Snake, undefined, {keepCase: ["bracketed"]}).toString()).toBe("get_value(fooBar(x))_[unclosed");
+});
+
+test("unknown character classes throw a RangeError", () =>
+{
+    expect(() => CS("a").toCase(Case.Snake, undefined, {keepCase: ["emoji" as KeepCase]})).toThrow(RangeError);
+});
+//#endregion
+
+//#region toCase pattern
+test("toCase can override the pattern of a case", () =>
+{
+    expect(CS("myVariableName").toCase(Case.Snake, undefined, {pattern: Pattern.Capital}).toString()).toBe("My_Variable_Name");
+    expect(CS("my-variable-name").toCase(Case.Kebab, Case.Kebab, {pattern: Pattern.Uppercase}).toString()).toBe("MY-VARIABLE-NAME");
+    expect(toCaseDetailed("userId", Case.Kebab, {pattern: Pattern.Capital}).result).toBe("User-Id");
+});
+//#endregion
+
+//#region from auto
+test("toCase can detect the case to split on", () =>
+{
+    expect(CS("MIXED_Case_v2").toCase(Case.Kebab, "auto").toString()).toBe("mixed-case-v2");
+    expect(CS("MIXED_Case_v2").toCase(Case.Kebab).toString()).toBe("mixed-case-v-2");
+    expect(CS("userIDList").toCase(Case.Snake, "auto").toString()).toBe("user_id_list");
+    expect(toCaseDetailed("v2-Release_Notes-x", Case.Snake, {fromCase: "auto"}).words).toEqual(["v2", "Release_Notes", "x"]);
+});
+//#endregion
+
+//#region droppedCharacters
+test("droppedCharacters reports lossy changes but not casing or delimiters", () =>
+{
+    expect(droppedCharacters("_Don't stop", "dont_stop", "_")).toEqual([
+        {kind: "removed", index: 0, original: "_", replacement: ""},
+        {kind: "removed", index: 4, original: "'", replacement: ""},
+    ]);
+    expect(droppedCharacters("user-Id", "USER_ID", "_")).toEqual([]);
+    expect(droppedCharacters("3d", "three_d", "_")).toEqual([{kind: "replaced", index: 0, original: "3", replacement: "three_"}]);
+});
+
+test("droppedCharacters aligns long strings without comparing every pair of characters", () =>
+{
+    const before = "fooBar!".repeat(1500);
+    const dropped = droppedCharacters(before, CS(before).toCase(Case.Snake).toString(), "_");
+    expect(dropped.length).toBe(1500);
+    expect(dropped[1499]).toEqual({kind: "added", index: 7 * 1499 + 3, original: "", replacement: "_"});
+});
+
+test("onDropped is called with what a conversion dropped", () =>
+{
+    const reports: DroppedCharacter[][] = [];
+    CS("user__id!").toCase(Case.Snake, undefined, {onDropped: dropped => reports.push(dropped)});
+    CS("user_id").toCase(Case.Kebab, undefined, {onDropped: dropped => reports.push(dropped)});
+    CS("a.b").mutate({delim: ".", onDropped: dropped => reports.push(dropped)});
+    expect(reports).toEqual([[{kind: "removed", index: 5, original: "_", replacement: ""}]]);
+});
+//#endregion
+
+//#region cache
+test("the cache memoizes conversions once it has a size", () =>
+{
+    clearCache();
+    expect(cacheStats().size).toBe(0);
+    CS("userId").toCase(Case.Snake);
+    expect(cacheStats().entries).toBe(0);
+
+    setCacheSize(2);
+    expect(CS("userId").toCase(Case.Snake).toString()).toBe("user_id");
+    expect(CS("userId").toCase(Case.Snake).toString()).toBe("user_id");
+    expect(CS("userId").toCase(Case.Kebab, Case.Camel).toString()).toBe("user-id");
+    expect(CS("userId").toCase(Case.Snake, undefined, {pattern: Pattern.Uppercase}).toString()).toBe("USER_ID");
+    const stats = cacheStats();
+    expect([stats.entries, stats.hits, stats.misses, stats.hitRate]).toEqual([2, 1, 2, 1 / 3]);
+    expect(stats.memoryEstimate).toBeGreaterThan(0);
+
+    CS("groupId").toCase(Case.Snake);
+    expect(cacheStats().entries).toBe(2);
+    clearCache();
+    expect([cacheStats().entries, cacheStats().hits, cacheStats().size]).toEqual([0, 0, 2]);
+    setCacheSize(0);
+});
+
+test("the cache leaves random cases random", () =>
+{
+    setCacheSize(10);
+    const results = new Set<string>();
+    for (let i = 0; i < 20; i++)
+    {
+        results.add(CS("hello world foo bar baz").toCase(Case.Random).toString());
+        results.add(CS("hello world foo bar baz").toCase(Case.PseudoRandom).toString());
+    }
+    expect(results.size).toBeGreaterThan(2);
+    expect([cacheStats().entries, cacheStats().hits, cacheStats().misses]).toEqual([0, 0, 0]);
+    setCacheSize(0);
+});
+
+test("every instance of the addon in a thread has its own cache", () =>
+{
+    const addon = path.join(__dirname, "..", "index.node");
+    const first = {exports: {} as any};
+    const second = {exports: {} as any};
+    process.dlopen(first, addon);
+    process.dlopen(second, addon);
+
+    first.exports.setCacheSize(10);
+    first.exports.toCase("userId", Case.Snake, undefined);
+    second.exports.toCase("userId", Case.Snake, undefined);
+    expect([first.exports.cacheStats().entries, first.exports.cacheStats().size]).toEqual([1, 10]);
+    expect([second.exports.cacheStats().entries, second.exports.cacheStats().size]).toEqual([0, 0]);
+    expect(cacheStats().size).toBe(0);
+});
+//#endregion
@@ -0,0 +1,112 @@
+import * as fs from "fs";
+import * as path from "path";
+import { Boundary, Case } from "../main";
+
+// The WebAssembly build, as `npm run build-wasm-node` writes it.  Its exports take the same
+// arguments as the addon's, so each case below is called on both and has to come out the same.
+const wasmPath = path.join(__dirname, "..", "pkg-node", "convert_case_wasm.js");
+const native = require("../index.node");
+
+/** What calling `name` with `args` gives: its result, or the class, message, and code it threw. */
+function outcome(module: any, name: string, args: () => unknown[]): unknown
+{
+    try {
+        return {result: module[name](...args())};
+    } catch (error: any) {
+        return {error: error.constructor.name, message: error.message, code: error.code};
+    }
+}
+
+const cyclic: any = {selfRef: null};
+cyclic.selfRef = cyclic;
+const symbol = Symbol("tag");
+
+// Arguments are built again for each module, so neither sees objects the other returned
+const cases: [string, () => unknown[]][] = [
+    ["toCase", () => ["toBe_or not-to-BE", Case.Camel, undefined]],
+    ["toCase", () => ["XMLHttpRequest", Case.Snake, undefined]],
+    ["toCase", () => ["MAX_1_000_RETRIES", Case.Camel, undefined, {keepNumericSeparators: true}]],
+    ["toCase", () => ["don't stop", Case.Snake, undefined, {apostrophes: "strip"}]],
+    ["toCase", () => ["foo__bar", Case.Kebab, undefined, {collapseDelims: false}]],
+    ["toCase", () => ["_private_", Case.Camel, undefined, {edgeDelimiters: "preserve"}]],
+    ["toCase", () => ["_private_", Case.Camel, undefined, {edgeDelimiters: "reject"}]],
+    ["toCase", () => ["get-(HTTP Status)-code", Case.Camel, undefined, {keepCase: ["bracketed"]}]],
+    ["toCase", () => ["3d model", Case.Camel, undefined, {leadingDigit: "spellOut"}]],
+    ["toCase", () => ["class", Case.Camel, undefined, {reservedWords: {language: "javascript"}}]],
+    ["toCase", () => ["sponge case fixture", Case.Random, undefined, {seed: 42}]],
+    ["toCase", () => ["one two three four", Case.Pascal, undefined, {wordRange: [1, -1]}]],
+    ["toCase", () => ["a".repeat(11), Case.Snake, undefined, {maxInputLength: 10}]],
+    ["toCase", () => ["a".repeat(11), Case.Snake, undefined, {maxInputLength: NaN}]],
+    ["setMaxInputLength", () => [-1]],
+    ["isCase", () => ["foo_bar", Case.Snake]],
+    ["isCase", () => ["fooBar", Case.Snake]],
+    ["isCase", () => ["fooBar", 99]],
+    ["toCase", () => ["fooBar", Case.Snake, 1.5]],
+    ["mutate", () => ["fooBar", {boundaries: [Boundary.Hyphen, 256]}]],
+    ["applyToWord", () => ["fooBar", 0, -1]],
+    ["convertKeys", () => [{userId: 1, nestedObject: {innerKey: [{deepKey: true}]}}, Case.Snake, {deep: true}]],
+    ["convertKeys", () => [{outerKey: {innerKey: 1}}, Case.Snake, {deep: true, maxDepth: 1}]],
+    ["convertKeys", () => [Object.assign(Object.create(null), {nullProto: 1}), Case.Snake, {}]],
+    ["convertKeys", () => [new Map<unknown, unknown>([["mapKey", {innerKey: 1}], [1, {numberKey: 2}]]), Case.Snake, {deep: true}]],
+    ["convertKeys", () => [new Set(["setMember", 1, {memberKey: 2}]), Case.Snake, {deep: true}]],
+    ["convertKeys", () => [{__proto__Key: 1, ["__proto__"]: 2, [symbol]: 3}, Case.Camel, {}]],
+    ["convertKeys", () => [{fooBar: 1, foo_bar: 2}, Case.Snake, {}]],
+    ["convertKeys", () => [{fooBar: 1, foo_bar: 2}, Case.Snake, {collisions: "error"}]],
+    ["convertKeys", () => [{fooBar: 1, foo_bar: 2}, Case.Snake, {collisions: "suffix"}]],
+    ["convertKeys", () => [{createdAt: new Date(0)}, Case.Snake, {deep: true, strict: true}]],
+    ["convertKeys", () => [cyclic, Case.Snake, {deep: true}]],
+    ["convertKeys", () => [{keyName: "valueName", skipMe: {innerKey: 1}}, Case.Snake, {deep: true, exclude: ["skipMe"], values: ["keyName"]}]],
+    ["convertKeys", () => [{userId: 1}, Case.Snake, {mappings: true}]],
+    ["convertJsonKeys", () => ['{"userId": 1, "nestedObject": {"innerKey": [1, 2]}}', Case.Snake, {deep: true}]],
+    ["convertJsonKeys", () => ["{unclosed", Case.Snake, {}]],
+    ["convertYamlKeys", () => ["userName: alice\nnestedMap:\n  innerKey: 1\n", Case.Snake]],
+    ["convertTomlKeys", () => ["fooBar = 1\nfoo_bar = 2\n", Case.Snake]],
+    ["convertCsvHeader", () => ["firstName,lastName\nAda,Lovelace\n", Case.Snake, {}]],
+    ["convertQueryKeys", () => ["userId=1&sortOrder=asc", Case.Snake]],
+    ["renameColumns", () => [[{firstName: "Ada"}], Case.Snake]],
+    ["htmlToJsxProp", () => ["onéclick"]],
+    ["jsxToHtmlAttribute", () => ["strokeWidth"]],
+    ["toK8sName", () => ["My Service_Name!"]],
+    ["toMacroName", () => ["3d-engine"]],
+    ["planRenames", () => [["src/myComponent.test.tsx", "aB.txt", "a_b.txt"], Case.Snake]],
+    ["droppedCharacters", () => ["_Don't stop", "dont_stop", "_"]],
+];
+
+// Skipped until the module is built, except in CI, which always builds it
+const built = fs.existsSync(wasmPath) || process.env.CI !== undefined;
+
+(built ? describe : describe.skip)("the WebAssembly build", () => {
+    const wasm = built ? require(wasmPath) : {};
+
+    test("exports every function the addon does", () => {
+        for (const name of Object.keys(native).filter(name => typeof native[name] == "function"))
+        {
+            expect(typeof wasm[name]).toBe("function");
+        }
+    });
+
+    test("converts like the addon", () => {
+        for (const [name, args] of cases)
+        {
+            expect(outcome(wasm, name, args)).toEqual(outcome(native, name, args));
+        }
+    });
+
+    test("limits the keys convertKeys converts like the addon", () => {
+        for (const module of [native, wasm])
+        {
+            module.setMaxInputLength(4);
+        }
+        try {
+            for (const args of [() => [{longKey: 1}, Case.Snake, {}], () => [{key: "longValue"}, Case.Snake, {values: ["key"]}], () => [new Set(["longMember"]), Case.Snake, {}]])
+            {
+                expect(outcome(wasm, "convertKeys", args)).toEqual(outcome(native, "convertKeys", args));
+            }
+        } finally {
+            for (const module of [native, wasm])
+            {
+                module.setMaxInputLength(undefined);
+            }
+        }
+    });
+});
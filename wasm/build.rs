@@ -0,0 +1,18 @@
+//! Writes `declarations.d.ts` into `OUT_DIR`, the declarations of the functions the module
+//! exports, which `wasm-bindgen` adds to the ones it generates in place of its own.
+
+use std::{env, fs, path::Path};
+
+use convert_case_core::dts;
+
+fn main()
+{
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let dts = dts::module(dts::TYPES, &dts::functions());
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("declarations.d.ts");
+    if fs::read_to_string(&path).ok().as_deref() != Some(dts.as_str())
+    {
+        fs::write(&path, dts).unwrap();
+    }
+}
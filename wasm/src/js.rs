@@ -0,0 +1,326 @@
+//! The JS values of the build's functions: the [`Js`] of `convert-case-core` for wasm-bindgen's
+//! `JsValue`s, which is all the build needs to export the functions of its `exports`.
+
+use std::cell::RefCell;
+use convert_case_core::js::{Js, State, Thrown, Type};
+use convert_case_core::key_walker::{Engine, Entries, Kind, WalkError};
+use js_sys::{Array, Error, Function, Map, Object, RangeError, Reflect, Set, TypeError};
+use wasm_bindgen::{JsCast, JsValue};
+
+thread_local! {
+    // Each instance of the module has its own memory, so this is per instance like the state the
+    // Node addon keeps on its exports
+    static STATE: State = State::default();
+
+    // Likewise for the callback `setTraceCallback` sets
+    static TRACE_CALLBACK: RefCell<Option<JsValue>> = const { RefCell::new(None) };
+}
+
+/// The prototypes [`Engine::kind`] compares others against, looked up the first time one call
+/// needs them.
+#[derive(Clone)]
+struct Prototypes
+{
+    object: JsValue,
+    map: JsValue,
+    set: JsValue,
+}
+
+/// The [`Js`] of one call of the WebAssembly build.
+#[derive(Default)]
+pub struct Wasm
+{
+    prototypes: Option<Prototypes>,
+}
+
+impl Wasm
+{
+    fn prototypes(&mut self) -> Prototypes
+    {
+        self.prototypes
+            .get_or_insert_with(|| Prototypes {
+                object: Object::get_prototype_of(&Object::new()).into(),
+                map: Object::get_prototype_of(&Map::new()).into(),
+                set: Object::get_prototype_of(&Set::new(&JsValue::UNDEFINED)).into(),
+            })
+            .clone()
+    }
+}
+
+impl Engine for Wasm
+{
+    type Value = JsValue;
+    type Error = JsValue;
+
+    fn kind(&mut self, value: &JsValue) -> Result<Kind, JsValue>
+    {
+        if Array::is_array(value)
+        {
+            return Ok(Kind::Array);
+        }
+        if !value.is_object()
+        {
+            return Ok(Kind::Other);
+        }
+
+        let prototypes = self.prototypes();
+        let prototype: JsValue = Object::get_prototype_of(value).into();
+        Ok(if prototype.is_null()
+        {
+            Kind::NullObject
+        }
+        else if prototype == prototypes.object
+        {
+            Kind::Object
+        }
+        else if prototype == prototypes.map
+        {
+            Kind::Map
+        }
+        else if prototype == prototypes.set
+        {
+            Kind::Set
+        }
+        else
+        {
+            Kind::Instance
+        })
+    }
+
+    fn string(&mut self, value: &JsValue) -> Result<Option<String>, JsValue>
+    {
+        Ok(value.as_string())
+    }
+
+    fn new_string(&mut self, string: &str) -> Result<JsValue, JsValue>
+    {
+        Ok(JsValue::from_str(string))
+    }
+
+    fn same(&mut self, a: &JsValue, b: &JsValue) -> Result<bool, JsValue>
+    {
+        Ok(Object::is(a, b))
+    }
+
+    fn elements(&mut self, array: &JsValue) -> Result<Vec<JsValue>, JsValue>
+    {
+        Ok(array.unchecked_ref::<Array>().iter().collect())
+    }
+
+    fn new_array(&mut self, elements: Vec<JsValue>) -> Result<JsValue, JsValue>
+    {
+        Ok(elements.into_iter().collect::<Array>().into())
+    }
+
+    fn keys(&mut self, object: &JsValue) -> Result<Vec<String>, JsValue>
+    {
+        Ok(Object::keys(object.unchecked_ref::<Object>()).iter().filter_map(|key| key.as_string()).collect())
+    }
+
+    fn get(&mut self, object: &JsValue, key: &str) -> Result<JsValue, JsValue>
+    {
+        Reflect::get(object, &JsValue::from_str(key))
+    }
+
+    fn new_object(&mut self, null_prototype: bool) -> Result<JsValue, JsValue>
+    {
+        Ok(if null_prototype { Object::create(JsValue::NULL.unchecked_ref()) } else { Object::new() }.into())
+    }
+
+    fn define(&mut self, object: &JsValue, name: &str, value: JsValue) -> Result<(), JsValue>
+    {
+        if name != "__proto__"
+        {
+            Reflect::set(object, &JsValue::from_str(name), &value)?;
+            return Ok(());
+        }
+
+        let descriptor = Object::new();
+        Reflect::set(&descriptor, &"value".into(), &value)?;
+        for attribute in ["writable", "enumerable", "configurable"]
+        {
+            Reflect::set(&descriptor, &attribute.into(), &JsValue::TRUE)?;
+        }
+
+        Object::define_property(object.unchecked_ref::<Object>(), &JsValue::from_str(name), &descriptor);
+        Ok(())
+    }
+
+    fn copy_symbols(&mut self, from: &JsValue, to: &JsValue) -> Result<(), JsValue>
+    {
+        let from: &Object = from.unchecked_ref();
+        for symbol in Object::get_own_property_symbols(from).iter()
+        {
+            if from.property_is_enumerable(&symbol)
+            {
+                Reflect::set(to, &symbol, &Reflect::get(from, &symbol)?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn entries(&mut self, map: &JsValue) -> Result<Entries<JsValue>, JsValue>
+    {
+        Ok(Array::from(map)
+            .iter()
+            .map(|entry| {
+                let entry: Array = entry.unchecked_into();
+                (entry.get(0), entry.get(1))
+            })
+            .collect())
+    }
+
+    fn new_map(&mut self, entries: Entries<JsValue>) -> Result<JsValue, JsValue>
+    {
+        let map = Map::new();
+        for (key, value) in entries
+        {
+            map.set(&key, &value);
+        }
+
+        Ok(map.into())
+    }
+
+    fn members(&mut self, set: &JsValue) -> Result<Vec<JsValue>, JsValue>
+    {
+        Ok(Array::from(set).iter().collect())
+    }
+
+    fn new_set(&mut self, members: Vec<JsValue>) -> Result<JsValue, JsValue>
+    {
+        let set = Set::new(&JsValue::UNDEFINED);
+        for member in members
+        {
+            set.add(&member);
+        }
+
+        Ok(set.into())
+    }
+
+    fn error(&mut self, error: WalkError) -> JsValue
+    {
+        self.throw(error.into())
+    }
+}
+
+impl Js for Wasm
+{
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    fn type_of(&mut self, value: &JsValue) -> Result<Type, JsValue>
+    {
+        Ok(if value.is_undefined()
+        {
+            Type::Undefined
+        }
+        else if value.is_null()
+        {
+            Type::Null
+        }
+        else if value.as_bool().is_some()
+        {
+            Type::Boolean
+        }
+        else if value.as_f64().is_some()
+        {
+            Type::Number
+        }
+        else if value.is_string()
+        {
+            Type::String
+        }
+        else if value.is_function()
+        {
+            Type::Function
+        }
+        else if Array::is_array(value)
+        {
+            Type::Array
+        }
+        else if value.is_object()
+        {
+            Type::Object
+        }
+        else
+        {
+            Type::Other
+        })
+    }
+
+    fn boolean(&mut self, value: &JsValue) -> Result<Option<bool>, JsValue>
+    {
+        Ok(value.as_bool())
+    }
+
+    fn number(&mut self, value: &JsValue) -> Result<Option<f64>, JsValue>
+    {
+        Ok(value.as_f64())
+    }
+
+    fn to_string(&mut self, value: &JsValue) -> Result<String, JsValue>
+    {
+        let string: Function = Reflect::get(&js_sys::global(), &JsValue::from_str("String"))?.unchecked_into();
+        Ok(string.call1(&JsValue::UNDEFINED, value)?.as_string().unwrap_or_default())
+    }
+
+    fn undefined(&mut self) -> Result<JsValue, JsValue>
+    {
+        Ok(JsValue::UNDEFINED)
+    }
+
+    fn null(&mut self) -> Result<JsValue, JsValue>
+    {
+        Ok(JsValue::NULL)
+    }
+
+    fn new_boolean(&mut self, boolean: bool) -> Result<JsValue, JsValue>
+    {
+        Ok(JsValue::from_bool(boolean))
+    }
+
+    fn new_number(&mut self, number: f64) -> Result<JsValue, JsValue>
+    {
+        Ok(JsValue::from_f64(number))
+    }
+
+    fn call(&mut self, function: &JsValue, args: &[JsValue]) -> Result<JsValue, JsValue>
+    {
+        function.unchecked_ref::<Function>().apply(&JsValue::UNDEFINED, &args.iter().collect::<Array>())
+    }
+
+    fn throw(&mut self, error: Thrown) -> JsValue
+    {
+        match error
+        {
+            Thrown::Error(message) => Error::new(&message).into(),
+            Thrown::TypeError(message) => TypeError::new(&message).into(),
+            Thrown::RangeError(message) => RangeError::new(&message).into(),
+            Thrown::InputTooLong(message) =>
+            {
+                let error = RangeError::new(&message);
+                match Reflect::set(&error, &JsValue::from_str("code"), &JsValue::from_str("ERR_INPUT_TOO_LONG"))
+                {
+                    Ok(_) => error.into(),
+                    Err(error) => error,
+                }
+            }
+        }
+    }
+
+    fn with_state<R>(&mut self, f: impl FnOnce(&State) -> R) -> Result<Option<R>, JsValue>
+    {
+        Ok(Some(STATE.with(f)))
+    }
+
+    fn trace_callback(&mut self) -> Result<Option<JsValue>, JsValue>
+    {
+        Ok(TRACE_CALLBACK.with(|callback| callback.borrow().clone()))
+    }
+
+    fn set_trace_callback(&mut self, callback: Option<JsValue>) -> Result<(), JsValue>
+    {
+        TRACE_CALLBACK.with(|trace_callback| trace_callback.replace(callback));
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! WebAssembly bindings for `convert-case-core`.
+//!
+//! Every function is one of `exports` in `convert-case-core`, exported under the same name as by
+//! the Node addon (`index.node`), so code written against the addon's bindings works unchanged
+//! against this module: it reads the same arguments and throws the same `Error`s, `RangeError`s,
+//! and `TypeError`s, with the same messages, and all this crate does is convert wasm-bindgen's
+//! values.  Its declarations are the addon's too, which the build script writes from the same
+//! list of exports.
+//!
+//! Build it with `wasm-pack build wasm --target web` (or `--target bundler` for bundlers, or
+//! `cargo build -p convert-case-wasm --target wasm32-unknown-unknown` followed by
+//! `wasm-bindgen`).
+
+use wasm_bindgen::prelude::*;
+
+use convert_case_core::exports;
+#[cfg(feature = "json")]
+use convert_case_core::json;
+
+mod js;
+
+use js::Wasm;
+
+#[wasm_bindgen(typescript_custom_section)]
+const DECLARATIONS: &str = include_str!(concat!(env!("OUT_DIR"), "/declarations.d.ts"));
+
+/// A stream of NDJSON records, like the `JsBox` the Node addon returns from `ndjsonNew`.
+#[cfg(feature = "json")]
+#[wasm_bindgen(skip_typescript)]
+pub struct NdjsonHandle
+{
+    stream: std::cell::RefCell<json::NdjsonStream>,
+    on_collision: Option<JsValue>,
+}
+
+#[cfg(feature = "json")]
+#[wasm_bindgen(js_name = ndjsonNew, skip_typescript)]
+pub fn ndjson_new(a: JsValue, b: JsValue) -> Result<NdjsonHandle, JsValue>
+{
+    let (stream, on_collision) = exports::ndjson_new(&mut Wasm::default(), &[a, b])?;
+    Ok(NdjsonHandle { stream: std::cell::RefCell::new(stream), on_collision })
+}
+
+// `convert-case-core` reads the chunk after the handle, which it doesn't read itself, so
+// `undefined` stands in for it
+#[cfg(feature = "json")]
+#[wasm_bindgen(js_name = ndjsonWrite, skip_typescript)]
+pub fn ndjson_write(handle: &NdjsonHandle, chunk: JsValue) -> Result<JsValue, JsValue>
+{
+    exports::ndjson_write(&mut Wasm::default(), &handle.stream, handle.on_collision.clone(), &[JsValue::UNDEFINED, chunk])
+}
+
+#[cfg(feature = "json")]
+#[wasm_bindgen(js_name = ndjsonEnd, skip_typescript)]
+pub fn ndjson_end(handle: &NdjsonHandle) -> Result<JsValue, JsValue>
+{
+    exports::ndjson_end(&mut Wasm::default(), &handle.stream, handle.on_collision.clone())
+}
+
+/// Exports one function of `exports`, but for the NDJSON ones, whose handles are typed above.
+/// wasm-bindgen passes `undefined` for the arguments a call leaves out, which is how
+/// `convert-case-core` reads them anyway.
+macro_rules! export_function
+{
+    (ndjson_new $($rest:tt)*) => {};
+    (ndjson_write $($rest:tt)*) => {};
+    (ndjson_end $($rest:tt)*) => {};
+    ($function:ident $name:literal $(#[$attr:meta])*) =>
+    {
+        $(#[$attr])*
+        #[wasm_bindgen(js_name = $name, skip_typescript)]
+        pub fn $function(a: JsValue, b: JsValue, c: JsValue, d: JsValue) -> Result<JsValue, JsValue>
+        {
+            exports::$function(&mut Wasm::default(), &[a, b, c, d])
+        }
+    };
+}
+
+macro_rules! export
+{
+    ($($(#[$attr:meta])* $name:literal => $function:ident $signature:literal;)*) =>
+    {
+        $(export_function!($function $name $(#[$attr])*);)*
+    };
+}
+
+convert_case_core::for_each_export!(export);